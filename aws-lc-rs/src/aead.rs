@@ -181,7 +181,7 @@
 //! ```
 
 use crate::error::Unspecified;
-use crate::{derive_debug_via_id, hkdf};
+use crate::{constant_time, derive_debug_via_id, derive_hex_via_as_ref, hkdf};
 use aead_ctx::AeadCtx;
 use core::fmt::Debug;
 use core::ops::RangeFrom;
@@ -189,20 +189,39 @@ use core::stringify;
 
 mod aead_ctx;
 mod aes_gcm;
+#[cfg(feature = "legacy-cbc-hmac")]
+pub mod cbc_hmac;
 mod chacha;
 pub mod chacha20_poly1305_openssh;
+mod framed;
+mod multi_recipient;
 mod nonce;
 pub mod nonce_sequence;
+mod nonce_tracker;
 mod poly1305;
 pub mod quic;
 mod rand_nonce;
+mod retag;
+mod session;
+mod stream;
 mod tls;
 mod unbound_key;
+pub mod xchacha20_poly1305;
 
 pub use self::aes_gcm::{AES_128_GCM, AES_128_GCM_SIV, AES_192_GCM, AES_256_GCM, AES_256_GCM_SIV};
 pub use self::chacha::CHACHA20_POLY1305;
+pub use self::framed::{
+    open_framed, open_framed_versioned, parse_framed, seal_framed, seal_framed_versioned,
+};
+pub use self::multi_recipient::{
+    open_multi_recipient, seal_multi_recipient, MultiRecipientCiphertext,
+};
 pub use self::nonce::{Nonce, NONCE_LEN};
+pub use self::nonce_tracker::NonceTracker;
 pub use self::rand_nonce::RandomizedNonceKey;
+pub use self::retag::retag;
+pub use self::session::{derive_session_keys, SessionKeys};
+pub use self::stream::{StreamOpeningKey, StreamSealingKey, STREAM_NONCE_PREFIX_LEN};
 pub use self::tls::{TlsProtocolId, TlsRecordOpeningKey, TlsRecordSealingKey};
 pub use self::unbound_key::UnboundKey;
 
@@ -656,6 +675,10 @@ impl<N: NonceSequence> SealingKeyPreparedNonce<'_, N> {
 ///
 /// The type `A` could be a byte slice `&[u8]`, a byte array `[u8; N]`
 /// for some constant `N`, `Vec<u8>`, etc.
+///
+/// The AAD is always processed as specified by the algorithm. For AES-GCM it is
+/// authenticated before the ciphertext, as required by NIST SP 800-38D; variants that
+/// authenticate the AAD after the ciphertext are not standard GCM and are not supported.
 pub struct Aad<A: AsRef<[u8]>>(A);
 
 impl<A: AsRef<[u8]>> Aad<A> {
@@ -935,6 +958,9 @@ pub struct Algorithm {
     // /// Use `max_input_len!()` to initialize this.
     // TODO: Make this `usize`.
     max_input_len: u64,
+
+    // The maximum total length of the additional authenticated data.
+    max_aad_len: u64,
 }
 
 impl Algorithm {
@@ -960,6 +986,36 @@ impl Algorithm {
     pub fn nonce_len(&self) -> usize {
         NONCE_LEN
     }
+
+    /// The recommended maximum number of messages to seal under a single key before rekeying.
+    ///
+    /// The limit is derived from the birthday bound on randomly generated nonces, which is the
+    /// most common way nonces are chosen when keys are long-lived. With `q` messages and random
+    /// `b`-bit nonces, the probability that two messages share a nonce is at most
+    /// `q^2 / 2^(b+1)`. Keeping that probability below 2^-32, as [NIST SP 800-38D] Section 8.3
+    /// requires for AES-GCM, allows `q = 2^((b-31)/2)` messages, i.e. 2^32 messages for the
+    /// 96-bit nonces used by every algorithm here. Each message's 128-bit tag limits a forgery
+    /// attempt to a success probability of about 2^-128 and does not lower this bound. For
+    /// AES-GCM-SIV a repeated nonce only reveals whether two messages are equal, so the limit is
+    /// conservative.
+    ///
+    /// The limit assumes random nonces. Keys used with a [`NonceSequence`] that never repeats a
+    /// nonce, such as a counter, are instead bounded by the sequence itself.
+    ///
+    /// [NIST SP 800-38D]: https://nvlpubs.nist.gov/nistpubs/Legacy/SP/nistspecialpublication800-38d.pdf
+    #[inline]
+    #[must_use]
+    pub fn safe_message_limit(&self) -> u64 {
+        const RANDOM_NONCE_MESSAGE_LIMIT: u64 = 1 << 32;
+        match self.id {
+            AlgorithmID::AES_128_GCM
+            | AlgorithmID::AES_192_GCM
+            | AlgorithmID::AES_256_GCM
+            | AlgorithmID::AES_128_GCM_SIV
+            | AlgorithmID::AES_256_GCM_SIV
+            | AlgorithmID::CHACHA20_POLY1305 => RANDOM_NONCE_MESSAGE_LIMIT,
+        }
+    }
 }
 
 derive_debug_via_id!(Algorithm);
@@ -995,6 +1051,19 @@ impl AsRef<[u8]> for Tag {
     }
 }
 
+derive_hex_via_as_ref!(Tag);
+
+/// Tags are compared in constant time with respect to their contents (using
+/// `CRYPTO_memcmp`), so comparing a computed tag against a received one with `==` does not
+/// leak where they differ. The comparison is not constant time with respect to the tag lengths.
+impl PartialEq for Tag {
+    fn eq(&self, other: &Self) -> bool {
+        constant_time::verify_slices_are_equal(self.as_ref(), other.as_ref()).is_ok()
+    }
+}
+
+impl Eq for Tag {}
+
 impl core::fmt::Debug for Tag {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Tag").finish()
@@ -1092,4 +1161,29 @@ mod tests {
         let tag = Tag([0u8; MAX_TAG_LEN], MAX_TAG_LEN);
         assert_eq!("Tag", format!("{tag:?}"));
     }
+
+    #[test]
+    fn tag_eq() {
+        let key = LessSafeKey::new(UnboundKey::new(&AES_128_GCM, &[0u8; 16]).unwrap());
+        let seal = |plaintext: &[u8]| {
+            let mut in_out = plaintext.to_vec();
+            key.seal_in_place_separate_tag(
+                Nonce::assume_unique_for_key([0u8; NONCE_LEN]),
+                Aad::empty(),
+                &mut in_out,
+            )
+            .unwrap()
+        };
+
+        assert_eq!(seal(b"message"), seal(b"message"));
+        assert_ne!(seal(b"message"), seal(b"messagf"));
+
+        let tag = seal(b"message");
+        assert_eq!(crate::hex::encode(&tag), format!("{tag:x}"));
+        assert_eq!(crate::hex::encode_upper(&tag), format!("{tag:X}"));
+
+        let full = Tag([1u8; MAX_TAG_LEN], MAX_TAG_LEN);
+        let truncated = Tag([1u8; MAX_TAG_LEN], MAX_TAG_LEN - 1);
+        assert_ne!(full, truncated);
+    }
 }
@@ -12,7 +12,8 @@ use crate::aws_lc::{
     EVP_aead_aes_128_gcm_randnonce, EVP_aead_aes_128_gcm_siv, EVP_aead_aes_128_gcm_tls12,
     EVP_aead_aes_128_gcm_tls13, EVP_aead_aes_192_gcm, EVP_aead_aes_256_gcm,
     EVP_aead_aes_256_gcm_randnonce, EVP_aead_aes_256_gcm_siv, EVP_aead_aes_256_gcm_tls12,
-    EVP_aead_aes_256_gcm_tls13, EVP_aead_chacha20_poly1305, OPENSSL_malloc, EVP_AEAD_CTX,
+    EVP_aead_aes_256_gcm_tls13, EVP_aead_chacha20_poly1305, EVP_aead_xchacha20_poly1305,
+    OPENSSL_malloc, EVP_AEAD_CTX,
 };
 use crate::cipher::aes::{AES_128_KEY_LEN, AES_192_KEY_LEN, AES_256_KEY_LEN};
 use crate::error::Unspecified;
@@ -196,6 +197,16 @@ impl AeadCtx {
         )?))
     }
 
+    pub(crate) fn xchacha20_poly1305_context(
+        key_bytes: &[u8],
+        tag_len: usize,
+    ) -> Result<LcPtr<EVP_AEAD_CTX>, Unspecified> {
+        if chacha::KEY_LEN != key_bytes.len() {
+            return Err(Unspecified);
+        }
+        AeadCtx::build_context(EVP_aead_xchacha20_poly1305, key_bytes, tag_len, None)
+    }
+
     fn aes_128_context(
         aead: unsafe extern "C" fn() -> *const aws_lc::evp_aead_st,
         key_bytes: &[u8],
@@ -7,12 +7,21 @@ use crate::aead::aead_ctx::AeadCtx;
 use crate::cipher::aes::{AES_128_KEY_LEN, AES_192_KEY_LEN, AES_256_KEY_LEN};
 use crate::error::Unspecified;
 
+/// The maximum length, in bytes, of the additional authenticated data for AES-GCM: the AAD is
+/// limited to 2^64 - 1 bits by NIST SP 800-38D, Section 5.2.1.1.
+pub(crate) const GCM_MAX_AAD_LEN: u64 = (1 << 61) - 1;
+
+/// The maximum length, in bytes, of the additional authenticated data for AES-GCM-SIV, from
+/// RFC 8452, Section 6.
+pub(crate) const GCM_SIV_MAX_AAD_LEN: u64 = 1 << 36;
+
 /// AES-128 in GCM mode with 128-bit tags and 96 bit nonces.
 pub const AES_128_GCM: Algorithm = Algorithm {
     init: init_128_aead,
     key_len: AES_128_KEY_LEN,
     id: AlgorithmID::AES_128_GCM,
     max_input_len: u64::MAX,
+    max_aad_len: GCM_MAX_AAD_LEN,
 };
 
 /// AES-192 in GCM mode with 128-bit tags and 96 bit nonces.
@@ -21,6 +30,7 @@ pub const AES_192_GCM: Algorithm = Algorithm {
     key_len: AES_192_KEY_LEN,
     id: AlgorithmID::AES_192_GCM,
     max_input_len: u64::MAX,
+    max_aad_len: GCM_MAX_AAD_LEN,
 };
 
 /// AES-256 in GCM mode with 128-bit tags and 96 bit nonces.
@@ -29,6 +39,7 @@ pub const AES_256_GCM: Algorithm = Algorithm {
     key_len: AES_256_KEY_LEN,
     id: AlgorithmID::AES_256_GCM,
     max_input_len: u64::MAX,
+    max_aad_len: GCM_MAX_AAD_LEN,
 };
 
 /// AES-256 in GCM mode with nonce reuse resistance, 128-bit tags and 96 bit nonces.
@@ -37,6 +48,7 @@ pub const AES_256_GCM_SIV: Algorithm = Algorithm {
     key_len: AES_256_KEY_LEN,
     id: AlgorithmID::AES_256_GCM_SIV,
     max_input_len: u64::MAX,
+    max_aad_len: GCM_SIV_MAX_AAD_LEN,
 };
 
 /// AES-128 in GCM mode with nonce reuse resistance, 128-bit tags and 96 bit nonces.
@@ -45,6 +57,7 @@ pub const AES_128_GCM_SIV: Algorithm = Algorithm {
     key_len: AES_128_KEY_LEN,
     id: AlgorithmID::AES_128_GCM_SIV,
     max_input_len: u64::MAX,
+    max_aad_len: GCM_SIV_MAX_AAD_LEN,
 };
 
 #[inline]
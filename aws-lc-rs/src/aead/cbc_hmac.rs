@@ -0,0 +1,207 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! AES-CBC with HMAC-SHA256 in encrypt-then-MAC order, for interoperability with legacy
+//! protocols.
+//!
+//! This is the composition described in [RFC 7518, Section 5.2] (`AES_128_CBC_HMAC_SHA_256`),
+//! also accepting AES-256 encryption keys, with an independent HMAC-SHA256 key:
+//!
+//! * The plaintext is PKCS#7 padded and encrypted with AES-CBC under the encryption key.
+//! * The tag is the first [`TAG_LEN`] bytes of
+//!   `HMAC-SHA256(mac_key, aad || iv || ciphertext || AL)`, where `AL` is the bit length of
+//!   `aad` as a 64-bit big-endian integer.
+//!
+//! `open` verifies the tag in constant time before any decryption or padding check takes
+//! place.
+//!
+//! New protocols should use the AEAD algorithms in [`crate::aead`] instead.
+//!
+//! [RFC 7518, Section 5.2]: https://www.rfc-editor.org/rfc/rfc7518#section-5.2
+//!
+//! # FIPS
+//! The APIs offered in this module must not be used.
+
+use crate::cipher::{
+    DecryptionContext, EncryptionContext, PaddedBlockDecryptingKey, PaddedBlockEncryptingKey,
+    UnboundCipherKey, AES_128, AES_128_KEY_LEN, AES_256, AES_256_KEY_LEN, AES_CBC_IV_LEN,
+};
+use crate::error::Unspecified;
+use crate::{constant_time, hmac};
+use core::fmt::{self, Debug, Formatter};
+
+/// The length of the initialization vector.
+pub const IV_LEN: usize = AES_CBC_IV_LEN;
+
+/// The length of the (truncated) authentication tag.
+pub const TAG_LEN: usize = 16;
+
+/// A key for `seal` and `open`, consisting of an AES encryption key and an HMAC-SHA256 key.
+pub struct Key {
+    encrypting: PaddedBlockEncryptingKey,
+    decrypting: PaddedBlockDecryptingKey,
+    mac: hmac::Key,
+}
+
+impl Key {
+    /// Constructs a new `Key` from an AES-128 or AES-256 encryption key and an HMAC-SHA256 key.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `encryption_key` is neither 16 nor 32 bytes long.
+    pub fn new(encryption_key: &[u8], mac_key: &[u8]) -> Result<Self, Unspecified> {
+        let algorithm = match encryption_key.len() {
+            AES_128_KEY_LEN => &AES_128,
+            AES_256_KEY_LEN => &AES_256,
+            _ => return Err(Unspecified),
+        };
+        Ok(Self {
+            encrypting: PaddedBlockEncryptingKey::cbc_pkcs7(UnboundCipherKey::new(
+                algorithm,
+                encryption_key,
+            )?)?,
+            decrypting: PaddedBlockDecryptingKey::cbc_pkcs7(UnboundCipherKey::new(
+                algorithm,
+                encryption_key,
+            )?)?,
+            mac: hmac::Key::new(hmac::HMAC_SHA256, mac_key),
+        })
+    }
+
+    fn tag(&self, iv: &[u8; IV_LEN], aad: &[u8], ciphertext: &[u8]) -> [u8; TAG_LEN] {
+        let aad_bits = (aad.len() as u64) * 8;
+        let mut ctx = hmac::Context::with_key(&self.mac);
+        ctx.update(aad);
+        ctx.update(iv);
+        ctx.update(ciphertext);
+        ctx.update(&aad_bits.to_be_bytes());
+        let mut tag = [0u8; TAG_LEN];
+        tag.copy_from_slice(&ctx.sign().as_ref()[..TAG_LEN]);
+        tag
+    }
+}
+
+impl Debug for Key {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("cbc_hmac::Key")
+            .field("algorithm", self.encrypting.algorithm())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Encrypts `plaintext` and authenticates it together with `aad`, returning
+/// `ciphertext || tag`.
+///
+/// `iv` must be unpredictable and must not be reused with the same key, e.g. it should be
+/// generated with `rand::SystemRandom` for each message.
+///
+// # FIPS
+// This function must not be used.
+//
+/// # Errors
+/// `error::Unspecified` on internal error.
+pub fn seal(
+    key: &Key,
+    iv: &[u8; IV_LEN],
+    aad: &[u8],
+    plaintext: &[u8],
+) -> Result<Vec<u8>, Unspecified> {
+    let mut in_out = plaintext.to_vec();
+    key.encrypting
+        .less_safe_encrypt(&mut in_out, EncryptionContext::Iv128(iv.into()))?;
+    let tag = key.tag(iv, aad, &in_out);
+    in_out.extend_from_slice(&tag);
+    Ok(in_out)
+}
+
+/// Verifies the tag of `ciphertext_and_tag` (as returned by `seal`) together with `aad`, and
+/// only if it is valid, decrypts and returns the plaintext.
+///
+// # FIPS
+// This function must not be used.
+//
+/// # Errors
+/// `error::Unspecified` if the input is malformed or could not be authenticated.
+pub fn open(
+    key: &Key,
+    iv: &[u8; IV_LEN],
+    aad: &[u8],
+    ciphertext_and_tag: &[u8],
+) -> Result<Vec<u8>, Unspecified> {
+    let ciphertext_len = ciphertext_and_tag
+        .len()
+        .checked_sub(TAG_LEN)
+        .ok_or(Unspecified)?;
+    let (ciphertext, received_tag) = ciphertext_and_tag.split_at(ciphertext_len);
+    let expected_tag = key.tag(iv, aad, ciphertext);
+    constant_time::verify_slices_are_equal(&expected_tag, received_tag)?;
+
+    let mut in_out = ciphertext.to_vec();
+    let plaintext_len = key
+        .decrypting
+        .decrypt(&mut in_out, DecryptionContext::Iv128(iv.into()))?
+        .len();
+    in_out.truncate(plaintext_len);
+    Ok(in_out)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aead::cbc_hmac::{open, seal, Key, IV_LEN};
+    use crate::test::from_hex;
+
+    // RFC 7518, Appendix B.1: AES_128_CBC_HMAC_SHA_256
+    const MAC_KEY: &str = "000102030405060708090a0b0c0d0e0f";
+    const ENC_KEY: &str = "101112131415161718191a1b1c1d1e1f";
+    const PLAINTEXT: &str = "41206369706865722073797374656d206d757374206e6f7420626520726571756972656420746f206265207365637265742c20616e64206974206d7573742062652061626c6520746f2066616c6c20696e746f207468652068616e6473206f662074686520656e656d7920776974686f757420696e636f6e76656e69656e6365";
+    const IV: &str = "1af38c2dc2b96ffdd86694092341bc04";
+    const AAD: &str =
+        "546865207365636f6e64207072696e6369706c65206f662041756775737465204b6572636b686f666673";
+    const CIPHERTEXT: &str = "c80edfa32ddf39d5ef00c0b468834279a2e46a1b8049f792f76bfe54b903a9c9a94ac9b47ad2655c5f10f9aef71427e2fc6f9b3f399a221489f16362c703233609d45ac69864e3321cf82935ac4096c86e133314c54019e8ca7980dfa4b9cf1b384c486f3a54c51078158ee5d79de59fbd34d848b3d69550a67646344427ade54b8851ffb598f7f80074b9473c82e2db";
+    const TAG: &str = "652c3fa36b0a7c5b3219fab3a30bc1c4";
+
+    #[test]
+    fn test_rfc7518_aes_128_cbc_hmac_sha_256() {
+        let key = Key::new(&from_hex(ENC_KEY).unwrap(), &from_hex(MAC_KEY).unwrap()).unwrap();
+        let iv: [u8; IV_LEN] = from_hex(IV).unwrap().try_into().unwrap();
+        let aad = from_hex(AAD).unwrap();
+        let plaintext = from_hex(PLAINTEXT).unwrap();
+        let mut expected = from_hex(CIPHERTEXT).unwrap();
+        expected.extend_from_slice(&from_hex(TAG).unwrap());
+
+        let sealed = seal(&key, &iv, &aad, &plaintext).unwrap();
+        assert_eq!(expected, sealed);
+
+        let opened = open(&key, &iv, &aad, &sealed).unwrap();
+        assert_eq!(plaintext, opened);
+    }
+
+    #[test]
+    fn test_open_rejects_tampering() {
+        let key = Key::new(&from_hex(ENC_KEY).unwrap(), &from_hex(MAC_KEY).unwrap()).unwrap();
+        let iv: [u8; IV_LEN] = from_hex(IV).unwrap().try_into().unwrap();
+        let aad = from_hex(AAD).unwrap();
+        let sealed = seal(&key, &iv, &aad, b"legacy record").unwrap();
+
+        for i in 0..sealed.len() {
+            let mut tampered = sealed.clone();
+            tampered[i] ^= 0x01;
+            assert!(open(&key, &iv, &aad, &tampered).is_err());
+        }
+        assert!(open(&key, &iv, b"other aad", &sealed).is_err());
+        let mut other_iv = iv;
+        other_iv[0] ^= 0x01;
+        assert!(open(&key, &other_iv, &aad, &sealed).is_err());
+        assert!(open(&key, &iv, &aad, &sealed[..sealed.len() - 1]).is_err());
+        assert!(open(&key, &iv, &aad, &[]).is_err());
+
+        // AES-256 keys round trip too.
+        let key = Key::new(&[0x42; 32], &[0x24; 32]).unwrap();
+        let sealed = seal(&key, &iv, &aad, b"legacy record").unwrap();
+        assert_eq!(
+            b"legacy record".to_vec(),
+            open(&key, &iv, &aad, &sealed).unwrap()
+        );
+
+        assert!(Key::new(&[0x42; 24], &[0x24; 32]).is_err());
+    }
+}
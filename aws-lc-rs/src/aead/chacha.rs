@@ -19,6 +19,7 @@ pub const CHACHA20_POLY1305: Algorithm = Algorithm {
     key_len: KEY_LEN,
     id: AlgorithmID::CHACHA20_POLY1305,
     max_input_len: u64::MAX,
+    max_aad_len: u64::MAX,
 };
 
 #[inline]
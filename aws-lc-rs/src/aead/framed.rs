@@ -0,0 +1,261 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use crate::error::Unspecified;
+use crate::rand::SecureRandom;
+
+use super::{Aad, Algorithm, LessSafeKey, Nonce, Tag, UnboundKey, MAX_TAG_LEN, NONCE_LEN};
+
+/// Seals `plaintext` under `key_bytes` with a random nonce, returning a single framed blob
+/// of `nonce || ciphertext || tag` that can be passed to [`open_framed`].
+///
+/// The nonce is `NONCE_LEN` bytes filled from `rng`. Random 96-bit nonces should not be
+/// used for more than 2^32 messages under the same key.
+///
+/// # Errors
+/// `error::Unspecified` if `key_bytes` is not a valid key for `algorithm`, `rng` fails, or
+/// encryption fails.
+#[allow(clippy::needless_pass_by_value)]
+pub fn seal_framed<A: AsRef<[u8]>>(
+    algorithm: &'static Algorithm,
+    key_bytes: &[u8],
+    rng: &dyn SecureRandom,
+    aad: Aad<A>,
+    plaintext: &[u8],
+) -> Result<Vec<u8>, Unspecified> {
+    let key = LessSafeKey::new(UnboundKey::new(algorithm, key_bytes)?);
+    let mut nonce = [0u8; NONCE_LEN];
+    rng.fill(&mut nonce)?;
+
+    let mut blob = Vec::with_capacity(NONCE_LEN + plaintext.len() + algorithm.tag_len());
+    blob.extend_from_slice(&nonce);
+    blob.extend_from_slice(plaintext);
+    let tag = key.seal_in_place_separate_tag(
+        Nonce::assume_unique_for_key(nonce),
+        aad,
+        &mut blob[NONCE_LEN..],
+    )?;
+    blob.extend_from_slice(tag.as_ref());
+    Ok(blob)
+}
+
+/// Opens a `nonce || ciphertext || tag` blob produced by [`seal_framed`], returning the
+/// plaintext.
+///
+/// `algorithm`, `key_bytes` and `aad` must match the values used to seal the blob.
+///
+/// # Errors
+/// `error::Unspecified` if `key_bytes` is not a valid key for `algorithm`, `blob` is too short,
+/// or the blob fails to authenticate.
+#[allow(clippy::needless_pass_by_value)]
+pub fn open_framed<A: AsRef<[u8]>>(
+    algorithm: &'static Algorithm,
+    key_bytes: &[u8],
+    aad: Aad<A>,
+    blob: &[u8],
+) -> Result<Vec<u8>, Unspecified> {
+    let key = LessSafeKey::new(UnboundKey::new(algorithm, key_bytes)?);
+    if blob.len() < NONCE_LEN + algorithm.tag_len() {
+        return Err(Unspecified);
+    }
+    let (nonce, ciphertext_and_tag) = blob.split_at(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce)?;
+
+    let mut in_out = ciphertext_and_tag.to_vec();
+    let plaintext_len = key.open_in_place(nonce, aad, &mut in_out)?.len();
+    in_out.truncate(plaintext_len);
+    Ok(in_out)
+}
+
+/// Splits a `nonce || ciphertext || tag` blob produced by [`seal_framed`] into its nonce,
+/// ciphertext and tag, without decrypting or authenticating it.
+///
+/// # Errors
+/// `error::Unspecified` if `blob` is shorter than the nonce and tag of `algorithm`.
+pub fn parse_framed<'a>(
+    algorithm: &'static Algorithm,
+    blob: &'a [u8],
+) -> Result<(Nonce, &'a [u8], Tag), Unspecified> {
+    let tag_len = algorithm.tag_len();
+    if blob.len() < NONCE_LEN + tag_len {
+        return Err(Unspecified);
+    }
+    let (nonce, ciphertext_and_tag) = blob.split_at(NONCE_LEN);
+    let (ciphertext, tag_bytes) = ciphertext_and_tag.split_at(ciphertext_and_tag.len() - tag_len);
+    let mut tag = [0u8; MAX_TAG_LEN];
+    tag[..tag_len].copy_from_slice(tag_bytes);
+    Ok((
+        Nonce::try_assume_unique_for_key(nonce)?,
+        ciphertext,
+        Tag(tag, tag_len),
+    ))
+}
+
+/// Seals `plaintext` like [`seal_framed`], prefixing the blob with `header` and authenticating
+/// the header as the additional data, producing `header || nonce || ciphertext || tag`.
+///
+/// This suits framings whose first bytes, e.g. a version number, are sent in the clear but
+/// must not be modified. The blob can be opened with [`open_framed_versioned`] given
+/// `header.len()`.
+///
+/// # Errors
+/// `error::Unspecified` if `key_bytes` is not a valid key for `algorithm`, `rng` fails, or
+/// encryption fails.
+pub fn seal_framed_versioned(
+    algorithm: &'static Algorithm,
+    key_bytes: &[u8],
+    rng: &dyn SecureRandom,
+    header: &[u8],
+    plaintext: &[u8],
+) -> Result<Vec<u8>, Unspecified> {
+    let framed = seal_framed(algorithm, key_bytes, rng, Aad::from(header), plaintext)?;
+    let mut blob = Vec::with_capacity(header.len() + framed.len());
+    blob.extend_from_slice(header);
+    blob.extend_from_slice(&framed);
+    Ok(blob)
+}
+
+/// Opens a `header || nonce || ciphertext || tag` blob produced by [`seal_framed_versioned`],
+/// returning the plaintext.
+///
+/// The first `header_len` bytes of `blob` are authenticated as the additional data and are not
+/// part of the returned plaintext; the caller can read them from `blob` once it has been
+/// opened successfully.
+///
+/// # Errors
+/// `error::Unspecified` if `key_bytes` is not a valid key for `algorithm`, `blob` is too short,
+/// or the blob fails to authenticate.
+pub fn open_framed_versioned(
+    algorithm: &'static Algorithm,
+    key_bytes: &[u8],
+    header_len: usize,
+    blob: &[u8],
+) -> Result<Vec<u8>, Unspecified> {
+    if blob.len() < header_len {
+        return Err(Unspecified);
+    }
+    let (header, framed) = blob.split_at(header_len);
+    open_framed(algorithm, key_bytes, Aad::from(header), framed)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aead::{
+        open_framed, open_framed_versioned, parse_framed, seal_framed, seal_framed_versioned, Aad,
+        LessSafeKey, UnboundKey, AES_128_GCM, AES_256_GCM, CHACHA20_POLY1305, NONCE_LEN,
+    };
+    use crate::rand::SystemRandom;
+    use crate::test::rand::FixedByteRandom;
+
+    const KEY: [u8; 32] = [0x42; 32];
+    const PLAINTEXT: &[u8] = b"encrypt this and give me something I can decrypt later";
+
+    #[test]
+    fn test_framed_round_trip() {
+        let rng = SystemRandom::new();
+        for algorithm in [&AES_256_GCM, &CHACHA20_POLY1305] {
+            let blob = seal_framed(algorithm, &KEY, &rng, Aad::from(b"aad"), PLAINTEXT).unwrap();
+            assert_eq!(
+                NONCE_LEN + PLAINTEXT.len() + algorithm.tag_len(),
+                blob.len()
+            );
+            assert_eq!(
+                PLAINTEXT,
+                open_framed(algorithm, &KEY, Aad::from(b"aad"), &blob).unwrap()
+            );
+
+            let empty = seal_framed(algorithm, &KEY, &rng, Aad::empty(), &[]).unwrap();
+            assert!(open_framed(algorithm, &KEY, Aad::empty(), &empty)
+                .unwrap()
+                .is_empty());
+        }
+
+        let blob = seal_framed(
+            &AES_256_GCM,
+            &KEY,
+            &FixedByteRandom { byte: 7 },
+            Aad::empty(),
+            PLAINTEXT,
+        )
+        .unwrap();
+        assert_eq!([7u8; NONCE_LEN], blob[..NONCE_LEN]);
+    }
+
+    #[test]
+    fn test_framed_tampered() {
+        let rng = SystemRandom::new();
+        let blob = seal_framed(&AES_256_GCM, &KEY, &rng, Aad::empty(), PLAINTEXT).unwrap();
+
+        // Flipping a bit in the nonce, ciphertext or tag is detected.
+        for i in [0, NONCE_LEN, blob.len() - 1] {
+            let mut tampered = blob.clone();
+            tampered[i] ^= 0x01;
+            assert!(open_framed(&AES_256_GCM, &KEY, Aad::empty(), &tampered).is_err());
+        }
+        assert!(open_framed(&AES_256_GCM, &KEY, Aad::from(b"aad"), &blob).is_err());
+        assert!(open_framed(&AES_256_GCM, &[0x43; 32], Aad::empty(), &blob).is_err());
+        assert!(open_framed(&AES_256_GCM, &KEY, Aad::empty(), &blob[..blob.len() - 1]).is_err());
+        assert!(open_framed(&AES_256_GCM, &KEY, Aad::empty(), &blob[..NONCE_LEN + 15]).is_err());
+    }
+
+    #[test]
+    fn test_parse_framed() {
+        let rng = SystemRandom::new();
+        let blob = seal_framed(&AES_256_GCM, &KEY, &rng, Aad::empty(), PLAINTEXT).unwrap();
+
+        let (nonce, ciphertext, tag) = parse_framed(&AES_256_GCM, &blob).unwrap();
+        assert_eq!(&blob[..NONCE_LEN], nonce.as_ref());
+        assert_eq!(&blob[NONCE_LEN..NONCE_LEN + PLAINTEXT.len()], ciphertext);
+        assert_eq!(&blob[blob.len() - AES_256_GCM.tag_len()..], tag.as_ref());
+
+        // The components can be opened with the separate-tag API.
+        let key = LessSafeKey::new(UnboundKey::new(&AES_256_GCM, &KEY).unwrap());
+        let mut in_out = ciphertext.to_vec();
+        in_out.extend_from_slice(tag.as_ref());
+        assert_eq!(
+            PLAINTEXT,
+            key.open_in_place(nonce, Aad::empty(), &mut in_out).unwrap()
+        );
+
+        // A blob of only a nonce and a tag has an empty ciphertext.
+        let empty = seal_framed(&AES_128_GCM, &KEY[..16], &rng, Aad::empty(), &[]).unwrap();
+        let (_, ciphertext, tag) = parse_framed(&AES_128_GCM, &empty).unwrap();
+        assert!(ciphertext.is_empty());
+        assert_eq!(AES_128_GCM.tag_len(), tag.as_ref().len());
+
+        let min_len = NONCE_LEN + AES_256_GCM.tag_len();
+        assert!(parse_framed(&AES_256_GCM, &blob[..min_len]).is_ok());
+        assert!(parse_framed(&AES_256_GCM, &blob[..min_len - 1]).is_err());
+        assert!(parse_framed(&AES_256_GCM, &blob[..NONCE_LEN]).is_err());
+        assert!(parse_framed(&AES_256_GCM, &[]).is_err());
+    }
+
+    #[test]
+    fn test_framed_versioned() {
+        const VERSION: &[u8] = &[0x02];
+
+        let rng = SystemRandom::new();
+        let blob = seal_framed_versioned(&AES_256_GCM, &KEY, &rng, VERSION, PLAINTEXT).unwrap();
+        assert_eq!(
+            VERSION.len() + NONCE_LEN + PLAINTEXT.len() + AES_256_GCM.tag_len(),
+            blob.len()
+        );
+        assert_eq!(VERSION, &blob[..1]);
+        assert_eq!(
+            PLAINTEXT,
+            open_framed_versioned(&AES_256_GCM, &KEY, VERSION.len(), &blob).unwrap()
+        );
+        // The header is the additional data of an ordinary framed blob.
+        assert_eq!(
+            PLAINTEXT,
+            open_framed(&AES_256_GCM, &KEY, Aad::from(VERSION), &blob[1..]).unwrap()
+        );
+
+        // The version byte is authenticated.
+        let mut tampered = blob.clone();
+        tampered[0] = 0x03;
+        assert!(open_framed_versioned(&AES_256_GCM, &KEY, VERSION.len(), &tampered).is_err());
+        assert!(open_framed_versioned(&AES_256_GCM, &KEY, 0, &blob).is_err());
+        assert!(open_framed_versioned(&AES_256_GCM, &KEY, blob.len() + 1, &blob).is_err());
+    }
+}
@@ -0,0 +1,240 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use crate::error::Unspecified;
+use crate::key_wrap::{AesKek, KeyWrap};
+use zeroize::Zeroize;
+
+use super::{Aad, Algorithm, AlgorithmID, RandomizedNonceKey, MAX_KEY_LEN, NONCE_LEN};
+
+/// The AES-KW output is the wrapped key plus one 64-bit integrity check block.
+const KEY_WRAP_OVERHEAD: usize = 8;
+
+/// The output of [`seal_multi_recipient`].
+#[derive(Clone, Debug)]
+pub struct MultiRecipientCiphertext {
+    nonce: [u8; NONCE_LEN],
+    ciphertext: Vec<u8>,
+    wrapped_keys: Vec<Vec<u8>>,
+}
+
+impl MultiRecipientCiphertext {
+    /// The randomly generated nonce used to seal the payload.
+    #[must_use]
+    pub fn nonce(&self) -> &[u8; NONCE_LEN] {
+        &self.nonce
+    }
+
+    /// The sealed payload, with the tag appended.
+    #[must_use]
+    pub fn ciphertext(&self) -> &[u8] {
+        &self.ciphertext
+    }
+
+    /// The AES-KW wrapped content keys, in the same order as the recipient key-encryption keys
+    /// passed to [`seal_multi_recipient`].
+    #[must_use]
+    pub fn wrapped_keys(&self) -> &[Vec<u8>] {
+        &self.wrapped_keys
+    }
+}
+
+/// Seals `payload` once under a freshly generated content key and wraps that content key for
+/// each of the `recipients` using AES Key Wrap (NIST SP 800-38F).
+///
+/// The payload is sealed with `algorithm` and a random nonce. Only `AES_128_GCM` and
+/// `AES_256_GCM` are supported. The content key never leaves this function unwrapped.
+///
+/// Each recipient recovers the payload with [`open_multi_recipient`] using its own
+/// key-encryption key and its entry of [`MultiRecipientCiphertext::wrapped_keys`].
+///
+/// # Errors
+/// `error::Unspecified` if `algorithm` is not supported, `recipients` is empty, or if any
+/// encryption or key wrapping operation fails.
+#[allow(clippy::needless_pass_by_value)]
+pub fn seal_multi_recipient<A, R>(
+    algorithm: &'static Algorithm,
+    aad: Aad<A>,
+    payload: &[u8],
+    recipients: R,
+) -> Result<MultiRecipientCiphertext, Unspecified>
+where
+    A: AsRef<[u8]>,
+    R: IntoIterator<Item = AesKek>,
+{
+    check_algorithm(algorithm)?;
+
+    let mut content_key = [0u8; MAX_KEY_LEN];
+    let content_key = &mut content_key[..algorithm.key_len()];
+    let result = crate::rand::fill(content_key)
+        .and_then(|()| seal_with_content_key(algorithm, content_key, aad, payload, recipients));
+    content_key.zeroize();
+    result
+}
+
+fn seal_with_content_key<A, R>(
+    algorithm: &'static Algorithm,
+    content_key: &[u8],
+    aad: Aad<A>,
+    payload: &[u8],
+    recipients: R,
+) -> Result<MultiRecipientCiphertext, Unspecified>
+where
+    A: AsRef<[u8]>,
+    R: IntoIterator<Item = AesKek>,
+{
+    let wrapped_keys = recipients
+        .into_iter()
+        .map(|kek| {
+            let mut wrapped_key = vec![0u8; content_key.len() + KEY_WRAP_OVERHEAD];
+            let wrapped_len = kek.wrap(content_key, &mut wrapped_key)?.len();
+            wrapped_key.truncate(wrapped_len);
+            Ok(wrapped_key)
+        })
+        .collect::<Result<Vec<_>, Unspecified>>()?;
+    if wrapped_keys.is_empty() {
+        return Err(Unspecified);
+    }
+
+    let key = RandomizedNonceKey::new(algorithm, content_key)?;
+    let mut ciphertext = payload.to_vec();
+    let nonce = key.seal_in_place_append_tag(aad, &mut ciphertext)?;
+
+    Ok(MultiRecipientCiphertext {
+        nonce: *nonce.as_ref(),
+        ciphertext,
+        wrapped_keys,
+    })
+}
+
+/// Unwraps `wrapped_key` with the recipient's `kek` and opens the payload sealed by
+/// [`seal_multi_recipient`].
+///
+/// `algorithm` and `aad` must match the values passed to [`seal_multi_recipient`].
+///
+/// # Errors
+/// `error::Unspecified` if `algorithm` is not supported, `wrapped_key` cannot be unwrapped with
+/// `kek`, or the payload fails to authenticate.
+#[allow(clippy::needless_pass_by_value)]
+pub fn open_multi_recipient<A: AsRef<[u8]>>(
+    algorithm: &'static Algorithm,
+    kek: AesKek,
+    wrapped_key: &[u8],
+    aad: Aad<A>,
+    sealed: &MultiRecipientCiphertext,
+) -> Result<Vec<u8>, Unspecified> {
+    check_algorithm(algorithm)?;
+    if wrapped_key.len() != algorithm.key_len() + KEY_WRAP_OVERHEAD {
+        return Err(Unspecified);
+    }
+
+    let mut content_key = [0u8; MAX_KEY_LEN + KEY_WRAP_OVERHEAD];
+    let result = kek
+        .unwrap(wrapped_key, &mut content_key)
+        .and_then(|content_key| RandomizedNonceKey::new(algorithm, content_key));
+    content_key.zeroize();
+    let key = result?;
+
+    let mut in_out = sealed.ciphertext.clone();
+    let plaintext_len = key
+        .open_in_place(
+            super::Nonce::assume_unique_for_key(sealed.nonce),
+            aad,
+            &mut in_out,
+        )?
+        .len();
+    in_out.truncate(plaintext_len);
+    Ok(in_out)
+}
+
+fn check_algorithm(algorithm: &'static Algorithm) -> Result<(), Unspecified> {
+    match algorithm.id {
+        AlgorithmID::AES_128_GCM | AlgorithmID::AES_256_GCM => Ok(()),
+        AlgorithmID::AES_192_GCM
+        | AlgorithmID::AES_128_GCM_SIV
+        | AlgorithmID::AES_256_GCM_SIV
+        | AlgorithmID::CHACHA20_POLY1305 => Err(Unspecified),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{open_multi_recipient, seal_multi_recipient};
+    use crate::aead::{Aad, AES_128_GCM, AES_256_GCM, CHACHA20_POLY1305};
+    use crate::key_wrap::{AesKek, AES_128, AES_256};
+
+    const KEKS: [&[u8]; 3] = [&[0x11; 16], &[0x22; 32], &[0x33; 16]];
+    const PAYLOAD: &[u8] = b"hello, group members";
+    const AAD: &[u8] = b"group-id";
+
+    fn kek(bytes: &[u8]) -> AesKek {
+        let cipher = if bytes.len() == 16 {
+            &AES_128
+        } else {
+            &AES_256
+        };
+        AesKek::new(cipher, bytes).unwrap()
+    }
+
+    #[test]
+    fn test_seal_multi_recipient() {
+        for algorithm in [&AES_128_GCM, &AES_256_GCM] {
+            let sealed = seal_multi_recipient(
+                algorithm,
+                Aad::from(AAD),
+                PAYLOAD,
+                KEKS.iter().map(|k| kek(k)),
+            )
+            .unwrap();
+            assert_eq!(
+                PAYLOAD.len() + algorithm.tag_len(),
+                sealed.ciphertext().len()
+            );
+            assert_eq!(KEKS.len(), sealed.wrapped_keys().len());
+
+            for (kek_bytes, wrapped_key) in KEKS.iter().zip(sealed.wrapped_keys()) {
+                let plaintext = open_multi_recipient(
+                    algorithm,
+                    kek(kek_bytes),
+                    wrapped_key,
+                    Aad::from(AAD),
+                    &sealed,
+                )
+                .unwrap();
+                assert_eq!(PAYLOAD, plaintext);
+
+                // Wrong AAD
+                assert!(open_multi_recipient(
+                    algorithm,
+                    kek(kek_bytes),
+                    wrapped_key,
+                    Aad::empty(),
+                    &sealed,
+                )
+                .is_err());
+            }
+
+            // A recipient cannot unwrap another recipient's key.
+            assert!(open_multi_recipient(
+                algorithm,
+                kek(KEKS[0]),
+                &sealed.wrapped_keys()[2],
+                Aad::from(AAD),
+                &sealed,
+            )
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn test_seal_multi_recipient_rejects() {
+        assert!(
+            seal_multi_recipient(&CHACHA20_POLY1305, Aad::empty(), PAYLOAD, [kek(KEKS[0])])
+                .is_err()
+        );
+        assert!(
+            seal_multi_recipient(&AES_128_GCM, Aad::empty(), PAYLOAD, Vec::<AesKek>::new())
+                .is_err()
+        );
+    }
+}
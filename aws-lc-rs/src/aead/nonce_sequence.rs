@@ -3,8 +3,10 @@
 
 //! Implementations of `NonceSequence` for use with `BoundKey`s.
 
+mod counter;
 mod counter32;
 mod counter64;
 
+pub use counter::CounterNonceSequence;
 pub use counter32::{Counter32, Counter32Builder};
 pub use counter64::{Counter64, Counter64Builder};
@@ -0,0 +1,122 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use crate::aead::{Nonce, NonceSequence, NONCE_LEN};
+use crate::error::Unspecified;
+use crate::iv::FixedLength;
+
+/// `CounterNonceSequence` is an implementation of the `NonceSequence` trait.
+///
+/// Each nonce is a 96-bit big-endian counter, starting from the value the sequence was
+/// constructed with and incremented by one on each call to `advance`. Unlike `Counter32` and
+/// `Counter64`, the counter never wraps: once the nonce with every bit set has been produced,
+/// `advance` fails, so a nonce is never repeated within the lifetime of the sequence.
+#[allow(clippy::module_name_repetitions)]
+pub struct CounterNonceSequence {
+    next: Option<u128>,
+}
+
+const COUNTER_MAX: u128 = (1 << (NONCE_LEN * 8)) - 1;
+
+impl CounterNonceSequence {
+    /// Constructs a `CounterNonceSequence` whose first nonce is `start`.
+    #[must_use]
+    pub fn new(start: [u8; NONCE_LEN]) -> Self {
+        let mut value = [0u8; 16];
+        value[16 - NONCE_LEN..].copy_from_slice(&start);
+        Self {
+            next: Some(u128::from_be_bytes(value)),
+        }
+    }
+
+    /// Provides the next nonce value, or `None` if the sequence is exhausted.
+    #[must_use]
+    pub fn counter(&self) -> Option<[u8; NONCE_LEN]> {
+        self.next.map(to_nonce_bytes)
+    }
+}
+
+impl Default for CounterNonceSequence {
+    fn default() -> Self {
+        Self::new([0u8; NONCE_LEN])
+    }
+}
+
+impl NonceSequence for CounterNonceSequence {
+    fn advance(&mut self) -> Result<Nonce, Unspecified> {
+        let value = self.next.ok_or(Unspecified)?;
+        self.next = if value < COUNTER_MAX {
+            Some(value + 1)
+        } else {
+            None
+        };
+        Ok(Nonce(FixedLength::from(to_nonce_bytes(value))))
+    }
+}
+
+fn to_nonce_bytes(value: u128) -> [u8; NONCE_LEN] {
+    let mut nonce = [0u8; NONCE_LEN];
+    nonce.copy_from_slice(&value.to_be_bytes()[16 - NONCE_LEN..]);
+    nonce
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aead::nonce_sequence::CounterNonceSequence;
+    use crate::aead::{
+        Aad, BoundKey, NonceSequence, OpeningKey, SealingKey, UnboundKey, AES_128_GCM, NONCE_LEN,
+    };
+
+    #[test]
+    fn test_counter_nonce_sequence() {
+        let start = [0, 0, 0, 0, 0, 0, 0, 1, 0xFF, 0xFF, 0xFF, 0xFE];
+        let mut cns = CounterNonceSequence::new(start);
+        assert_eq!(Some(start), cns.counter());
+
+        let nonces: Vec<[u8; NONCE_LEN]> =
+            (0..3).map(|_| *cns.advance().unwrap().as_ref()).collect();
+        assert_eq!(
+            vec![
+                [0, 0, 0, 0, 0, 0, 0, 1, 0xFF, 0xFF, 0xFF, 0xFE],
+                [0, 0, 0, 0, 0, 0, 0, 1, 0xFF, 0xFF, 0xFF, 0xFF],
+                [0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0],
+            ],
+            nonces
+        );
+        assert!(nonces.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_counter_nonce_sequence_seal_open() {
+        const KEY: [u8; 16] = [0x42; 16];
+        let messages: [&[u8]; 3] = [b"first", b"second", b"third"];
+
+        let mut sealing_key = SealingKey::new(
+            UnboundKey::new(&AES_128_GCM, &KEY).unwrap(),
+            CounterNonceSequence::default(),
+        );
+        let mut opening_key = OpeningKey::new(
+            UnboundKey::new(&AES_128_GCM, &KEY).unwrap(),
+            CounterNonceSequence::default(),
+        );
+        for message in messages {
+            let mut in_out = message.to_vec();
+            sealing_key
+                .seal_in_place_append_tag(Aad::empty(), &mut in_out)
+                .unwrap();
+            let plaintext = opening_key
+                .open_in_place(Aad::empty(), &mut in_out)
+                .unwrap();
+            assert_eq!(message, plaintext);
+        }
+    }
+
+    #[test]
+    fn test_counter_nonce_sequence_overflow() {
+        let mut cns = CounterNonceSequence::new([0xFF; NONCE_LEN]);
+        assert_eq!(&[0xFF; NONCE_LEN], cns.advance().unwrap().as_ref());
+        assert_eq!(None, cns.counter());
+        assert!(cns.advance().is_err());
+        assert!(cns.advance().is_err());
+    }
+}
@@ -0,0 +1,163 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use crate::aead::{Nonce, NonceSequence, NONCE_LEN};
+use crate::error::Unspecified;
+use core::fmt::Debug;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+/// `NonceTracker` wraps a `NonceSequence` and rejects any nonce that has already been
+/// produced for the same key.
+///
+/// Reusing a nonce with AES-GCM or ChaCha20-Poly1305 is catastrophic: it reveals the XOR of
+/// the plaintexts and allows tag forgeries. `NonceTracker` is a debugging aid for catching such
+/// bugs during testing, e.g. a counter that is reset or two `SealingKey`s for the same key
+/// whose nonce sequences overlap:
+///
+/// ```
+/// # use aws_lc_rs::aead::{
+/// #     nonce_sequence::Counter32Builder, Aad, BoundKey, NonceTracker, SealingKey, UnboundKey,
+/// #     AES_128_GCM,
+/// # };
+/// # let key_bytes = [0u8; 16];
+/// let tracker = NonceTracker::new(Counter32Builder::new().build());
+/// // A second sequence for the same key that mistakenly starts at the same counter value.
+/// let overlapping = tracker.track(Counter32Builder::new().build());
+///
+/// let mut key_a = SealingKey::new(UnboundKey::new(&AES_128_GCM, &key_bytes)?, tracker);
+/// let mut key_b = SealingKey::new(UnboundKey::new(&AES_128_GCM, &key_bytes)?, overlapping);
+///
+/// let mut in_out = b"message".to_vec();
+/// key_a.seal_in_place_append_tag(Aad::empty(), &mut in_out)?;
+/// assert!(key_b.seal_in_place_append_tag(Aad::empty(), &mut in_out).is_err());
+/// # Ok::<(), aws_lc_rs::error::Unspecified>(())
+/// ```
+///
+/// Every nonce produced is remembered for the lifetime of the tracker, so memory use grows
+/// with the number of operations. It is not intended for production use.
+#[allow(clippy::module_name_repetitions)]
+pub struct NonceTracker<N: NonceSequence> {
+    inner: N,
+    used: Arc<Mutex<HashSet<[u8; NONCE_LEN]>>>,
+}
+
+impl<N: NonceSequence> NonceTracker<N> {
+    /// Constructs a `NonceTracker` for a new key, wrapping `nonce_sequence`.
+    #[must_use]
+    pub fn new(nonce_sequence: N) -> Self {
+        Self {
+            inner: nonce_sequence,
+            used: Arc::default(),
+        }
+    }
+
+    /// Constructs a `NonceTracker` wrapping `nonce_sequence` that shares this tracker's record
+    /// of used nonces. Use this when `nonce_sequence` is bound to the same key as this tracker.
+    #[must_use]
+    pub fn track<M: NonceSequence>(&self, nonce_sequence: M) -> NonceTracker<M> {
+        NonceTracker {
+            inner: nonce_sequence,
+            used: Arc::clone(&self.used),
+        }
+    }
+
+    /// The number of distinct nonces produced for this key.
+    ///
+    /// # Panics
+    /// Panics if a thread panicked while holding the record of used nonces.
+    #[must_use]
+    pub fn used(&self) -> usize {
+        self.used.lock().unwrap().len()
+    }
+}
+
+impl<N: NonceSequence> NonceSequence for NonceTracker<N> {
+    /// Returns the next nonce of the wrapped sequence.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the wrapped sequence fails, or if the nonce has already been
+    /// produced for this key.
+    fn advance(&mut self) -> Result<Nonce, Unspecified> {
+        let nonce = self.inner.advance()?;
+        let mut used = self.used.lock().map_err(|_| Unspecified)?;
+        if !used.insert(*nonce.as_ref()) {
+            return Err(Unspecified);
+        }
+        Ok(nonce)
+    }
+}
+
+impl<N: NonceSequence> Debug for NonceTracker<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("NonceTracker").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aead::nonce_sequence::Counter32Builder;
+    use crate::aead::{
+        Aad, BoundKey, Nonce, NonceSequence, NonceTracker, SealingKey, UnboundKey, AES_256_GCM,
+    };
+    use crate::error::Unspecified;
+
+    struct FixedNonce;
+
+    impl NonceSequence for FixedNonce {
+        fn advance(&mut self) -> Result<Nonce, Unspecified> {
+            Ok(Nonce::assume_unique_for_key([7u8; 12]))
+        }
+    }
+
+    fn sealing_key<N: NonceSequence>(nonce_sequence: N) -> SealingKey<N> {
+        SealingKey::new(
+            UnboundKey::new(&AES_256_GCM, &[0u8; 32]).unwrap(),
+            nonce_sequence,
+        )
+    }
+
+    #[test]
+    fn test_nonce_tracker_detects_reuse() {
+        let mut key = sealing_key(NonceTracker::new(FixedNonce));
+        let mut in_out = b"first".to_vec();
+        key.seal_in_place_append_tag(Aad::empty(), &mut in_out)
+            .unwrap();
+        let mut in_out = b"second".to_vec();
+        assert!(key
+            .seal_in_place_append_tag(Aad::empty(), &mut in_out)
+            .is_err());
+        assert_eq!(b"second", in_out.as_slice());
+    }
+
+    #[test]
+    fn test_nonce_tracker_shared_across_sequences() {
+        let tracker = NonceTracker::new(Counter32Builder::new().build());
+        let overlapping = tracker.track(Counter32Builder::new().counter(2).build());
+        let mut key_a = sealing_key(tracker);
+        let mut key_b = sealing_key(overlapping);
+
+        for _ in 0..2 {
+            let mut in_out = b"message".to_vec();
+            key_a
+                .seal_in_place_append_tag(Aad::empty(), &mut in_out)
+                .unwrap();
+        }
+        // Counter value 2 has not been used yet.
+        let mut in_out = b"message".to_vec();
+        key_b
+            .seal_in_place_append_tag(Aad::empty(), &mut in_out)
+            .unwrap();
+        // ...but counter value 2 has now been used by `key_b`.
+        assert!(key_a
+            .seal_in_place_append_tag(Aad::empty(), &mut in_out)
+            .is_err());
+    }
+
+    #[test]
+    fn test_nonce_tracker_debug() {
+        let tracker = NonceTracker::new(FixedNonce);
+        assert_eq!("NonceTracker { .. }", format!("{tracker:?}"));
+        assert_eq!(0, tracker.used());
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use crate::error::Unspecified;
+use zeroize::Zeroize;
+
+use super::{Aad, AlgorithmID, LessSafeKey, Nonce, Tag};
+
+/// Computes the tag that `key` would produce for `ciphertext` under `nonce` and `new_aad`,
+/// without changing the ciphertext.
+///
+/// This allows a record that was sealed with one AAD to be re-framed with another: the
+/// original ciphertext followed by the returned tag opens with `new_aad`. It is intended for
+/// proxies that re-frame records and already hold the key.
+///
+/// **Use with care.** `retag` produces a valid tag for *any* `ciphertext`: it does not check
+/// the record's original tag. Callers must authenticate the record, e.g. by opening it with
+/// its original AAD, before retagging it, or a forged record becomes authentic. The `nonce`
+/// must be the nonce the record was sealed with.
+///
+/// Only stream-cipher based AEADs, where the ciphertext is the plaintext XORed with a
+/// keystream that depends only on the key and nonce, can be retagged: AES-GCM and
+/// ChaCha20-Poly1305. The record is decrypted transiently to recompute the tag; the plaintext
+/// is zeroized before returning.
+///
+/// # Errors
+/// `error::Unspecified` if `key`'s algorithm is AES-GCM-SIV, or if computing the tag fails.
+#[allow(clippy::needless_pass_by_value)]
+pub fn retag<A: AsRef<[u8]>>(
+    key: &LessSafeKey,
+    nonce: Nonce,
+    new_aad: Aad<A>,
+    ciphertext: &[u8],
+) -> Result<Tag, Unspecified> {
+    match key.algorithm().id {
+        AlgorithmID::AES_128_GCM
+        | AlgorithmID::AES_192_GCM
+        | AlgorithmID::AES_256_GCM
+        | AlgorithmID::CHACHA20_POLY1305 => {}
+        AlgorithmID::AES_128_GCM_SIV | AlgorithmID::AES_256_GCM_SIV => return Err(Unspecified),
+    }
+    let nonce = *nonce.as_ref();
+
+    // Sealing zeros yields the keystream for this key and nonce, from which the plaintext is
+    // recovered. Sealing that plaintext again reproduces `ciphertext` with the new tag.
+    let mut plaintext = vec![0u8; ciphertext.len()];
+    let result = key
+        .seal_in_place_separate_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::empty(),
+            &mut plaintext,
+        )
+        .and_then(|_| {
+            for (p, c) in plaintext.iter_mut().zip(ciphertext) {
+                *p ^= *c;
+            }
+            key.seal_in_place_separate_tag(
+                Nonce::assume_unique_for_key(nonce),
+                new_aad,
+                &mut plaintext,
+            )
+        });
+    debug_assert!(result.is_err() || plaintext == ciphertext);
+    plaintext.zeroize();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aead::{
+        retag, Aad, LessSafeKey, Nonce, UnboundKey, AES_128_GCM, AES_256_GCM, AES_256_GCM_SIV,
+        CHACHA20_POLY1305, NONCE_LEN,
+    };
+
+    const NONCE: [u8; NONCE_LEN] = [0x24; NONCE_LEN];
+    const MESSAGE: &[u8] = b"a record that is re-framed by a proxy";
+
+    #[test]
+    fn test_retag() {
+        for (alg, key_bytes) in [
+            (&AES_128_GCM, &[0x11u8; 16][..]),
+            (&AES_256_GCM, &[0x11u8; 32][..]),
+            (&CHACHA20_POLY1305, &[0x11u8; 32][..]),
+        ] {
+            let key = LessSafeKey::new(UnboundKey::new(alg, key_bytes).unwrap());
+            let mut ciphertext = MESSAGE.to_vec();
+            let tag = key
+                .seal_in_place_separate_tag(
+                    Nonce::assume_unique_for_key(NONCE),
+                    Aad::from(b"old header"),
+                    &mut ciphertext,
+                )
+                .unwrap();
+
+            let new_tag = retag(
+                &key,
+                Nonce::assume_unique_for_key(NONCE),
+                Aad::from(b"new header"),
+                &ciphertext,
+            )
+            .unwrap();
+            assert_ne!(tag, new_tag);
+
+            let mut record = ciphertext.clone();
+            record.extend_from_slice(new_tag.as_ref());
+            let plaintext = key
+                .open_in_place(
+                    Nonce::assume_unique_for_key(NONCE),
+                    Aad::from(b"new header"),
+                    &mut record.clone(),
+                )
+                .unwrap()
+                .to_vec();
+            assert_eq!(MESSAGE, plaintext);
+            assert!(key
+                .open_in_place(
+                    Nonce::assume_unique_for_key(NONCE),
+                    Aad::from(b"old header"),
+                    &mut record,
+                )
+                .is_err());
+
+            // Retagging with the original AAD reproduces the original tag.
+            assert_eq!(
+                tag,
+                retag(
+                    &key,
+                    Nonce::assume_unique_for_key(NONCE),
+                    Aad::from(b"old header"),
+                    &ciphertext,
+                )
+                .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_retag_gcm_siv_unsupported() {
+        let key = LessSafeKey::new(UnboundKey::new(&AES_256_GCM_SIV, &[0x11u8; 32]).unwrap());
+        assert!(retag(
+            &key,
+            Nonce::assume_unique_for_key(NONCE),
+            Aad::empty(),
+            MESSAGE
+        )
+        .is_err());
+    }
+}
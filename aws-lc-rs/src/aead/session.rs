@@ -0,0 +1,144 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use super::{Algorithm, NONCE_LEN};
+use crate::encoding::AeadKeyBin;
+use crate::error::Unspecified;
+use crate::hkdf;
+use core::fmt::Debug;
+
+const C2S_KEY_INFO: &[u8] = b"aws-lc-rs session c2s key";
+const C2S_IV_INFO: &[u8] = b"aws-lc-rs session c2s iv";
+const S2C_KEY_INFO: &[u8] = b"aws-lc-rs session s2c key";
+const S2C_IV_INFO: &[u8] = b"aws-lc-rs session s2c iv";
+
+/// AEAD key material for both directions of a client-server session, as returned by
+/// [`derive_session_keys`].
+///
+/// Each direction is a `(key, iv)` pair. The key bytes have length `aead_alg.key_len()` and
+/// can be passed to [`super::UnboundKey::new`] by both the sealing and the opening side. The
+/// IV is [`NONCE_LEN`] bytes and is combined with a per-message counter, e.g. by XOR, to form
+/// each message's nonce. The key bytes are zeroized when dropped.
+#[allow(clippy::module_name_repetitions)]
+pub struct SessionKeys {
+    /// The key and IV for messages sent from the client to the server.
+    pub c2s: (AeadKeyBin<'static>, [u8; NONCE_LEN]),
+    /// The key and IV for messages sent from the server to the client.
+    pub s2c: (AeadKeyBin<'static>, [u8; NONCE_LEN]),
+}
+
+impl Debug for SessionKeys {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.debug_struct("SessionKeys").finish_non_exhaustive()
+    }
+}
+
+/// Derives separate client-to-server and server-to-client AEAD keys and IVs from `prk`.
+///
+/// `prk` is typically the output of [`hkdf::Salt::extract`] over a shared secret. Each key and
+/// IV is a separate HKDF-Expand of `prk`, with one of the following ASCII strings as the info:
+///
+/// | Output     | Info                          | Length               |
+/// |------------|-------------------------------|----------------------|
+/// | `c2s` key  | `"aws-lc-rs session c2s key"` | `aead_alg.key_len()` |
+/// | `c2s` IV   | `"aws-lc-rs session c2s iv"`  | [`NONCE_LEN`]        |
+/// | `s2c` key  | `"aws-lc-rs session s2c key"` | `aead_alg.key_len()` |
+/// | `s2c` IV   | `"aws-lc-rs session s2c iv"`  | [`NONCE_LEN`]        |
+///
+/// The two directions are therefore independent, and a message sealed for one direction
+/// cannot be opened with the other direction's key.
+///
+/// # Errors
+/// `error::Unspecified` if the expansion fails.
+pub fn derive_session_keys(
+    prk: &hkdf::Prk,
+    aead_alg: &'static Algorithm,
+) -> Result<SessionKeys, Unspecified> {
+    Ok(SessionKeys {
+        c2s: derive_direction(prk, aead_alg, C2S_KEY_INFO, C2S_IV_INFO)?,
+        s2c: derive_direction(prk, aead_alg, S2C_KEY_INFO, S2C_IV_INFO)?,
+    })
+}
+
+fn derive_direction(
+    prk: &hkdf::Prk,
+    aead_alg: &'static Algorithm,
+    key_info: &[u8],
+    iv_info: &[u8],
+) -> Result<(AeadKeyBin<'static>, [u8; NONCE_LEN]), Unspecified> {
+    let expand =
+        |info: &[u8], out: &mut [u8]| prk.expand(&[info], hkdf::OutputLen(out.len()))?.fill(out);
+    hkdf::expand_aead_material(
+        aead_alg,
+        |key| expand(key_info, key),
+        |iv| expand(iv_info, iv),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aead::{derive_session_keys, Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM};
+    use crate::hkdf;
+
+    #[test]
+    fn test_derive_session_keys() {
+        let prk = hkdf::Salt::new(hkdf::HKDF_SHA256, b"salt").extract(b"shared secret");
+        let keys = derive_session_keys(&prk, &AES_256_GCM).unwrap();
+        assert_eq!(AES_256_GCM.key_len(), keys.c2s.0.as_ref().len());
+        assert_ne!(keys.c2s.0.as_ref(), keys.s2c.0.as_ref());
+        assert_ne!(keys.c2s.1, keys.s2c.1);
+
+        // The info strings are part of the documented derivation.
+        for (info, expected) in [
+            (&b"aws-lc-rs session c2s key"[..], keys.c2s.0.as_ref()),
+            (b"aws-lc-rs session c2s iv", &keys.c2s.1),
+            (b"aws-lc-rs session s2c key", keys.s2c.0.as_ref()),
+            (b"aws-lc-rs session s2c iv", &keys.s2c.1),
+        ] {
+            let mut out = vec![0u8; expected.len()];
+            prk.expand(&[info], hkdf::OutputLen(out.len()))
+                .unwrap()
+                .fill(&mut out)
+                .unwrap();
+            assert_eq!(expected, out.as_slice());
+        }
+
+        let again = derive_session_keys(&prk, &AES_256_GCM).unwrap();
+        assert_eq!(keys.c2s.0.as_ref(), again.c2s.0.as_ref());
+        assert_eq!(keys.c2s.1, again.c2s.1);
+        assert_eq!(keys.s2c.0.as_ref(), again.s2c.0.as_ref());
+        assert_eq!(keys.s2c.1, again.s2c.1);
+
+        let client_sealing =
+            LessSafeKey::new(UnboundKey::new(&AES_256_GCM, keys.c2s.0.as_ref()).unwrap());
+        let mut in_out = b"hello server".to_vec();
+        client_sealing
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(keys.c2s.1),
+                Aad::empty(),
+                &mut in_out,
+            )
+            .unwrap();
+
+        let s2c_opening =
+            LessSafeKey::new(UnboundKey::new(&AES_256_GCM, keys.s2c.0.as_ref()).unwrap());
+        assert!(s2c_opening
+            .open_in_place(
+                Nonce::assume_unique_for_key(keys.c2s.1),
+                Aad::empty(),
+                &mut in_out.clone(),
+            )
+            .is_err());
+
+        let c2s_opening =
+            LessSafeKey::new(UnboundKey::new(&AES_256_GCM, keys.c2s.0.as_ref()).unwrap());
+        let plaintext = c2s_opening
+            .open_in_place(
+                Nonce::assume_unique_for_key(keys.c2s.1),
+                Aad::empty(),
+                &mut in_out,
+            )
+            .unwrap();
+        assert_eq!(b"hello server", plaintext);
+    }
+}
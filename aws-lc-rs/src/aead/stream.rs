@@ -0,0 +1,309 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use super::{Nonce, UnboundKey, NONCE_LEN};
+use crate::error::Unspecified;
+use core::fmt::Debug;
+
+/// The length of the nonce prefix that identifies a stream.
+///
+/// The remaining bytes of each chunk's nonce are a 32-bit big-endian chunk counter followed by
+/// a byte that is `1` for the final chunk and `0` otherwise.
+pub const STREAM_NONCE_PREFIX_LEN: usize = NONCE_LEN - 5;
+
+/// Seals a stream of chunks using the [STREAM] construction.
+///
+/// Each chunk is sealed independently under a nonce derived from the stream's nonce prefix,
+/// the chunk's position and whether it is the final chunk. This allows data of any size to be
+/// sealed without holding it in memory, while [`StreamOpeningKey`] still detects chunks that
+/// have been reordered, dropped, or truncated from the end of the stream.
+///
+/// The nonce prefix must never be reused with the same key. Random prefixes are only 56 bits,
+/// so a key with randomly chosen prefixes should not be used for more than about 2^20 streams.
+///
+/// ```
+/// # use aws_lc_rs::aead::{
+/// #     StreamOpeningKey, StreamSealingKey, UnboundKey, AES_256_GCM, STREAM_NONCE_PREFIX_LEN,
+/// # };
+/// # let key_bytes = [0u8; 32];
+/// let prefix = [1u8; STREAM_NONCE_PREFIX_LEN];
+/// let key = UnboundKey::new(&AES_256_GCM, &key_bytes)?;
+/// let mut sealing_key = StreamSealingKey::new(key, prefix);
+/// let mut first = b"first chunk".to_vec();
+/// sealing_key.seal_chunk(&mut first)?;
+/// let mut last = b"last chunk".to_vec();
+/// sealing_key.seal_last_chunk(&mut last)?;
+///
+/// let key = UnboundKey::new(&AES_256_GCM, &key_bytes)?;
+/// let mut opening_key = StreamOpeningKey::new(key, prefix);
+/// assert_eq!(b"first chunk", opening_key.open_chunk(&mut first)?);
+/// assert_eq!(b"last chunk", opening_key.open_last_chunk(&mut last)?);
+/// # Ok::<(), aws_lc_rs::error::Unspecified>(())
+/// ```
+///
+/// [STREAM]: https://eprint.iacr.org/2015/189.pdf
+//
+// # FIPS
+// The following conditions must be met:
+// * `UnboundKey`'s algorithm is one of:
+//   * `AES_128_GCM`
+//   * `AES_256_GCM`
+#[allow(clippy::module_name_repetitions)]
+pub struct StreamSealingKey {
+    key: UnboundKey,
+    nonces: StreamNonces,
+}
+
+impl StreamSealingKey {
+    /// Constructs a `StreamSealingKey` for a new stream identified by `nonce_prefix`.
+    #[must_use]
+    pub fn new(key: UnboundKey, nonce_prefix: [u8; STREAM_NONCE_PREFIX_LEN]) -> Self {
+        Self {
+            key,
+            nonces: StreamNonces::new(nonce_prefix),
+        }
+    }
+
+    /// Seals the next chunk of the stream in place, appending the tag.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the stream has reached the maximum number of chunks or the
+    /// encryption operation fails.
+    pub fn seal_chunk<InOut>(&mut self, in_out: &mut InOut) -> Result<(), Unspecified>
+    where
+        InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
+    {
+        let nonce = self.nonces.next(false)?;
+        self.seal(nonce, in_out)
+    }
+
+    /// Seals the final chunk of the stream in place, appending the tag.
+    ///
+    /// Every stream must end with a chunk sealed by this method, even if it is empty.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the stream has reached the maximum number of chunks or the
+    /// encryption operation fails.
+    pub fn seal_last_chunk<InOut>(mut self, in_out: &mut InOut) -> Result<(), Unspecified>
+    where
+        InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
+    {
+        let nonce = self.nonces.next(true)?;
+        self.seal(nonce, in_out)
+    }
+
+    fn seal<InOut>(&self, nonce: Nonce, in_out: &mut InOut) -> Result<(), Unspecified>
+    where
+        InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
+    {
+        self.key
+            .seal_in_place_append_tag(Some(nonce), &[], in_out)
+            .map(|_| ())
+    }
+}
+
+impl Debug for StreamSealingKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("StreamSealingKey")
+            .field("algorithm", self.key.algorithm())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Opens a stream of chunks sealed by a [`StreamSealingKey`].
+///
+/// Chunks must be opened in the order they were sealed, and the final chunk must be opened
+/// with [`StreamOpeningKey::open_last_chunk`]. A stream that was truncated, so that its final
+/// chunk is missing, is detected when the last chunk received fails to open as the final
+/// chunk. Once a chunk fails to open, all further chunks are rejected.
+//
+// # FIPS
+// The following conditions must be met:
+// * `UnboundKey`'s algorithm is one of:
+//   * `AES_128_GCM`
+//   * `AES_256_GCM`
+#[allow(clippy::module_name_repetitions)]
+pub struct StreamOpeningKey {
+    key: UnboundKey,
+    nonces: StreamNonces,
+}
+
+impl StreamOpeningKey {
+    /// Constructs a `StreamOpeningKey` for the stream identified by `nonce_prefix`.
+    #[must_use]
+    pub fn new(key: UnboundKey, nonce_prefix: [u8; STREAM_NONCE_PREFIX_LEN]) -> Self {
+        Self {
+            key,
+            nonces: StreamNonces::new(nonce_prefix),
+        }
+    }
+
+    /// Opens the next chunk of the stream in place, returning the plaintext without the tag.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the chunk is invalid, is out of order, is the final chunk, or a
+    /// previous chunk failed to open.
+    pub fn open_chunk<'in_out>(
+        &mut self,
+        in_out: &'in_out mut [u8],
+    ) -> Result<&'in_out mut [u8], Unspecified> {
+        let nonce = self.nonces.next(false)?;
+        self.open(nonce, in_out)
+    }
+
+    /// Opens the final chunk of the stream in place, returning the plaintext without the tag.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the chunk is invalid, is out of order, is not the final chunk,
+    /// or a previous chunk failed to open.
+    pub fn open_last_chunk(mut self, in_out: &mut [u8]) -> Result<&mut [u8], Unspecified> {
+        let nonce = self.nonces.next(true)?;
+        self.open(nonce, in_out)
+    }
+
+    fn open<'in_out>(
+        &mut self,
+        nonce: Nonce,
+        in_out: &'in_out mut [u8],
+    ) -> Result<&'in_out mut [u8], Unspecified> {
+        let result = self.key.open_within(nonce, &[], in_out, 0..);
+        if result.is_err() {
+            self.nonces.exhausted = true;
+        }
+        result
+    }
+}
+
+impl Debug for StreamOpeningKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("StreamOpeningKey")
+            .field("algorithm", self.key.algorithm())
+            .finish_non_exhaustive()
+    }
+}
+
+struct StreamNonces {
+    prefix: [u8; STREAM_NONCE_PREFIX_LEN],
+    counter: u32,
+    exhausted: bool,
+}
+
+impl StreamNonces {
+    fn new(prefix: [u8; STREAM_NONCE_PREFIX_LEN]) -> Self {
+        Self {
+            prefix,
+            counter: 0,
+            exhausted: false,
+        }
+    }
+
+    fn next(&mut self, last: bool) -> Result<Nonce, Unspecified> {
+        if self.exhausted {
+            return Err(Unspecified);
+        }
+        let mut nonce = [0u8; NONCE_LEN];
+        nonce[..STREAM_NONCE_PREFIX_LEN].copy_from_slice(&self.prefix);
+        nonce[STREAM_NONCE_PREFIX_LEN..NONCE_LEN - 1].copy_from_slice(&self.counter.to_be_bytes());
+        nonce[NONCE_LEN - 1] = u8::from(last);
+        match self.counter.checked_add(1) {
+            Some(counter) => self.counter = counter,
+            None => self.exhausted = true,
+        }
+        Ok(Nonce::assume_unique_for_key(nonce))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StreamOpeningKey, StreamSealingKey, STREAM_NONCE_PREFIX_LEN};
+    use crate::aead::{UnboundKey, AES_128_GCM, CHACHA20_POLY1305};
+
+    const KEY: [u8; 16] = [0x42; 16];
+    const PREFIX: [u8; STREAM_NONCE_PREFIX_LEN] = [0x24; STREAM_NONCE_PREFIX_LEN];
+
+    fn seal_stream(chunks: &[Vec<u8>]) -> Vec<Vec<u8>> {
+        let mut key = StreamSealingKey::new(UnboundKey::new(&AES_128_GCM, &KEY).unwrap(), PREFIX);
+        let (last, rest) = chunks.split_last().unwrap();
+        let mut sealed: Vec<Vec<u8>> = rest
+            .iter()
+            .map(|chunk| {
+                let mut chunk = chunk.clone();
+                key.seal_chunk(&mut chunk).unwrap();
+                chunk
+            })
+            .collect();
+        let mut last = last.clone();
+        key.seal_last_chunk(&mut last).unwrap();
+        sealed.push(last);
+        sealed
+    }
+
+    fn opening_key() -> StreamOpeningKey {
+        StreamOpeningKey::new(UnboundKey::new(&AES_128_GCM, &KEY).unwrap(), PREFIX)
+    }
+
+    #[test]
+    fn test_stream_round_trip() {
+        let chunks: Vec<Vec<u8>> = (0..1000u32)
+            .map(|i| i.to_be_bytes().repeat(i as usize % 7))
+            .collect();
+        let mut sealed = seal_stream(&chunks);
+
+        let mut key = opening_key();
+        let (last, rest) = sealed.split_last_mut().unwrap();
+        for (chunk, expected) in rest.iter_mut().zip(&chunks) {
+            assert_eq!(expected.as_slice(), key.open_chunk(chunk).unwrap());
+        }
+        assert_eq!(
+            chunks.last().unwrap().as_slice(),
+            key.open_last_chunk(last).unwrap()
+        );
+
+        // Works with any algorithm using `NONCE_LEN`-byte nonces.
+        let key_bytes = [0x42; 32];
+        let sealing_key = StreamSealingKey::new(
+            UnboundKey::new(&CHACHA20_POLY1305, &key_bytes).unwrap(),
+            PREFIX,
+        );
+        let mut chunk = b"chunk".to_vec();
+        sealing_key.seal_last_chunk(&mut chunk).unwrap();
+        let opening_key = StreamOpeningKey::new(
+            UnboundKey::new(&CHACHA20_POLY1305, &key_bytes).unwrap(),
+            PREFIX,
+        );
+        assert_eq!(b"chunk", opening_key.open_last_chunk(&mut chunk).unwrap());
+    }
+
+    #[test]
+    fn test_stream_truncation_detected() {
+        let chunks: Vec<Vec<u8>> = (0..4u8).map(|i| vec![i; 10]).collect();
+        let sealed = seal_stream(&chunks);
+
+        // Dropping the final chunk leaves a stream whose last chunk was not sealed as final.
+        let mut key = opening_key();
+        for chunk in &sealed[..2] {
+            key.open_chunk(&mut chunk.clone()).unwrap();
+        }
+        assert!(key.open_last_chunk(&mut sealed[2].clone()).is_err());
+
+        // The final chunk cannot be opened as a non-final chunk.
+        let mut key = opening_key();
+        for chunk in &sealed[..3] {
+            key.open_chunk(&mut chunk.clone()).unwrap();
+        }
+        assert!(key.open_chunk(&mut sealed[3].clone()).is_err());
+
+        // Reordered chunks are rejected, and the key stays unusable afterwards.
+        let mut key = opening_key();
+        assert!(key.open_chunk(&mut sealed[1].clone()).is_err());
+        assert!(key.open_chunk(&mut sealed[1].clone()).is_err());
+        assert!(key.open_last_chunk(&mut sealed[3].clone()).is_err());
+
+        // A different prefix identifies a different stream.
+        let mut key = StreamOpeningKey::new(
+            UnboundKey::new(&AES_128_GCM, &KEY).unwrap(),
+            [0u8; STREAM_NONCE_PREFIX_LEN],
+        );
+        assert!(key.open_chunk(&mut sealed[0].clone()).is_err());
+    }
+}
@@ -13,10 +13,12 @@ use crate::error::Unspecified;
 use crate::fips::indicator_check;
 use crate::hkdf;
 use crate::iv::FixedLength;
+use crate::rand::SecureRandom;
 use core::fmt::Debug;
 use core::mem::MaybeUninit;
 use core::ops::RangeFrom;
 use core::ptr::null;
+use zeroize::{Zeroize, Zeroizing};
 
 /// The maximum length of a nonce returned by our AEAD API.
 const MAX_NONCE_LEN: usize = NONCE_LEN;
@@ -50,6 +52,41 @@ impl UnboundKey {
         })
     }
 
+    /// Constructs an `UnboundKey` using `algorithm.key_len()` bytes from `rng` as the key.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `rng` fails to provide the key bytes.
+    pub fn generate(
+        algorithm: &'static Algorithm,
+        rng: &dyn SecureRandom,
+    ) -> Result<Self, Unspecified> {
+        let mut key_bytes = [0u8; MAX_KEY_LEN];
+        let key_bytes = &mut key_bytes[..algorithm.key_len()];
+        let result = rng
+            .fill(key_bytes)
+            .and_then(|()| Self::new(algorithm, key_bytes));
+        key_bytes.zeroize();
+        result
+    }
+
+    /// Constructs an `UnboundKey` using `algorithm.key_len()` bytes from `rng` as the key,
+    /// returning the generated key bytes along with the key.
+    ///
+    /// The returned bytes are secret key material and are zeroized when dropped. Prefer
+    /// [`UnboundKey::generate`] unless the key must be persisted or shared.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `rng` fails to provide the key bytes.
+    pub fn generate_with_key_bytes(
+        algorithm: &'static Algorithm,
+        rng: &dyn SecureRandom,
+    ) -> Result<(Self, Zeroizing<Vec<u8>>), Unspecified> {
+        let mut key_bytes = Zeroizing::new(vec![0u8; algorithm.key_len()]);
+        rng.fill(&mut key_bytes)?;
+        let key = Self::new(algorithm, &key_bytes)?;
+        Ok((key, key_bytes))
+    }
+
     #[inline]
     pub(crate) fn open_within<'in_out>(
         &self,
@@ -64,6 +101,7 @@ impl UnboundKey {
             .checked_sub(self.algorithm().tag_len())
             .ok_or(Unspecified)?;
         self.check_per_nonce_max_bytes(ciphertext_len)?;
+        self.check_aad_len(aad.len() as u64)?;
 
         match self.ctx {
             AeadCtx::AES_128_GCM_RANDNONCE(_) | AeadCtx::AES_256_GCM_RANDNONCE(_) => {
@@ -89,6 +127,7 @@ impl UnboundKey {
         out_plaintext: &mut [u8],
     ) -> Result<(), Unspecified> {
         self.check_per_nonce_max_bytes(in_ciphertext.len())?;
+        self.check_aad_len(aad.len() as u64)?;
 
         // ensure that the lengths match
         {
@@ -133,6 +172,7 @@ impl UnboundKey {
         InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
     {
         self.check_per_nonce_max_bytes(in_out.as_mut().len())?;
+        self.check_aad_len(aad.len() as u64)?;
         match nonce {
             Some(nonce) => self.seal_combined(nonce, aad, in_out),
             None => self.seal_combined_randnonce(aad, in_out),
@@ -147,6 +187,7 @@ impl UnboundKey {
         in_out: &mut [u8],
     ) -> Result<(Nonce, Tag), Unspecified> {
         self.check_per_nonce_max_bytes(in_out.len())?;
+        self.check_aad_len(aad.len() as u64)?;
         match nonce {
             Some(nonce) => self.seal_separate(nonce, aad, in_out),
             None => self.seal_separate_randnonce(aad, in_out),
@@ -164,6 +205,7 @@ impl UnboundKey {
         extra_out_and_tag: &mut [u8],
     ) -> Result<(), Unspecified> {
         self.check_per_nonce_max_bytes(in_out.len())?;
+        self.check_aad_len(aad.len() as u64)?;
         // ensure that the extra lengths match
         {
             let actual = extra_in.len() + self.algorithm().tag_len();
@@ -206,6 +248,17 @@ impl UnboundKey {
         self.algorithm
     }
 
+    /// Checks that `aad_len`, the total length of the additional authenticated data of a
+    /// message, is within the algorithm's limit, e.g. 2^61 - 1 bytes for AES-GCM. Callers that
+    /// accumulate the AAD in chunks pass the running total.
+    #[inline]
+    pub(crate) fn check_aad_len(&self, aad_len: u64) -> Result<(), Unspecified> {
+        if aad_len > self.algorithm().max_aad_len {
+            return Err(Unspecified);
+        }
+        Ok(())
+    }
+
     #[inline]
     pub(crate) fn check_per_nonce_max_bytes(&self, in_out_len: usize) -> Result<(), Unspecified> {
         if in_out_len as u64 > self.algorithm().max_input_len {
@@ -511,3 +564,99 @@ impl From<hkdf::Okm<'_, &'static Algorithm>> for UnboundKey {
         Self::new(algorithm, key_bytes).unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::aead::{
+        Aad, LessSafeKey, Nonce, UnboundKey, AES_128_GCM, AES_256_GCM, CHACHA20_POLY1305,
+    };
+    use crate::error::Unspecified;
+    use crate::rand::SystemRandom;
+    use crate::test::rand::FixedByteRandom;
+
+    #[derive(Debug)]
+    struct FailingRandom;
+
+    impl crate::rand::sealed::SecureRandom for FailingRandom {
+        fn fill_impl(&self, _dest: &mut [u8]) -> Result<(), Unspecified> {
+            Err(Unspecified)
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        const MESSAGE: &[u8] = b"hello, world";
+        let rng = SystemRandom::new();
+
+        for algorithm in [&AES_128_GCM, &AES_256_GCM, &CHACHA20_POLY1305] {
+            let (unbound_key, key_bytes) =
+                UnboundKey::generate_with_key_bytes(algorithm, &rng).unwrap();
+            assert_eq!(algorithm, unbound_key.algorithm());
+            assert_eq!(algorithm.key_len(), key_bytes.len());
+
+            let sealing_key = LessSafeKey::new(unbound_key);
+            let opening_key = LessSafeKey::new(UnboundKey::new(algorithm, &key_bytes).unwrap());
+
+            let mut in_out = MESSAGE.to_vec();
+            sealing_key
+                .seal_in_place_append_tag(
+                    Nonce::assume_unique_for_key([0u8; 12]),
+                    Aad::empty(),
+                    &mut in_out,
+                )
+                .unwrap();
+            let plaintext = opening_key
+                .open_in_place(
+                    Nonce::assume_unique_for_key([0u8; 12]),
+                    Aad::empty(),
+                    &mut in_out,
+                )
+                .unwrap();
+            assert_eq!(MESSAGE, plaintext);
+
+            let unbound_key = UnboundKey::generate(algorithm, &rng).unwrap();
+            assert_eq!(algorithm, unbound_key.algorithm());
+        }
+    }
+
+    #[test]
+    fn test_generate_uses_rng() {
+        let rng = FixedByteRandom { byte: 42 };
+        let (_, key_bytes) = UnboundKey::generate_with_key_bytes(&AES_256_GCM, &rng).unwrap();
+        assert_eq!(vec![42u8; 32], *key_bytes);
+    }
+
+    #[test]
+    fn test_generate_rng_failure() {
+        assert!(UnboundKey::generate(&AES_128_GCM, &FailingRandom).is_err());
+        assert!(UnboundKey::generate_with_key_bytes(&AES_256_GCM, &FailingRandom).is_err());
+    }
+
+    #[test]
+    fn test_check_aad_len() {
+        use crate::aead::aes_gcm::{GCM_MAX_AAD_LEN, GCM_SIV_MAX_AAD_LEN};
+        use crate::aead::AES_256_GCM_SIV;
+
+        // 2^64 - 1 bits, rounded down to whole bytes.
+        assert_eq!(0x1fff_ffff_ffff_ffff, GCM_MAX_AAD_LEN);
+        assert_eq!(u64::MAX / 8, GCM_MAX_AAD_LEN);
+
+        let gcm_key = UnboundKey::new(&AES_128_GCM, &[0u8; 16]).unwrap();
+        // Simulates the running total of AAD fed in chunks reaching the limit.
+        let mut total = 0u64;
+        for chunk_len in [1u64 << 60, (1 << 60) - 1] {
+            total += chunk_len;
+            assert!(gcm_key.check_aad_len(total).is_ok());
+        }
+        assert_eq!(GCM_MAX_AAD_LEN, total);
+        assert!(gcm_key.check_aad_len(total + 1).is_err());
+        assert!(gcm_key.check_aad_len(u64::MAX).is_err());
+
+        let gcm_siv_key = UnboundKey::new(&AES_256_GCM_SIV, &[0u8; 32]).unwrap();
+        assert!(gcm_siv_key.check_aad_len(GCM_SIV_MAX_AAD_LEN).is_ok());
+        assert!(gcm_siv_key.check_aad_len(GCM_SIV_MAX_AAD_LEN + 1).is_err());
+
+        let chacha_key = UnboundKey::new(&CHACHA20_POLY1305, &[0u8; 32]).unwrap();
+        assert!(chacha_key.check_aad_len(u64::MAX).is_ok());
+    }
+}
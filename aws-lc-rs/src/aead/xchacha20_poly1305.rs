@@ -0,0 +1,221 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! XChaCha20-Poly1305 as described in [draft-irtf-cfrg-xchacha].
+//!
+//! XChaCha20-Poly1305 extends ChaCha20-Poly1305 to a 192-bit nonce, which is long enough for
+//! nonces to be chosen at random for every message without tracking a counter. Since
+//! `aead::Nonce` is always `aead::NONCE_LEN` bytes, the construct is offered through its own
+//! `Key` type rather than as an `aead::Algorithm`.
+//!
+//! [draft-irtf-cfrg-xchacha]: https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-xchacha-03
+//!
+//! # FIPS
+//! The APIs offered in this module must not be used.
+
+use super::aead_ctx::AeadCtx;
+use super::Aad;
+use crate::aws_lc::{EVP_AEAD_CTX_open, EVP_AEAD_CTX_seal, EVP_AEAD_CTX};
+use crate::error::Unspecified;
+use crate::ptr::LcPtr;
+use core::fmt::Debug;
+use core::mem::MaybeUninit;
+
+/// The length of a key.
+pub const KEY_LEN: usize = 32;
+
+/// The length of a nonce.
+pub const NONCE_LEN: usize = 192 / 8;
+
+/// The length of a tag.
+pub const TAG_LEN: usize = 16;
+
+/// An XChaCha20-Poly1305 key, used for both sealing and opening.
+pub struct Key {
+    ctx: LcPtr<EVP_AEAD_CTX>,
+}
+
+unsafe impl Send for Key {}
+unsafe impl Sync for Key {}
+
+impl Key {
+    /// Constructs a new `Key`.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `key_bytes.len() != KEY_LEN`.
+    pub fn new(key_bytes: &[u8]) -> Result<Self, Unspecified> {
+        Ok(Self {
+            ctx: AeadCtx::xchacha20_poly1305_context(key_bytes, TAG_LEN)?,
+        })
+    }
+
+    /// Encrypts and signs (“seals”) data in place, appending the tag to the resulting
+    /// ciphertext.
+    ///
+    /// `nonce` must be unique for every call with this key. Filling it from a
+    /// `SecureRandom` is sufficient.
+    //
+    // # FIPS
+    // This method must not be used.
+    //
+    /// # Errors
+    /// `error::Unspecified` if encryption operation fails.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn seal_in_place_append_tag<A, InOut>(
+        &self,
+        nonce: &[u8; NONCE_LEN],
+        aad: Aad<A>,
+        in_out: &mut InOut,
+    ) -> Result<(), Unspecified>
+    where
+        A: AsRef<[u8]>,
+        InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
+    {
+        let aad = aad.as_ref();
+        let plaintext_len = in_out.as_mut().len();
+        in_out.extend([0u8; TAG_LEN].iter());
+        let in_out = in_out.as_mut();
+
+        let mut out_len = MaybeUninit::<usize>::uninit();
+        if 1 != unsafe {
+            EVP_AEAD_CTX_seal(
+                *self.ctx.as_const(),
+                in_out.as_mut_ptr(),
+                out_len.as_mut_ptr(),
+                plaintext_len + TAG_LEN,
+                nonce.as_ptr(),
+                nonce.len(),
+                in_out.as_ptr(),
+                plaintext_len,
+                aad.as_ptr(),
+                aad.len(),
+            )
+        } {
+            return Err(Unspecified);
+        }
+        Ok(())
+    }
+
+    /// Authenticates and decrypts (“opens”) data in place.
+    ///
+    /// On input, `in_out` must be the ciphertext followed by the tag. When `open_in_place()`
+    /// returns `Ok(plaintext)`, the input ciphertext has been overwritten by the plaintext;
+    /// `plaintext` will refer to the plaintext without the tag.
+    //
+    // # FIPS
+    // This method must not be used.
+    //
+    /// # Errors
+    /// `error::Unspecified` when ciphertext is invalid.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn open_in_place<'in_out, A>(
+        &self,
+        nonce: &[u8; NONCE_LEN],
+        aad: Aad<A>,
+        in_out: &'in_out mut [u8],
+    ) -> Result<&'in_out mut [u8], Unspecified>
+    where
+        A: AsRef<[u8]>,
+    {
+        let aad = aad.as_ref();
+        let plaintext_len = in_out.len().checked_sub(TAG_LEN).ok_or(Unspecified)?;
+
+        let mut out_len = MaybeUninit::<usize>::uninit();
+        if 1 != unsafe {
+            EVP_AEAD_CTX_open(
+                *self.ctx.as_const(),
+                in_out.as_mut_ptr(),
+                out_len.as_mut_ptr(),
+                plaintext_len,
+                nonce.as_ptr(),
+                nonce.len(),
+                in_out.as_ptr(),
+                in_out.len(),
+                aad.as_ptr(),
+                aad.len(),
+            )
+        } {
+            return Err(Unspecified);
+        }
+        Ok(&mut in_out[..plaintext_len])
+    }
+}
+
+impl Debug for Key {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.debug_struct("Key").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Key, NONCE_LEN, TAG_LEN};
+    use crate::aead::Aad;
+    use crate::{rand, test};
+
+    #[test]
+    fn test_draft_irtf_cfrg_xchacha_vector() {
+        // draft-irtf-cfrg-xchacha-03, Appendix A.3.1.
+        const PLAINTEXT: &[u8] = b"Ladies and Gentlemen of the class of '99: If I could offer \
+            you only one tip for the future, sunscreen would be it.";
+        let key = test::from_dirty_hex(
+            "808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f",
+        );
+        let nonce: [u8; NONCE_LEN] =
+            test::from_dirty_hex("404142434445464748494a4b4c4d4e4f5051525354555657")
+                .try_into()
+                .unwrap();
+        let aad = test::from_dirty_hex("50515253c0c1c2c3c4c5c6c7");
+        let expected = test::from_dirty_hex(
+            "bd6d179d3e83d43b9576579493c0e939572a1700252bfaccbed2902c21396cbb731c7f1b0b4aa644\
+             0bf3a82f4eda7e39ae64c6708c54c216cb96b72e1213b4522f8c9ba40db5d945b11b69b982c1bb9e\
+             3f3fac2bc369488f76b2383565d3fff921f9664c97637da9768812f615c68b13b52e\
+             c0875924c1c7987947deafd8780acf49",
+        );
+
+        let key = Key::new(&key).unwrap();
+        let mut in_out = PLAINTEXT.to_vec();
+        key.seal_in_place_append_tag(&nonce, Aad::from(&aad), &mut in_out)
+            .unwrap();
+        assert_eq!(expected, in_out);
+
+        let plaintext = key
+            .open_in_place(&nonce, Aad::from(&aad), &mut in_out)
+            .unwrap();
+        assert_eq!(PLAINTEXT, plaintext);
+    }
+
+    #[test]
+    fn test_random_nonce_round_trip() {
+        const PLAINTEXT: &[u8] = b"sealed under a random 192-bit nonce";
+        let key_bytes: [u8; super::KEY_LEN] =
+            rand::generate(&rand::SystemRandom::new()).unwrap().expose();
+        let key = Key::new(&key_bytes).unwrap();
+        let mut nonce = [0u8; NONCE_LEN];
+        rand::fill(&mut nonce).unwrap();
+
+        let mut in_out = PLAINTEXT.to_vec();
+        key.seal_in_place_append_tag(&nonce, Aad::from(b"header"), &mut in_out)
+            .unwrap();
+        assert_eq!(PLAINTEXT.len() + TAG_LEN, in_out.len());
+
+        let mut tampered = in_out.clone();
+        tampered[0] ^= 1;
+        assert!(key
+            .open_in_place(&nonce, Aad::from(b"header"), &mut tampered)
+            .is_err());
+        assert!(key
+            .open_in_place(&nonce, Aad::from(b"other"), &mut in_out.clone())
+            .is_err());
+        assert!(key
+            .open_in_place(&nonce, Aad::from(b"header"), &mut in_out[..TAG_LEN - 1])
+            .is_err());
+
+        let plaintext = key
+            .open_in_place(&nonce, Aad::from(b"header"), &mut in_out)
+            .unwrap();
+        assert_eq!(PLAINTEXT, plaintext);
+
+        assert!(Key::new(&key_bytes[1..]).is_err());
+    }
+}
@@ -50,6 +50,7 @@
 //! # Ok::<(), aws_lc_rs::error::Unspecified>(())
 //! ```
 mod ephemeral;
+mod transport;
 
 use crate::ec::encoding::sec1::{
     marshal_sec1_private_key, marshal_sec1_public_point, marshal_sec1_public_point_into_buffer,
@@ -63,12 +64,14 @@ use crate::ec::{encoding, evp_key_generate};
 use crate::error::{KeyRejected, Unspecified};
 use crate::hex;
 use crate::ptr::ConstPointer;
-pub use ephemeral::{agree_ephemeral, EphemeralPrivateKey};
+pub use ephemeral::{agree_ephemeral, agree_ephemeral_checked, EphemeralPrivateKey, PeerKeyError};
+pub use transport::derive_transport_keys;
 
 use crate::aws_lc::{
-    EVP_PKEY_derive, EVP_PKEY_derive_init, EVP_PKEY_derive_set_peer, EVP_PKEY_get0_EC_KEY,
-    NID_X9_62_prime256v1, NID_secp384r1, NID_secp521r1, EVP_PKEY, EVP_PKEY_EC, EVP_PKEY_X25519,
-    NID_X25519,
+    point_conversion_form_t, EC_KEY_get0_group, EC_KEY_get0_private_key, EC_KEY_get0_public_key,
+    EC_POINT_is_at_infinity, EC_POINT_mul, EC_POINT_new, EC_POINT_point2oct, EVP_PKEY_derive,
+    EVP_PKEY_derive_init, EVP_PKEY_derive_set_peer, EVP_PKEY_get0_EC_KEY, NID_X9_62_prime256v1,
+    NID_secp384r1, NID_secp521r1, EVP_PKEY, EVP_PKEY_EC, EVP_PKEY_X25519, NID_X25519,
 };
 
 use crate::buffer::Buffer;
@@ -84,7 +87,7 @@ use crate::pkcs8::Version;
 use crate::ptr::LcPtr;
 use core::fmt;
 use core::fmt::{Debug, Formatter};
-use core::ptr::null_mut;
+use core::ptr::{null, null_mut};
 
 #[allow(non_camel_case_types)]
 #[derive(PartialEq, Eq)]
@@ -145,6 +148,16 @@ pub struct Algorithm {
     id: AlgorithmID,
 }
 
+impl Algorithm {
+    /// The length, in bytes, of the shared secret produced by key agreement with this
+    /// algorithm: 32 for X25519, and the size of an element of the curve's field for ECDH.
+    #[inline]
+    #[must_use]
+    pub const fn shared_secret_len(&self) -> usize {
+        self.id.private_key_len()
+    }
+}
+
 impl Debug for Algorithm {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
         f.write_str(&format!("Algorithm {{ {:?} }}", self.id))
@@ -286,7 +299,7 @@ impl PrivateKey {
     /// attempt to automatically detect other key formats. This function supports unencrypted
     /// PKCS#8 `PrivateKeyInfo` structures as well as key type specific formats.
     ///
-    /// X25519 keys are not supported. See `PrivateKey::as_der`.
+    /// X25519 keys are only accepted as PKCS#8 `PrivateKeyInfo` structures.
     ///
     /// # Errors
     /// `error::KeyRejected` if parsing failed or key otherwise unacceptable.
@@ -297,7 +310,9 @@ impl PrivateKey {
         key_bytes: &[u8],
     ) -> Result<Self, KeyRejected> {
         if AlgorithmID::X25519 == alg.id {
-            return Err(KeyRejected::invalid_encoding());
+            let evp_pkey =
+                LcPtr::<EVP_PKEY>::parse_rfc5208_private_key(key_bytes, EVP_PKEY_X25519)?;
+            return Ok(Self::new(alg, evp_pkey));
         }
         let evp_pkey = LcPtr::<EVP_PKEY>::parse_rfc5208_private_key(key_bytes, EVP_PKEY_EC)
             .or(parse_rfc5915_private_key(key_bytes, alg.id.nid()))?;
@@ -309,14 +324,15 @@ impl PrivateKey {
         Ok(Self::new(alg, evp_pkey))
     }
 
-    /// Constructs an ECDH key from private key bytes
+    /// Constructs a private key from its raw bytes for the given algorithm.
     ///
-    /// The private key must encoded as a big-endian fixed-length integer. For
-    /// example, a P-256 private key must be 32 bytes prefixed with leading
-    /// zeros as needed.
+    /// The algorithm is never inferred from the input. `key_bytes` must be exactly the
+    /// algorithm's scalar length: 32 bytes for X25519, or the big-endian, zero-padded
+    /// field size for ECDH (32, 48 and 66 bytes for P-256, P-384 and P-521 respectively).
     ///
     /// # Errors
-    /// `error::KeyRejected` if parsing failed or key otherwise unacceptable.
+    /// `error::KeyRejected` if `key_bytes` has the wrong length for `alg`, or the key is
+    /// otherwise unacceptable.
     pub fn from_private_key(
         alg: &'static Algorithm,
         key_bytes: &[u8],
@@ -464,15 +480,9 @@ impl AsDer<EcPrivateKeyRfc5915Der<'static>> for PrivateKey {
 impl AsDer<Pkcs8V1Der<'static>> for PrivateKey {
     /// Serializes the key as a PKCS #8 private key structure.
     ///
-    /// X25519 is not supported.
-    ///
     /// # Errors
     /// `error::Unspecified`  if serialization failed.
     fn as_der(&self) -> Result<Pkcs8V1Der<'static>, Unspecified> {
-        if AlgorithmID::X25519 == self.inner_key.algorithm().id {
-            return Err(Unspecified);
-        }
-
         Ok(Pkcs8V1Der::new(
             self.inner_key
                 .get_evp_pkey()
@@ -697,28 +707,135 @@ pub fn agree<B: AsRef<[u8]>, F, R, E>(
 where
     F: FnOnce(&[u8]) -> Result<R, E>,
 {
+    let mut buffer = [0u8; MAX_AGREEMENT_SECRET_LEN];
+    let secret =
+        agree_to_buffer(my_private_key, peer_public_key, &mut buffer).or(Err(error_value))?;
+    kdf(secret)
+}
+
+/// Performs a key agreement like [`agree`], but writes the raw key material into `out` rather
+/// than passing it to a `kdf` callback, and returns its length.
+///
+/// The key material is written to the start of `out`, which must be at least
+/// `my_private_key.algorithm().shared_secret_len()` bytes long. The caller is responsible for
+/// zeroizing it when it is no longer needed.
+///
+// # FIPS
+// Use this function with one of the following key algorithms:
+// * `ECDH_P256`
+// * `ECDH_P384`
+// * `ECDH_P521`
+//
+/// # Errors
+/// `error::Unspecified` if `out` is too small, `peer_public_key` does not match
+/// `my_private_key`'s algorithm or is not a valid public key, or the key agreement otherwise
+/// fails.
+pub fn agree_into<B: AsRef<[u8]>>(
+    my_private_key: &PrivateKey,
+    peer_public_key: &UnparsedPublicKey<B>,
+    out: &mut [u8],
+) -> Result<usize, Unspecified> {
+    if out.len() < my_private_key.algorithm().shared_secret_len() {
+        return Err(Unspecified);
+    }
+    Ok(agree_to_buffer(my_private_key, peer_public_key, out)?.len())
+}
+
+fn agree_to_buffer<'a, B: AsRef<[u8]>>(
+    my_private_key: &PrivateKey,
+    peer_public_key: &UnparsedPublicKey<B>,
+    buffer: &'a mut [u8],
+) -> Result<&'a [u8], Unspecified> {
     let expected_alg = my_private_key.algorithm();
     let expected_nid = expected_alg.id.nid();
 
     if peer_public_key.alg != expected_alg {
-        return Err(error_value);
+        return Err(Unspecified);
     }
 
     let peer_pub_bytes = peer_public_key.bytes.as_ref();
 
-    let mut buffer = [0u8; MAX_AGREEMENT_SECRET_LEN];
-
-    let secret: &[u8] = match &my_private_key.inner_key {
-        KeyInner::X25519(priv_key) => {
-            x25519_diffie_hellman(&mut buffer, priv_key, peer_pub_bytes).or(Err(error_value))?
-        }
+    match &my_private_key.inner_key {
+        KeyInner::X25519(priv_key) => Ok(x25519_diffie_hellman(buffer, priv_key, peer_pub_bytes)?),
         KeyInner::ECDH_P256(priv_key)
         | KeyInner::ECDH_P384(priv_key)
         | KeyInner::ECDH_P521(priv_key) => {
-            ec_key_ecdh(&mut buffer, priv_key, peer_pub_bytes, expected_nid).or(Err(error_value))?
+            ec_key_ecdh(buffer, priv_key, peer_pub_bytes, expected_nid)
         }
+    }
+}
+
+/// Computes the ECDH shared point `d * Q` of `my_private_key` and `peer_public_key` and returns
+/// it encoded as an uncompressed elliptic curve point, `0x04 || X || Y`.
+///
+/// **This is not a standard key agreement output.** ECDH as specified in NIST SP 800-56A and
+/// SEC 1, and as computed by [`agree`], uses only the X coordinate of the shared point. A few
+/// libraries expose the whole point; this function exists to test interoperability with them.
+/// The X coordinate of the returned point is equal to the secret passed to `agree`'s `kdf`.
+///
+/// `peer_public_key` is validated as it is by [`agree`]. X25519 keys are not supported.
+///
+// # FIPS
+// This function must not be used.
+//
+/// # Errors
+/// `error::Unspecified` if `my_private_key` is an X25519 key, `peer_public_key` does not match
+/// its algorithm or is not a valid public key, or the computation otherwise fails.
+pub fn shared_point_uncompressed<B: AsRef<[u8]>>(
+    my_private_key: &PrivateKey,
+    peer_public_key: &UnparsedPublicKey<B>,
+) -> Result<Vec<u8>, Unspecified> {
+    let expected_alg = my_private_key.algorithm();
+    if peer_public_key.alg != expected_alg {
+        return Err(Unspecified);
+    }
+    let priv_key = match &my_private_key.inner_key {
+        KeyInner::ECDH_P256(priv_key)
+        | KeyInner::ECDH_P384(priv_key)
+        | KeyInner::ECDH_P521(priv_key) => priv_key,
+        KeyInner::X25519(_) => return Err(Unspecified),
     };
-    kdf(secret)
+    let peer_key =
+        encoding::parse_ec_public_key(peer_public_key.bytes.as_ref(), expected_alg.id.nid())?;
+
+    let ec_key = ConstPointer::new(unsafe { EVP_PKEY_get0_EC_KEY(*priv_key.as_const()) })?;
+    let ec_group = ConstPointer::new(unsafe { EC_KEY_get0_group(*ec_key) })?;
+    let private_bn = ConstPointer::new(unsafe { EC_KEY_get0_private_key(*ec_key) })?;
+    let peer_ec_key = ConstPointer::new(unsafe { EVP_PKEY_get0_EC_KEY(*peer_key.as_const()) })?;
+    let peer_point = ConstPointer::new(unsafe { EC_KEY_get0_public_key(*peer_ec_key) })?;
+
+    let mut shared_point = LcPtr::new(unsafe { EC_POINT_new(*ec_group) })?;
+    if 1 != unsafe {
+        EC_POINT_mul(
+            *ec_group,
+            *shared_point.as_mut(),
+            null(),
+            *peer_point,
+            *private_bn,
+            null_mut(),
+        )
+    } {
+        return Err(Unspecified);
+    }
+    if 1 == unsafe { EC_POINT_is_at_infinity(*ec_group, *shared_point.as_const()) } {
+        return Err(Unspecified);
+    }
+
+    let mut buffer = vec![0u8; expected_alg.id.pub_key_len()];
+    let out_len = unsafe {
+        EC_POINT_point2oct(
+            *ec_group,
+            *shared_point.as_const(),
+            point_conversion_form_t::POINT_CONVERSION_UNCOMPRESSED,
+            buffer.as_mut_ptr(),
+            buffer.len(),
+            null_mut(),
+        )
+    };
+    if out_len != buffer.len() {
+        return Err(Unspecified);
+    }
+    Ok(buffer)
 }
 
 // Current max secret length is P-521's.
@@ -727,7 +844,7 @@ const MAX_AGREEMENT_SECRET_LEN: usize = AlgorithmID::ECDH_P521.private_key_len()
 #[inline]
 #[allow(clippy::needless_pass_by_value)]
 fn ec_key_ecdh<'a>(
-    buffer: &'a mut [u8; MAX_AGREEMENT_SECRET_LEN],
+    buffer: &'a mut [u8],
     priv_key: &LcPtr<EVP_PKEY>,
     peer_pub_key_bytes: &[u8],
     nid: i32,
@@ -761,7 +878,7 @@ fn ec_key_ecdh<'a>(
 
 #[inline]
 fn x25519_diffie_hellman<'a>(
-    buffer: &'a mut [u8; MAX_AGREEMENT_SECRET_LEN],
+    buffer: &'a mut [u8],
     priv_key: &LcPtr<EVP_PKEY>,
     peer_pub_key: &[u8],
 ) -> Result<&'a [u8], ()> {
@@ -812,8 +929,8 @@ fn try_parse_x25519_public_key_raw_bytes(key_bytes: &[u8]) -> Result<LcPtr<EVP_P
 #[cfg(test)]
 mod tests {
     use crate::agreement::{
-        agree, Algorithm, PrivateKey, PublicKey, UnparsedPublicKey, ECDH_P256, ECDH_P384,
-        ECDH_P521, X25519,
+        agree, agree_into, shared_point_uncompressed, Algorithm, PrivateKey, PublicKey,
+        UnparsedPublicKey, ECDH_P256, ECDH_P384, ECDH_P521, X25519,
     };
     use crate::encoding::{
         AsBigEndian, AsDer, Curve25519SeedBin, EcPrivateKeyBin, EcPrivateKeyRfc5915Der,
@@ -880,6 +997,70 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_static_private_key_reuse() {
+        // A long-term key loaded from PKCS#8 is only borrowed by `agree`, so it can be used
+        // for any number of agreements.
+        let pkcs8: Pkcs8V1Der = PrivateKey::generate(&ECDH_P256).unwrap().as_der().unwrap();
+        let static_key = PrivateKey::from_private_key_der(&ECDH_P256, pkcs8.as_ref()).unwrap();
+
+        let peer_private = PrivateKey::generate(&ECDH_P256).unwrap();
+        let peer_public = peer_private.compute_public_key().unwrap();
+        let peer_public = UnparsedPublicKey::new(&ECDH_P256, &peer_public);
+
+        let first = agree(&static_key, &peer_public, (), |s| Ok(s.to_vec())).unwrap();
+        let second = agree(&static_key, &peer_public, (), |s| Ok(s.to_vec())).unwrap();
+        assert_eq!(first, second);
+
+        // Peer keys for, or encoded on, another curve are rejected.
+        let p384_public = PrivateKey::generate(&ECDH_P384)
+            .unwrap()
+            .compute_public_key()
+            .unwrap();
+        let wrong_alg = UnparsedPublicKey::new(&ECDH_P384, &p384_public);
+        assert!(agree(&static_key, &wrong_alg, (), |_| Ok(())).is_err());
+        let wrong_curve = UnparsedPublicKey::new(&ECDH_P256, p384_public.as_ref());
+        assert!(agree(&static_key, &wrong_curve, (), |_| Ok(())).is_err());
+    }
+
+    #[test]
+    fn test_x448_is_unsupported() {
+        use crate::aws_lc::{EVP_PKEY, EVP_PKEY_X448};
+        use crate::ptr::LcPtr;
+
+        // AWS-LC defines the X448 key type but does not implement it, so no `X448` algorithm
+        // is provided. This fails if a future AWS-LC starts accepting X448 keys.
+        assert!(LcPtr::<EVP_PKEY>::parse_raw_public_key(&[9u8; 56], EVP_PKEY_X448).is_err());
+        assert!(LcPtr::<EVP_PKEY>::parse_raw_private_key(&[9u8; 56], EVP_PKEY_X448).is_err());
+    }
+
+    #[test]
+    fn test_private_key_pkcs8_round_trip() {
+        for alg in [&X25519, &ECDH_P256, &ECDH_P384, &ECDH_P521] {
+            let private_key = PrivateKey::generate(alg).unwrap();
+            let pkcs8: Pkcs8V1Der = private_key.as_der().unwrap();
+            let parsed = PrivateKey::from_private_key_der(alg, pkcs8.as_ref()).unwrap();
+            assert_eq!(
+                private_key.compute_public_key().unwrap().as_ref(),
+                parsed.compute_public_key().unwrap().as_ref()
+            );
+
+            let peer_public = PrivateKey::generate(alg)
+                .unwrap()
+                .compute_public_key()
+                .unwrap();
+            let peer_public = UnparsedPublicKey::new(alg, &peer_public);
+            let expected = agree(&private_key, &peer_public, (), |s| Ok(s.to_vec())).unwrap();
+            let actual = agree(&parsed, &peer_public, (), |s| Ok(s.to_vec())).unwrap();
+            assert_eq!(expected, actual);
+        }
+
+        let x25519_pkcs8: Pkcs8V1Der = PrivateKey::generate(&X25519).unwrap().as_der().unwrap();
+        assert!(PrivateKey::from_private_key_der(&ECDH_P256, x25519_pkcs8.as_ref()).is_err());
+        let p256_pkcs8: Pkcs8V1Der = PrivateKey::generate(&ECDH_P256).unwrap().as_der().unwrap();
+        assert!(PrivateKey::from_private_key_der(&X25519, p256_pkcs8.as_ref()).is_err());
+    }
+
     #[test]
     fn test_agreement_invalid_keys() {
         fn test_with_key(alg: &'static Algorithm, my_private_key: &PrivateKey, test_key: &[u8]) {
@@ -1289,6 +1470,33 @@ mod tests {
         public_keys
     }
 
+    #[test]
+    fn test_from_private_key_lengths() {
+        for (alg, scalar_len) in [
+            (&X25519, 32),
+            (&ECDH_P256, 32),
+            (&ECDH_P384, 48),
+            (&ECDH_P521, 66),
+        ] {
+            let mut key_bytes = vec![0u8; scalar_len];
+            key_bytes[scalar_len - 1] = 1;
+
+            let private_key = PrivateKey::from_private_key(alg, &key_bytes).unwrap();
+            assert_eq!(alg, private_key.algorithm());
+
+            assert!(PrivateKey::from_private_key(alg, &key_bytes[1..]).is_err());
+            key_bytes.push(0);
+            assert!(PrivateKey::from_private_key(alg, &key_bytes).is_err());
+            assert!(PrivateKey::from_private_key(alg, &[]).is_err());
+        }
+
+        // A P-256 scalar is not accepted as a P-384 or P-521 key.
+        let p256_key = PrivateKey::generate(&ECDH_P256).unwrap();
+        let p256_bytes = AsBigEndian::<EcPrivateKeyBin>::as_be_bytes(&p256_key).unwrap();
+        assert!(PrivateKey::from_private_key(&ECDH_P384, p256_bytes.as_ref()).is_err());
+        assert!(PrivateKey::from_private_key(&ECDH_P521, p256_bytes.as_ref()).is_err());
+    }
+
     #[test]
     fn private_key_drop() {
         let private_key = PrivateKey::generate(&ECDH_P256).unwrap();
@@ -1299,4 +1507,106 @@ mod tests {
         let _ = AsBigEndian::<EcPublicKeyUncompressedBin>::as_be_bytes(&public_key).unwrap();
         let _ = AsDer::<PublicKeyX509Der>::as_der(&public_key).unwrap();
     }
+
+    #[test]
+    fn test_agree_into() {
+        assert_eq!(32, X25519.shared_secret_len());
+        assert_eq!(32, ECDH_P256.shared_secret_len());
+        assert_eq!(48, ECDH_P384.shared_secret_len());
+        assert_eq!(66, ECDH_P521.shared_secret_len());
+
+        for alg in [&X25519, &ECDH_P256, &ECDH_P384, &ECDH_P521] {
+            let my_private = PrivateKey::generate(alg).unwrap();
+            let peer_private = PrivateKey::generate(alg).unwrap();
+            let peer_public = peer_private.compute_public_key().unwrap();
+            let peer_public = UnparsedPublicKey::new(alg, &peer_public);
+
+            let expected =
+                agree(&my_private, &peer_public, (), |secret| Ok(secret.to_vec())).unwrap();
+            assert_eq!(alg.shared_secret_len(), expected.len());
+
+            let mut out = [0u8; 80];
+            let len = agree_into(&my_private, &peer_public, &mut out).unwrap();
+            assert_eq!(alg.shared_secret_len(), len);
+            assert_eq!(expected.as_slice(), &out[..len]);
+
+            let mut exact = vec![0u8; alg.shared_secret_len()];
+            assert_eq!(
+                alg.shared_secret_len(),
+                agree_into(&my_private, &peer_public, &mut exact).unwrap()
+            );
+            assert_eq!(expected, exact);
+
+            let mut too_small = vec![0u8; alg.shared_secret_len() - 1];
+            assert!(agree_into(&my_private, &peer_public, &mut too_small).is_err());
+        }
+
+        let x25519_private = PrivateKey::generate(&X25519).unwrap();
+        let p384_public = PrivateKey::generate(&ECDH_P384)
+            .unwrap()
+            .compute_public_key()
+            .unwrap();
+        assert!(agree_into(
+            &x25519_private,
+            &UnparsedPublicKey::new(&ECDH_P384, &p384_public),
+            &mut [0u8; 80]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_shared_point_uncompressed() {
+        for alg in [&ECDH_P256, &ECDH_P384, &ECDH_P521] {
+            let my_private = PrivateKey::generate(alg).unwrap();
+            let peer_private = PrivateKey::generate(alg).unwrap();
+            let my_public = my_private.compute_public_key().unwrap();
+            let peer_public = peer_private.compute_public_key().unwrap();
+
+            let point =
+                shared_point_uncompressed(&my_private, &UnparsedPublicKey::new(alg, &peer_public))
+                    .unwrap();
+            assert_eq!(my_public.as_ref().len(), point.len());
+            assert_eq!(0x04, point[0]);
+            assert_eq!(
+                point,
+                shared_point_uncompressed(&peer_private, &UnparsedPublicKey::new(alg, &my_public))
+                    .unwrap()
+            );
+
+            // The standard ECDH secret is the X coordinate of the shared point.
+            let coordinate_len = (point.len() - 1) / 2;
+            agree(
+                &my_private,
+                &UnparsedPublicKey::new(alg, &peer_public),
+                (),
+                |secret| {
+                    assert_eq!(&point[1..=coordinate_len], secret);
+                    Ok(())
+                },
+            )
+            .unwrap();
+        }
+
+        let p256_public = PrivateKey::generate(&ECDH_P256)
+            .unwrap()
+            .compute_public_key()
+            .unwrap();
+        let p384_private = PrivateKey::generate(&ECDH_P384).unwrap();
+        assert!(shared_point_uncompressed(
+            &p384_private,
+            &UnparsedPublicKey::new(&ECDH_P256, &p256_public)
+        )
+        .is_err());
+
+        let x25519_private = PrivateKey::generate(&X25519).unwrap();
+        let x25519_public = PrivateKey::generate(&X25519)
+            .unwrap()
+            .compute_public_key()
+            .unwrap();
+        assert!(shared_point_uncompressed(
+            &x25519_private,
+            &UnparsedPublicKey::new(&X25519, &x25519_public)
+        )
+        .is_err());
+    }
 }
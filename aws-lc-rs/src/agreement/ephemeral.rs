@@ -1,7 +1,11 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
-use crate::agreement::{agree, Algorithm, PrivateKey, PublicKey, UnparsedPublicKey};
+use crate::agreement::{
+    agree, try_parse_x25519_public_key_bytes, Algorithm, AlgorithmID, PrivateKey, PublicKey,
+    UnparsedPublicKey,
+};
+use crate::ec::encoding::parse_ec_public_key;
 use crate::error::Unspecified;
 use crate::rand::SecureRandom;
 use core::fmt;
@@ -40,6 +44,28 @@ impl EphemeralPrivateKey {
         Ok(Self(PrivateKey::generate(alg)?))
     }
 
+    /// Generates a new ephemeral private key for the given algorithm and returns it along with
+    /// its public key, serialized as by [`Self::compute_public_key`], to send to the peer.
+    ///
+    /// # *ring* Compatibility
+    ///  Our implementation ignores the `SecureRandom` parameter.
+    // # FIPS
+    // Use this function with one of the following algorithms:
+    // * `ECDH_P256`
+    // * `ECDH_P384`
+    // * `ECDH_P521`
+    //
+    /// # Errors
+    /// `error::Unspecified` when operation fails due to internal error.
+    pub fn generate_with_public(
+        alg: &'static Algorithm,
+        rng: &dyn SecureRandom,
+    ) -> Result<(Self, Vec<u8>), Unspecified> {
+        let private_key = Self::generate(alg, rng)?;
+        let public_key = private_key.compute_public_key()?.as_ref().to_vec();
+        Ok((private_key, public_key))
+    }
+
     #[cfg(test)]
     #[allow(missing_docs, clippy::missing_errors_doc)]
     pub fn generate_for_test(
@@ -110,6 +136,71 @@ where
     agree(&my_private_key.0, peer_public_key, error_value, kdf)
 }
 
+/// The reason `agree_ephemeral_checked` failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PeerKeyError<E> {
+    /// The peer's public key is for a different algorithm than the private key, is not
+    /// encoded in a supported form, or is not a valid key for the algorithm, e.g. an
+    /// elliptic curve point that is not on the curve.
+    InvalidPeerKey,
+
+    /// The key agreement failed for a reason unrelated to the peer's public key.
+    Unspecified,
+
+    /// `kdf` returned an error.
+    Kdf(E),
+}
+
+/// Performs a key agreement like [`agree_ephemeral`], but reports whether a failure was caused
+/// by the peer's public key.
+///
+/// This is intended for diagnosing handshake failures. Protocols should generally not reveal
+/// to the peer why a key agreement failed.
+///
+// # FIPS
+// Use this function with one of the following key algorithms:
+// * `ECDH_P256`
+// * `ECDH_P384`
+// * `ECDH_P521`
+//
+/// # Errors
+/// * `PeerKeyError::InvalidPeerKey` if `peer_public_key` does not match `my_private_key`'s
+///   algorithm, cannot be decoded, or is not a valid public key.
+/// * `PeerKeyError::Unspecified` if the key agreement otherwise fails.
+/// * `PeerKeyError::Kdf` with the error returned by `kdf`.
+#[allow(clippy::needless_pass_by_value)]
+pub fn agree_ephemeral_checked<B: AsRef<[u8]>, F, R, E>(
+    my_private_key: EphemeralPrivateKey,
+    peer_public_key: &UnparsedPublicKey<B>,
+    kdf: F,
+) -> Result<R, PeerKeyError<E>>
+where
+    F: FnOnce(&[u8]) -> Result<R, E>,
+{
+    let alg = my_private_key.algorithm();
+    if peer_public_key.alg != alg {
+        return Err(PeerKeyError::InvalidPeerKey);
+    }
+    let peer_pub_bytes = peer_public_key.bytes.as_ref();
+    let peer_key_valid = match alg.id {
+        AlgorithmID::X25519 => try_parse_x25519_public_key_bytes(peer_pub_bytes).is_ok(),
+        AlgorithmID::ECDH_P256 | AlgorithmID::ECDH_P384 | AlgorithmID::ECDH_P521 => {
+            parse_ec_public_key(peer_pub_bytes, alg.id.nid()).is_ok()
+        }
+    };
+    if !peer_key_valid {
+        return Err(PeerKeyError::InvalidPeerKey);
+    }
+
+    agree(
+        &my_private_key.0,
+        peer_public_key,
+        PeerKeyError::Unspecified,
+        |secret| kdf(secret).map_err(PeerKeyError::Kdf),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use crate::agreement::{AlgorithmID, PublicKey};
@@ -500,4 +591,107 @@ mod tests {
             Ok(Vec::from(agreed_value))
         })
     }
+
+    #[test]
+    fn test_agree_ephemeral_checked() {
+        use crate::agreement::{
+            agree_ephemeral_checked, EphemeralPrivateKey, PeerKeyError, UnparsedPublicKey,
+            ECDH_P256, ECDH_P384, X25519,
+        };
+
+        let rng = rand::SystemRandom::new();
+        let peer = EphemeralPrivateKey::generate(&ECDH_P256, &rng).unwrap();
+        let peer_public_key = peer.compute_public_key().unwrap();
+
+        let my_private_key = EphemeralPrivateKey::generate(&ECDH_P256, &rng).unwrap();
+        let secret = agree_ephemeral_checked(
+            my_private_key,
+            &UnparsedPublicKey::new(&ECDH_P256, peer_public_key.as_ref()),
+            |secret| Ok::<_, ()>(secret.to_vec()),
+        )
+        .unwrap();
+        assert_eq!(32, secret.len());
+
+        // An uncompressed point that is not on the curve.
+        let mut off_curve = peer_public_key.as_ref().to_vec();
+        off_curve[64] ^= 0x01;
+        let my_private_key = EphemeralPrivateKey::generate(&ECDH_P256, &rng).unwrap();
+        assert_eq!(
+            Err(PeerKeyError::InvalidPeerKey),
+            agree_ephemeral_checked(
+                my_private_key,
+                &UnparsedPublicKey::new(&ECDH_P256, &off_curve),
+                |_| Ok::<_, ()>(()),
+            )
+        );
+
+        // A key for a different algorithm.
+        let my_private_key = EphemeralPrivateKey::generate(&ECDH_P384, &rng).unwrap();
+        assert_eq!(
+            Err(PeerKeyError::InvalidPeerKey),
+            agree_ephemeral_checked(
+                my_private_key,
+                &UnparsedPublicKey::new(&ECDH_P256, peer_public_key.as_ref()),
+                |_| Ok::<_, ()>(()),
+            )
+        );
+
+        let my_private_key = EphemeralPrivateKey::generate(&X25519, &rng).unwrap();
+        assert_eq!(
+            Err(PeerKeyError::InvalidPeerKey),
+            agree_ephemeral_checked(
+                my_private_key,
+                &UnparsedPublicKey::new(&X25519, [0u8; 31]),
+                |_| Ok::<_, ()>(()),
+            )
+        );
+
+        // Errors from the KDF are passed through.
+        let my_private_key = EphemeralPrivateKey::generate(&ECDH_P256, &rng).unwrap();
+        assert_eq!(
+            Err(PeerKeyError::Kdf("kdf failed")),
+            agree_ephemeral_checked(
+                my_private_key,
+                &UnparsedPublicKey::new(&ECDH_P256, peer_public_key.as_ref()),
+                |_| Err::<(), _>("kdf failed"),
+            )
+        );
+    }
+
+    #[test]
+    fn test_generate_with_public() {
+        let rng = rand::SystemRandom::new();
+        for alg in [
+            &agreement::X25519,
+            &agreement::ECDH_P256,
+            &agreement::ECDH_P384,
+            &agreement::ECDH_P521,
+        ] {
+            let (my_private, my_public) =
+                agreement::EphemeralPrivateKey::generate_with_public(alg, &rng).unwrap();
+            assert_eq!(alg, my_private.algorithm());
+            assert_eq!(
+                my_private.compute_public_key().unwrap().as_ref(),
+                my_public.as_slice()
+            );
+
+            let (peer_private, peer_public) =
+                agreement::EphemeralPrivateKey::generate_with_public(alg, &rng).unwrap();
+            let my_secret = agreement::agree_ephemeral(
+                my_private,
+                &agreement::UnparsedPublicKey::new(alg, &peer_public),
+                (),
+                |secret| Ok(secret.to_vec()),
+            )
+            .unwrap();
+            let peer_secret = agreement::agree_ephemeral(
+                peer_private,
+                &agreement::UnparsedPublicKey::new(alg, &my_public),
+                (),
+                |secret| Ok(secret.to_vec()),
+            )
+            .unwrap();
+            assert_eq!(my_secret, peer_secret);
+        }
+    }
 }
@@ -0,0 +1,164 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use crate::aead::nonce_sequence::{Counter64, Counter64Builder};
+use crate::aead::{self, BoundKey, OpeningKey, SealingKey, UnboundKey};
+use crate::agreement::{agree, AlgorithmID, PrivateKey, UnparsedPublicKey};
+use crate::error::Unspecified;
+use crate::hkdf;
+
+const TRANSPORT_KEY_INFO: &[u8] = b"aws-lc-rs agreement transport key";
+
+/// Performs a key agreement and derives a pair of AEAD keys for a bidirectional channel with
+/// the peer.
+///
+/// Each direction of the channel uses its own key. The shared secret is extracted with HKDF,
+/// salted with both public keys, and expanded into one key per direction. The direction is
+/// determined by ordering the two public keys, so no role needs to be agreed upon: the
+/// `SealingKey` returned to one party opens with the `OpeningKey` returned to the other.
+/// Both keys use a `Counter64` nonce sequence starting at zero, so messages in each direction
+/// must be opened in the order they were sealed.
+///
+/// `peer_public_key` must be encoded as returned by [`PrivateKey::compute_public_key`], i.e.
+/// a raw X25519 public key or an uncompressed elliptic curve point. The HKDF digest is
+/// SHA-256 for `X25519` and `ECDH_P256`, SHA-384 for `ECDH_P384` and SHA-512 for `ECDH_P521`.
+///
+// # FIPS
+// Use this function with one of the following key algorithms:
+// * `ECDH_P256`
+// * `ECDH_P384`
+// * `ECDH_P521`
+//
+/// # Errors
+/// `error::Unspecified` if `peer_public_key` is not a valid public key for `my_private_key`'s
+/// algorithm, is not in the expected encoding, is equal to `my_private_key`'s public key, or
+/// if the key agreement otherwise fails.
+#[allow(clippy::module_name_repetitions)]
+pub fn derive_transport_keys<B: AsRef<[u8]>>(
+    my_private_key: &PrivateKey,
+    peer_public_key: &UnparsedPublicKey<B>,
+    aead_alg: &'static aead::Algorithm,
+) -> Result<(SealingKey<Counter64>, OpeningKey<Counter64>), Unspecified> {
+    let my_public_key = my_private_key.compute_public_key()?;
+    let my_public_key = my_public_key.as_ref();
+    let peer_public_key_bytes = peer_public_key.bytes().as_ref();
+    if my_public_key.len() != peer_public_key_bytes.len() || my_public_key == peer_public_key_bytes
+    {
+        return Err(Unspecified);
+    }
+    let i_am_low = my_public_key < peer_public_key_bytes;
+    let (low, high) = if i_am_low {
+        (my_public_key, peer_public_key_bytes)
+    } else {
+        (peer_public_key_bytes, my_public_key)
+    };
+
+    let hkdf_alg = match my_private_key.algorithm().id {
+        AlgorithmID::X25519 | AlgorithmID::ECDH_P256 => hkdf::HKDF_SHA256,
+        AlgorithmID::ECDH_P384 => hkdf::HKDF_SHA384,
+        AlgorithmID::ECDH_P521 => hkdf::HKDF_SHA512,
+    };
+    let mut salt = Vec::with_capacity(low.len() + high.len());
+    salt.extend_from_slice(low);
+    salt.extend_from_slice(high);
+
+    agree(my_private_key, peer_public_key, Unspecified, |secret| {
+        let prk = hkdf::Salt::new(hkdf_alg, &salt).extract(secret);
+        let low_to_high = UnboundKey::from(prk.expand(&[TRANSPORT_KEY_INFO, b"\x00"], aead_alg)?);
+        let high_to_low = UnboundKey::from(prk.expand(&[TRANSPORT_KEY_INFO, b"\x01"], aead_alg)?);
+        let (sealing, opening) = if i_am_low {
+            (low_to_high, high_to_low)
+        } else {
+            (high_to_low, low_to_high)
+        };
+        Ok((
+            SealingKey::new(sealing, Counter64Builder::new().build()),
+            OpeningKey::new(opening, Counter64Builder::new().build()),
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aead::{Aad, AES_256_GCM, CHACHA20_POLY1305};
+    use crate::agreement::{
+        derive_transport_keys, PrivateKey, UnparsedPublicKey, ECDH_P256, ECDH_P384, X25519,
+    };
+
+    #[test]
+    fn test_derive_transport_keys() {
+        for alg in [&X25519, &ECDH_P256, &ECDH_P384] {
+            for aead_alg in [&AES_256_GCM, &CHACHA20_POLY1305] {
+                let alice = PrivateKey::generate(alg).unwrap();
+                let bob = PrivateKey::generate(alg).unwrap();
+                let alice_public = alice.compute_public_key().unwrap();
+                let bob_public = bob.compute_public_key().unwrap();
+
+                let (mut alice_sealing, mut alice_opening) = derive_transport_keys(
+                    &alice,
+                    &UnparsedPublicKey::new(alg, &bob_public),
+                    aead_alg,
+                )
+                .unwrap();
+                let (mut bob_sealing, mut bob_opening) = derive_transport_keys(
+                    &bob,
+                    &UnparsedPublicKey::new(alg, &alice_public),
+                    aead_alg,
+                )
+                .unwrap();
+
+                for message in [&b"first"[..], b"second"] {
+                    let mut in_out = message.to_vec();
+                    alice_sealing
+                        .seal_in_place_append_tag(Aad::empty(), &mut in_out)
+                        .unwrap();
+                    let plaintext = bob_opening
+                        .open_in_place(Aad::empty(), &mut in_out)
+                        .unwrap();
+                    assert_eq!(message, plaintext);
+
+                    let mut in_out = message.to_vec();
+                    bob_sealing
+                        .seal_in_place_append_tag(Aad::empty(), &mut in_out)
+                        .unwrap();
+                    let plaintext = alice_opening
+                        .open_in_place(Aad::empty(), &mut in_out)
+                        .unwrap();
+                    assert_eq!(message, plaintext);
+                }
+
+                // A message cannot be reflected back to its sender.
+                let mut in_out = b"reflected".to_vec();
+                alice_sealing
+                    .seal_in_place_append_tag(Aad::empty(), &mut in_out)
+                    .unwrap();
+                assert!(alice_opening
+                    .open_in_place(Aad::empty(), &mut in_out)
+                    .is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn test_derive_transport_keys_rejects_bad_peer_key() {
+        let key = PrivateKey::generate(&X25519).unwrap();
+        let public_key = key.compute_public_key().unwrap();
+        let p256_public_key = PrivateKey::generate(&ECDH_P256)
+            .unwrap()
+            .compute_public_key()
+            .unwrap();
+
+        assert!(derive_transport_keys(
+            &key,
+            &UnparsedPublicKey::new(&X25519, &public_key),
+            &AES_256_GCM
+        )
+        .is_err());
+        assert!(derive_transport_keys(
+            &key,
+            &UnparsedPublicKey::new(&ECDH_P256, &p256_public_key),
+            &AES_256_GCM
+        )
+        .is_err());
+    }
+}
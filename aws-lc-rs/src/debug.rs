@@ -49,6 +49,28 @@ macro_rules! derive_debug_self_as_ref_hex_bytes {
     };
 }
 
+// Generates implementations of the `LowerHex` and `UpperHex` traits for a type that output the
+// hex encoding of the byte slice representation of the value.
+macro_rules! derive_hex_via_as_ref {
+    ($type:ty) => {
+        impl ::core::fmt::LowerHex for $type {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> Result<(), ::core::fmt::Error> {
+                crate::debug::write_hex_bytes(f, self.as_ref())
+            }
+        }
+
+        impl ::core::fmt::UpperHex for $type {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> Result<(), ::core::fmt::Error> {
+                for byte in self.as_ref() {
+                    write!(f, "{byte:02X}")?;
+                }
+                Ok(())
+            }
+        }
+    };
+}
+pub(crate) use derive_hex_via_as_ref;
+
 pub(crate) fn write_hex_bytes(
     fmt: &mut core::fmt::Formatter,
     bytes: &[u8],
@@ -29,17 +29,19 @@
 
 #![allow(non_snake_case)]
 use crate::fips::indicator_check;
-use crate::{debug, derive_debug_via_id};
+use crate::{debug, derive_debug_via_id, derive_hex_via_as_ref};
 
 pub(crate) mod digest_ctx;
 mod sha;
 use crate::aws_lc::{
-    EVP_DigestFinal, EVP_DigestUpdate, EVP_sha1, EVP_sha224, EVP_sha256, EVP_sha384, EVP_sha3_256,
-    EVP_sha3_384, EVP_sha3_512, EVP_sha512, EVP_sha512_256, EVP_MD,
+    EVP_DigestFinal, EVP_DigestFinalXOF, EVP_DigestInit_ex, EVP_DigestUpdate, EVP_sha1, EVP_sha224,
+    EVP_sha256, EVP_sha384, EVP_sha3_256, EVP_sha3_384, EVP_sha3_512, EVP_sha512, EVP_sha512_256,
+    EVP_shake128, EVP_shake256, EVP_MD,
 };
 use crate::error::Unspecified;
 use crate::ptr::ConstPointer;
 use core::mem::MaybeUninit;
+use core::ptr::null_mut;
 use digest_ctx::DigestContext;
 pub use sha::{
     SHA1_FOR_LEGACY_USE_ONLY, SHA1_OUTPUT_LEN, SHA224, SHA224_OUTPUT_LEN, SHA256,
@@ -166,6 +168,15 @@ impl Context {
     pub fn algorithm(&self) -> &'static Algorithm {
         self.algorithm
     }
+
+    /// Discards any data absorbed so far, returning the context to the state of a newly
+    /// constructed context for the same algorithm.
+    ///
+    /// # Panics
+    /// Panics if it fails to reinitialize the aws-lc digest context.
+    pub fn reset(&mut self) {
+        *self = Self::new(self.algorithm);
+    }
 }
 
 /// Returns the digest of `data` using the given digest algorithm.
@@ -204,6 +215,164 @@ pub fn digest(algorithm: &'static Algorithm, data: &[u8]) -> Digest {
     }
 }
 
+/// Returns the digest of each of the `inputs` using the given digest algorithm.
+///
+/// The returned digests are in the same order as `inputs`. Each input is
+/// hashed independently; this is a convenience wrapper that calls [`digest`]
+/// on each input.
+///
+// # FIPS
+// This function must only be used with one of the following algorithms:
+// * `SHA1_FOR_LEGACY_USE_ONLY`
+// * `SHA224`
+// * `SHA256`
+// * `SHA384`
+// * `SHA512`
+// * `SHA512_256`
+//
+/// # Examples:
+///
+/// ```
+/// use aws_lc_rs::digest;
+///
+/// let leaves: [&[u8]; 3] = [b"a", b"b", b"c"];
+/// let digests = digest::digest_many(&digest::SHA256, &leaves);
+///
+/// assert_eq!(digests.len(), 3);
+/// assert_eq!(
+///     digests[1].as_ref(),
+///     digest::digest(&digest::SHA256, b"b").as_ref()
+/// );
+/// ```
+#[must_use]
+pub fn digest_many(algorithm: &'static Algorithm, inputs: &[&[u8]]) -> Vec<Digest> {
+    inputs
+        .iter()
+        .map(|input| digest(algorithm, input))
+        .collect()
+}
+
+/// Returns a keyed SHA3-256 hash of `message` under `key`.
+///
+/// Unlike SHA-2, SHA-3 is not vulnerable to length-extension attacks, so hashing the key
+/// followed by the message is a sound MAC construction that avoids the two passes of HMAC.
+/// To keep the boundary between `key` and `message` unambiguous, the key is preceded by its
+/// length in bytes as a 64-bit big-endian integer, i.e. the input to SHA3-256 is
+/// `len(key) || key || message`.
+///
+/// This construction is neither HMAC nor KMAC and its output is not interoperable with either.
+/// Use [`crate::hmac`] when interoperability or FIPS approval is required.
+///
+/// The output is a MAC tag; check received tags with [`verify_keyed_sha3_256`] rather than
+/// comparing them with `==`.
+///
+/// # Examples:
+///
+/// ```
+/// use aws_lc_rs::digest;
+///
+/// let tag = digest::keyed_sha3_256(b"secret key", b"hello, world");
+/// assert_eq!(tag.algorithm(), &digest::SHA3_256);
+/// ```
+#[must_use]
+pub fn keyed_sha3_256(key: &[u8], message: &[u8]) -> Digest {
+    let mut ctx = Context::new(&SHA3_256);
+    ctx.update(&(key.len() as u64).to_be_bytes());
+    ctx.update(key);
+    ctx.update(message);
+    ctx.finish()
+}
+
+/// Verifies that `tag` is the [`keyed_sha3_256`] of `message` under `key`.
+///
+/// The comparison is done in constant time to prevent timing attacks.
+///
+/// # Errors
+/// `error::Unspecified` if `tag` does not match.
+pub fn verify_keyed_sha3_256(key: &[u8], message: &[u8], tag: &[u8]) -> Result<(), Unspecified> {
+    crate::constant_time::verify_slices_are_equal(keyed_sha3_256(key, message).as_ref(), tag)
+}
+
+/// Returns the BIP340 tagged hash of `message` under `tag`.
+///
+/// The tagged hash is `SHA256(SHA256(tag) || SHA256(tag) || message)`, as specified in
+/// [BIP340]. Hashes computed with different tags are independent, so the tag separates the
+/// uses of SHA-256 within a protocol, e.g. `"BIP0340/challenge"` and `"BIP0340/nonce"`.
+///
+/// [BIP340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+///
+/// # Examples:
+///
+/// ```
+/// use aws_lc_rs::digest;
+///
+/// let hash = digest::tagged_hash("BIP0340/challenge", b"hello, world");
+/// assert_eq!(hash.algorithm(), &digest::SHA256);
+/// ```
+#[must_use]
+pub fn tagged_hash(tag: &str, message: &[u8]) -> Digest {
+    let tag_hash = digest(&SHA256, tag.as_bytes());
+    let mut ctx = Context::new(&SHA256);
+    ctx.update(tag_hash.as_ref());
+    ctx.update(tag_hash.as_ref());
+    ctx.update(message);
+    ctx.finish()
+}
+
+/// Returns `output_len` bytes of SHAKE128 output for `input`, as specified in [FIPS 202].
+///
+/// SHAKE128 is an extendable-output function (XOF), so unlike the fixed-length [`Algorithm`]s
+/// the caller chooses how many bytes to produce. Shorter outputs are prefixes of longer ones.
+///
+/// [FIPS 202]: https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.202.pdf
+///
+/// # Examples:
+///
+/// ```
+/// use aws_lc_rs::digest;
+///
+/// let output = digest::shake128(b"hello, world", 64)?;
+/// assert_eq!(output.len(), 64);
+///
+/// # Ok::<(), aws_lc_rs::error::Unspecified>(())
+/// ```
+///
+/// # Errors
+/// `error::Unspecified` on internal error.
+pub fn shake128(input: &[u8], output_len: usize) -> Result<Vec<u8>, Unspecified> {
+    shake(unsafe { EVP_shake128() }, input, output_len)
+}
+
+/// Returns `output_len` bytes of SHAKE256 output for `input`, as specified in [FIPS 202].
+///
+/// See [`shake128`].
+///
+/// [FIPS 202]: https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.202.pdf
+///
+/// # Errors
+/// `error::Unspecified` on internal error.
+pub fn shake256(input: &[u8], output_len: usize) -> Result<Vec<u8>, Unspecified> {
+    shake(unsafe { EVP_shake256() }, input, output_len)
+}
+
+fn shake(md: *const EVP_MD, input: &[u8], output_len: usize) -> Result<Vec<u8>, Unspecified> {
+    let mut digest_ctx = DigestContext::new_uninit();
+    if 1 != unsafe { EVP_DigestInit_ex(digest_ctx.as_mut_ptr(), md, null_mut()) } {
+        return Err(Unspecified);
+    }
+    if 1 != unsafe { EVP_DigestUpdate(digest_ctx.as_mut_ptr(), input.as_ptr().cast(), input.len()) }
+    {
+        return Err(Unspecified);
+    }
+    let mut output = vec![0u8; output_len];
+    if 1 != indicator_check!(unsafe {
+        EVP_DigestFinalXOF(digest_ctx.as_mut_ptr(), output.as_mut_ptr(), output.len())
+    }) {
+        return Err(Unspecified);
+    }
+    Ok(output)
+}
+
 /// A calculated digest value.
 ///
 /// Use [`Self::as_ref`] to get the value as a `&[u8]`.
@@ -233,6 +402,8 @@ impl AsRef<[u8]> for Digest {
     }
 }
 
+derive_hex_via_as_ref!(Digest);
+
 impl core::fmt::Debug for Digest {
     fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(fmt, "{:?}:", self.algorithm)?;
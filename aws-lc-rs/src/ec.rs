@@ -7,19 +7,16 @@ use crate::ec::signature::AlgorithmID;
 // TODO: Uncomment when MSRV >= 1.64
 use std::os::raw::c_int;
 
-#[cfg(feature = "fips")]
-use crate::aws_lc::EC_KEY_check_fips;
 #[cfg(not(feature = "fips"))]
 use crate::aws_lc::EC_KEY_check_key;
 use crate::aws_lc::{
     ECDSA_SIG_from_bytes, ECDSA_SIG_get0_r, ECDSA_SIG_get0_s, EC_GROUP_get_curve_name,
-    EC_KEY_get0_group, EC_group_p224, EC_group_p256, EC_group_p384, EC_group_p521,
-    EC_group_secp256k1, EVP_PKEY_CTX_set_ec_paramgen_curve_nid, EVP_PKEY_get0_EC_KEY,
-    NID_X9_62_prime256v1, NID_secp224r1, NID_secp256k1, NID_secp384r1, NID_secp521r1, EC_GROUP,
-    EC_KEY, EVP_PKEY, EVP_PKEY_EC,
+    EC_KEY_check_fips, EC_KEY_get0_group, EC_group_p224, EC_group_p256, EC_group_p384,
+    EC_group_p521, EC_group_secp256k1, EVP_PKEY_CTX_set_ec_paramgen_curve_nid,
+    EVP_PKEY_get0_EC_KEY, NID_X9_62_prime256v1, NID_secp224r1, NID_secp256k1, NID_secp384r1,
+    NID_secp521r1, EC_GROUP, EC_KEY, EVP_PKEY, EVP_PKEY_EC,
 };
 use crate::error::{KeyRejected, Unspecified};
-#[cfg(feature = "fips")]
 use crate::fips::indicator_check;
 use crate::ptr::{ConstPointer, LcPtr};
 use crate::signature::Signature;
@@ -47,6 +44,13 @@ fn verify_ec_key_nid(
     Ok(())
 }
 
+/// Returns the NID of the curve of an `EVP_PKEY_EC` key.
+pub(crate) fn evp_key_curve_nid(evp_pkey: &ConstPointer<EVP_PKEY>) -> Result<i32, KeyRejected> {
+    let ec_key = ConstPointer::new(unsafe { EVP_PKEY_get0_EC_KEY(**evp_pkey) })?;
+    let ec_group = ConstPointer::new(unsafe { EC_KEY_get0_group(*ec_key) })?;
+    Ok(unsafe { EC_GROUP_get_curve_name(*ec_group) })
+}
+
 #[inline]
 #[cfg(not(feature = "fips"))]
 pub(crate) fn verify_evp_key_nid(
@@ -80,6 +84,23 @@ pub(crate) fn validate_ec_evp_key(
     Ok(())
 }
 
+/// Validates the key with `EC_KEY_check_fips` regardless of whether the `fips` feature is
+/// enabled.
+#[inline]
+pub(crate) fn validate_ec_evp_key_fips(
+    evp_pkey: &ConstPointer<EVP_PKEY>,
+    expected_curve_nid: i32,
+) -> Result<(), KeyRejected> {
+    let ec_key = ConstPointer::new(unsafe { EVP_PKEY_get0_EC_KEY(**evp_pkey) })?;
+    verify_ec_key_nid(&ec_key, expected_curve_nid)?;
+
+    if 1 != indicator_check!(unsafe { EC_KEY_check_fips(*ec_key) }) {
+        return Err(KeyRejected::inconsistent_components());
+    }
+
+    Ok(())
+}
+
 #[inline]
 pub(crate) fn evp_key_generate(nid: c_int) -> Result<LcPtr<EVP_PKEY>, Unspecified> {
     let params_fn = |ctx| {
@@ -101,6 +122,8 @@ pub(crate) fn ec_group_from_nid(nid: i32) -> Result<ConstPointer<EC_GROUP>, Unsp
         NID_secp384r1 => Ok(unsafe { EC_group_p384() }),
         NID_secp521r1 => Ok(unsafe { EC_group_p521() }),
         NID_secp256k1 => Ok(unsafe { EC_group_secp256k1() }),
+        // AWS-LC defines the brainpool NIDs but provides no built-in groups for them, so
+        // brainpool curves are rejected here along with every other unknown curve.
         _ => {
             // OPENSSL_PUT_ERROR(EC, EC_R_UNKNOWN_GROUP);
             Err(Unspecified)
@@ -120,6 +143,10 @@ fn ecdsa_asn1_to_fixed(alg_id: &'static AlgorithmID, sig: &[u8]) -> Result<Signa
     let s_bn = ConstPointer::new(unsafe { ECDSA_SIG_get0_s(*ecdsa_sig.as_const()) })?;
     let s_buffer = s_bn.to_be_bytes();
 
+    if r_buffer.len() > expected_number_size || s_buffer.len() > expected_number_size {
+        return Err(Unspecified);
+    }
+
     Ok(Signature::new(|slice| {
         let (r_start, r_end) = (expected_number_size - r_buffer.len(), expected_number_size);
         let (s_start, s_end) = (
@@ -155,6 +182,24 @@ mod tests {
     use crate::test::from_dirty_hex;
     use crate::{signature, test};
 
+    #[test]
+    fn test_ec_group_from_nid_rejects_brainpool() {
+        use crate::aws_lc::{
+            EC_GROUP_new_by_curve_name, NID_brainpoolP256r1, NID_brainpoolP384r1,
+            NID_brainpoolP512r1,
+        };
+
+        for nid in [
+            NID_brainpoolP256r1,
+            NID_brainpoolP384r1,
+            NID_brainpoolP512r1,
+        ] {
+            // AWS-LC itself has no group for the curve.
+            assert!(unsafe { EC_GROUP_new_by_curve_name(nid) }.is_null());
+            assert!(super::ec_group_from_nid(nid).is_err());
+        }
+    }
+
     #[test]
     fn test_from_pkcs8() {
         let input = from_dirty_hex(
@@ -243,4 +288,73 @@ mod tests {
                 .unwrap();
         }
     }
+
+    #[test]
+    fn test_normalize_low_s() {
+        // The signature from `test_ecdsa_asn1_verify`, which is low-S, and its high-S
+        // counterpart `(r, n - s)`.
+        const R: &str = "341f6779b75e98bb42e01095dd48356cbf9002dc704ac8bd2a8240b88d3796c6";
+        const LOW_S: &str = "555843b1b4e264fe6ffe6e2b705a376c05c09404303ffe5d2711f3e3b3a010a1";
+        const HIGH_S: &str = "aaa7bc4d4b1d9b02900191d48fa5c893b72666a976d7a027cca7d6df48c314b0";
+
+        let public_key = from_dirty_hex(
+            r"0430345fd47ea21a11129be651b0884bfac698377611acc9f689458e1
+        3b9ed7d4b9d7599a68dcf125e7f31055ccb374cd04f6d6fd2b217438a63f6f667d50ef2f0",
+        );
+        let to_signature = |bytes: Vec<u8>| {
+            signature::Signature::new(|slice| {
+                slice[..bytes.len()].copy_from_slice(&bytes);
+                bytes.len()
+            })
+        };
+        let low_fixed = from_dirty_hex(&format!("{R}{LOW_S}"));
+        let high_fixed = from_dirty_hex(&format!("{R}{HIGH_S}"));
+        let low_asn1 = from_dirty_hex(&format!("30440220{R}0220{LOW_S}"));
+        let high_asn1 = from_dirty_hex(&format!("30450220{R}022100{HIGH_S}"));
+
+        for (alg, low, high) in [
+            (&signature::ECDSA_P256_SHA256_FIXED, low_fixed, high_fixed),
+            (&signature::ECDSA_P256_SHA256_ASN1, low_asn1, high_asn1),
+        ] {
+            let public_key = UnparsedPublicKey::new(alg, &public_key);
+            public_key.verify(b"", &low).unwrap();
+            // Both forms are valid signatures.
+            public_key.verify(b"", &high).unwrap();
+
+            let normalized = to_signature(high).normalize_low_s(alg).unwrap();
+            assert_eq!(low.as_slice(), normalized.as_ref());
+            let unchanged = to_signature(low.clone()).normalize_low_s(alg).unwrap();
+            assert_eq!(low.as_slice(), unchanged.as_ref());
+        }
+
+        // `s` must be in the range [1, n - 1].
+        let zero_s = to_signature(from_dirty_hex(&format!("{R}{}", "00".repeat(32))));
+        assert!(zero_s
+            .normalize_low_s(&signature::ECDSA_P256_SHA256_FIXED)
+            .is_err());
+        let large_s = to_signature(from_dirty_hex(&format!("{R}{}", "ff".repeat(32))));
+        assert!(large_s
+            .normalize_low_s(&signature::ECDSA_P256_SHA256_FIXED)
+            .is_err());
+
+        // Signatures from a signing algorithm keep their format and stay valid.
+        let rng = crate::rand::SystemRandom::new();
+        for alg in [
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            &ECDSA_P256_SHA256_FIXED_SIGNING,
+            &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+            &signature::ECDSA_P256K1_SHA256_FIXED_SIGNING,
+        ] {
+            let key_pair = EcdsaKeyPair::generate(alg).unwrap();
+            let sig = key_pair.sign(&rng, b"low s").unwrap();
+            let normalized = sig.normalize_low_s(alg).unwrap();
+            UnparsedPublicKey::new(alg.0, key_pair.public_key())
+                .verify(b"low s", normalized.as_ref())
+                .unwrap();
+            assert_eq!(
+                normalized.as_ref(),
+                normalized.normalize_low_s(alg).unwrap().as_ref()
+            );
+        }
+    }
 }
@@ -51,7 +51,7 @@ pub(crate) mod sec1 {
     }
 
     #[inline]
-    fn from_ec_public_point(
+    pub(crate) fn from_ec_public_point(
         ec_group: &ConstPointer<EC_GROUP>,
         public_ec_point: &LcPtr<EC_POINT>,
     ) -> Result<LcPtr<EVP_PKEY>, KeyRejected> {
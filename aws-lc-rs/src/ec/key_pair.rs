@@ -3,29 +3,41 @@
 // Modifications copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
-use crate::aws_lc::{EVP_PKEY, EVP_PKEY_EC};
+use crate::aws_lc::{
+    BN_bn2bin_padded, CRYPTO_memcmp, ECDSA_SIG_to_bytes, ECDSA_do_sign, EC_KEY_get0_group,
+    EC_KEY_get0_private_key, EC_KEY_get0_public_key, EC_POINT_cmp, EC_POINT_mul, EC_POINT_new,
+    EVP_DigestSignFinal, EVP_DigestSignUpdate, EVP_PKEY, EVP_PKEY_EC,
+};
 use core::fmt;
 use core::fmt::{Debug, Formatter};
+use core::mem::MaybeUninit;
+use core::ptr::{null, null_mut};
 
 use crate::ec::evp_key_generate;
 use crate::ec::signature::{EcdsaSignatureFormat, EcdsaSigningAlgorithm, PublicKey};
 #[cfg(feature = "fips")]
 use crate::ec::validate_ec_evp_key;
+use crate::ec::validate_ec_evp_key_fips;
 #[cfg(not(feature = "fips"))]
 use crate::ec::verify_evp_key_nid;
 
+use crate::digest::digest_ctx::DigestContext;
 use crate::ec;
+use crate::ec::encoding::parse_ec_public_key;
 use crate::ec::encoding::rfc5915::{marshal_rfc5915_private_key, parse_rfc5915_private_key};
 use crate::ec::encoding::sec1::{
-    marshal_sec1_private_key, parse_sec1_private_bn, parse_sec1_public_point,
+    marshal_sec1_private_key, marshal_sec1_public_point, parse_sec1_private_bn,
+    parse_sec1_public_point,
 };
 use crate::encoding::{AsBigEndian, AsDer, EcPrivateKeyBin, EcPrivateKeyRfc5915Der};
 use crate::error::{KeyRejected, Unspecified};
 use crate::evp_pkey::No_EVP_PKEY_CTX_consumer;
+use crate::fips::indicator_check;
 use crate::pkcs8::{Document, Version};
-use crate::ptr::LcPtr;
+use crate::ptr::{ConstPointer, LcPtr};
 use crate::rand::SecureRandom;
-use crate::signature::{KeyPair, Signature};
+use crate::signature::{KeyPair, Signature, VerificationAlgorithm};
+use zeroize::Zeroize;
 
 /// An ECDSA key pair, used for signing.
 #[allow(clippy::module_name_repetitions)]
@@ -53,6 +65,24 @@ impl KeyPair for EcdsaKeyPair {
     fn public_key(&self) -> &Self::PublicKey {
         &self.pubkey
     }
+
+    #[inline]
+    /// Provides the verification algorithm of the key pair's signing algorithm.
+    fn verification_algorithm(&self) -> Option<&'static dyn VerificationAlgorithm> {
+        Some(self.algorithm.0)
+    }
+}
+
+impl crate::signature::pkcs8_sealed::Pkcs8SigningAlgorithm for EcdsaSigningAlgorithm {
+    fn validate_pkcs8_impl(&self, pkcs8: &[u8]) -> Result<(), KeyRejected> {
+        // Includes a call to `EC_KEY_check_key`
+        let evp_pkey = LcPtr::<EVP_PKEY>::parse_rfc5208_private_key(pkcs8, EVP_PKEY_EC)?;
+
+        #[cfg(not(feature = "fips"))]
+        return verify_evp_key_nid(&evp_pkey.as_const(), self.id.nid());
+        #[cfg(feature = "fips")]
+        return validate_ec_evp_key(&evp_pkey.as_const(), self.id.nid());
+    }
 }
 
 impl EcdsaKeyPair {
@@ -61,7 +91,7 @@ impl EcdsaKeyPair {
         algorithm: &'static EcdsaSigningAlgorithm,
         evp_pkey: LcPtr<EVP_PKEY>,
     ) -> Result<Self, ()> {
-        let pubkey = ec::signature::public_key_from_evp_pkey(&evp_pkey, algorithm)?;
+        let pubkey = ec::signature::public_key_from_evp_pkey(&evp_pkey, algorithm.0)?;
 
         Ok(Self {
             algorithm,
@@ -70,6 +100,11 @@ impl EcdsaKeyPair {
         })
     }
 
+    #[inline]
+    pub(crate) fn signing_algorithm(&self) -> &'static EcdsaSigningAlgorithm {
+        self.algorithm
+    }
+
     /// Generates a new key pair.
     ///
     /// # Errors
@@ -81,6 +116,43 @@ impl EcdsaKeyPair {
         Ok(Self::new(alg, evp_pkey)?)
     }
 
+    /// Generates key pairs using `rng` until one has a public key for which `predicate` returns
+    /// `true`. Only for test fixtures; see [`crate::test::generate_matching_ecdsa_key`].
+    pub(crate) fn generate_matching(
+        alg: &'static EcdsaSigningAlgorithm,
+        rng: &dyn SecureRandom,
+        predicate: impl Fn(&[u8]) -> bool,
+    ) -> Result<Self, Unspecified> {
+        let mut scalar = zeroize::Zeroizing::new([0u8; ec::ELEM_MAX_BYTES]);
+        let scalar = &mut scalar[..alg.id.private_key_size()];
+        loop {
+            rng.fill(scalar)?;
+            if alg.id == &ec::signature::AlgorithmID::ECDSA_P521 {
+                // Only the low 521 bits of the 66 bytes are significant.
+                scalar[0] &= 0x01;
+            }
+            // Scalars outside of the range [1, n) are rejected; just try again.
+            if let Ok(evp_pkey) = parse_sec1_private_bn(scalar, alg.id.nid()) {
+                let key_pair = Self::new(alg, evp_pkey)?;
+                if predicate(key_pair.public_key().as_ref()) {
+                    return Ok(key_pair);
+                }
+            }
+        }
+    }
+
+    /// Generates a new key pair on the curve named `name`.
+    ///
+    /// `name` is resolved with [`ecdsa_curve_from_name`](crate::signature::ecdsa_curve_from_name),
+    /// and the key pair uses the curve's [`signing_algorithm`](crate::signature::EcdsaCurve::signing_algorithm).
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `name` is not a recognized curve name, or on internal error.
+    pub fn generate_named(name: &str) -> Result<Self, Unspecified> {
+        let curve = ec::signature::curve_from_name(name).ok_or(Unspecified)?;
+        Self::generate(curve.signing_algorithm())
+    }
+
     /// Constructs an ECDSA key pair by parsing an unencrypted PKCS#8 v1
     /// id-ecPublicKey `ECPrivateKey` key.
     ///
@@ -149,8 +221,12 @@ impl EcdsaKeyPair {
     /// [SEC 1: Elliptic Curve Cryptography, Version 2.0]:
     ///     http://www.secg.org/sec1-v2.pdf
     ///
+    /// The public key is not trusted: it must be the point derived from the private key, so a
+    /// key pair loaded from separate sources is only accepted if its halves belong together.
+    ///
     /// # Errors
-    /// `error::KeyRejected` if parsing failed or key otherwise unacceptable.
+    /// `error::KeyRejected` if parsing failed, the public key does not match the private key,
+    /// or the key is otherwise unacceptable.
     pub fn from_private_key_and_public_key(
         alg: &'static EcdsaSigningAlgorithm,
         private_key: &[u8],
@@ -167,6 +243,29 @@ impl EcdsaKeyPair {
         Ok(key_pair)
     }
 
+    /// Constructs an ECDSA key pair from a private key alone, given as the big-endian
+    /// fixed-length encoding of the private scalar, e.g. 32 bytes for P-256 or 66 bytes for
+    /// P-521. The public key is derived from the private key.
+    ///
+    /// This is the encoding produced by `EcdsaPrivateKey::as_be_bytes`.
+    ///
+    /// # Errors
+    /// `error::KeyRejected` if `scalar` is not the curve's private key length, if the scalar
+    /// is zero or not less than the order of the curve, or if the key is otherwise not
+    /// acceptable.
+    pub fn from_private_scalar(
+        alg: &'static EcdsaSigningAlgorithm,
+        scalar: &[u8],
+    ) -> Result<Self, KeyRejected> {
+        if scalar.len() != alg.id.private_key_size() {
+            return Err(KeyRejected::invalid_encoding());
+        }
+        // Rejects scalars that are zero or not less than the group order.
+        let evp_pkey = parse_sec1_private_bn(scalar, alg.id.nid())?;
+
+        Ok(Self::new(alg, evp_pkey)?)
+    }
+
     /// Deserializes a DER-encoded private key structure to produce a `EcdsaKeyPair`.
     ///
     /// This function is typically used to deserialize RFC 5915 encoded private keys, but it will
@@ -193,6 +292,40 @@ impl EcdsaKeyPair {
         Ok(Self::new(alg, evp_pkey)?)
     }
 
+    /// Constructs an ECDSA key pair like [`EcdsaKeyPair::from_pkcs8`], but always validates the
+    /// key with the stricter FIPS key check (`EC_KEY_check_fips`), which adds a pairwise
+    /// consistency test, even when the `fips` feature is not enabled.
+    ///
+    /// # Errors
+    /// `error::KeyRejected` if bytes do not encode an ECDSA key pair or if the key does not pass
+    /// the FIPS key check.
+    pub fn from_pkcs8_fips_checked(
+        alg: &'static EcdsaSigningAlgorithm,
+        pkcs8: &[u8],
+    ) -> Result<Self, KeyRejected> {
+        let evp_pkey = LcPtr::<EVP_PKEY>::parse_rfc5208_private_key(pkcs8, EVP_PKEY_EC)?;
+        validate_ec_evp_key_fips(&evp_pkey.as_const(), alg.id.nid())?;
+
+        Ok(Self::new(alg, evp_pkey)?)
+    }
+
+    /// Deserializes a DER-encoded private key like [`EcdsaKeyPair::from_private_key_der`], but
+    /// always validates the key with the stricter FIPS key check (`EC_KEY_check_fips`), even
+    /// when the `fips` feature is not enabled.
+    ///
+    /// # Errors
+    /// `error::KeyRejected` if parsing failed or the key does not pass the FIPS key check.
+    pub fn from_private_key_der_fips_checked(
+        alg: &'static EcdsaSigningAlgorithm,
+        private_key: &[u8],
+    ) -> Result<Self, KeyRejected> {
+        let evp_pkey = LcPtr::<EVP_PKEY>::parse_rfc5208_private_key(private_key, EVP_PKEY_EC)
+            .or(parse_rfc5915_private_key(private_key, alg.id.nid()))?;
+        validate_ec_evp_key_fips(&evp_pkey.as_const(), alg.id.nid())?;
+
+        Ok(Self::new(alg, evp_pkey)?)
+    }
+
     /// Access functions related to the private key.
     #[must_use]
     pub fn private_key(&self) -> PrivateKey<'_> {
@@ -219,16 +352,255 @@ impl EcdsaKeyPair {
             No_EVP_PKEY_CTX_consumer,
         )?;
 
+        self.format_signature(&out_sig)
+    }
+
+    /// Returns the signature of a precomputed message digest using a random nonce.
+    ///
+    /// `digest` must be the output of the digest algorithm associated with this key pair's
+    /// signing algorithm (e.g., SHA-256 for `ECDSA_P256_SHA256_FIXED_SIGNING`). The digest is
+    /// signed as-is; it is not hashed again. The resulting signature can be verified against
+    /// the original message using the corresponding verification algorithm.
+    ///
+    /// # *ring* Compatibility
+    /// Our implementation ignores the `SecureRandom` parameter.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the length of `digest` does not match the output length of the
+    /// algorithm's digest, or on internal error.
+    pub fn sign_digest(
+        &self,
+        _rng: &dyn SecureRandom,
+        digest: &[u8],
+    ) -> Result<Signature, Unspecified> {
+        if digest.len() != self.algorithm.digest.output_len() {
+            return Err(Unspecified);
+        }
+
+        let ec_key = self.evp_pkey.get_ec_key()?;
+        let ecdsa_sig = LcPtr::new(indicator_check!(unsafe {
+            ECDSA_do_sign(digest.as_ptr(), digest.len(), *ec_key)
+        }))?;
+
+        let mut out_bytes = null_mut::<u8>();
+        let mut out_bytes_len = MaybeUninit::<usize>::uninit();
+        if 1 != unsafe {
+            ECDSA_SIG_to_bytes(
+                &mut out_bytes,
+                out_bytes_len.as_mut_ptr(),
+                *ecdsa_sig.as_const(),
+            )
+        } {
+            return Err(Unspecified);
+        }
+        let out_bytes = LcPtr::new(out_bytes)?;
+        let out_sig = unsafe { out_bytes.as_slice(out_bytes_len.assume_init()) };
+
+        self.format_signature(out_sig)
+    }
+
+    /// Creates a context for signing a message that is provided incrementally.
+    ///
+    /// This allows a large message, such as a file, to be signed without holding all of it in
+    /// memory. The resulting signature is produced the same way as one from [`Self::sign`] over
+    /// the concatenation of the chunks passed to [`EcdsaSigningContext::update`], and verifies
+    /// identically.
+    ///
+    // # FIPS
+    // The following conditions must be met:
+    // * NIST Elliptic Curves: P256, P384, P521
+    // * Digest Algorithms: SHA256, SHA384, SHA512
+    //
+    /// # Errors
+    /// `error::Unspecified` on internal error.
+    pub fn signing_context(&self) -> Result<EcdsaSigningContext<'_>, Unspecified> {
+        let md_ctx = self
+            .evp_pkey
+            .digest_sign_init(Some(self.algorithm.digest), No_EVP_PKEY_CTX_consumer)?;
+        Ok(EcdsaSigningContext {
+            key_pair: self,
+            md_ctx,
+        })
+    }
+
+    /// Recomputes the public point from the private scalar and checks that it matches the
+    /// public key held by this key pair.
+    ///
+    /// This can be used to detect corruption of key material held in memory.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the recomputed public point does not match the stored public key,
+    /// or on internal error.
+    pub fn verify_public_key_consistency(&self) -> Result<(), Unspecified> {
+        let ec_key = self.evp_pkey.get_ec_key()?;
+        let ec_group = ConstPointer::new(unsafe { EC_KEY_get0_group(*ec_key) })?;
+        let private_bn = ConstPointer::new(unsafe { EC_KEY_get0_private_key(*ec_key) })?;
+        let public_point = ConstPointer::new(unsafe { EC_KEY_get0_public_key(*ec_key) })?;
+
+        let mut computed_point = LcPtr::new(unsafe { EC_POINT_new(*ec_group) })?;
+        if 1 != unsafe {
+            EC_POINT_mul(
+                *ec_group,
+                *computed_point.as_mut(),
+                *private_bn,
+                null(),
+                null(),
+                null_mut(),
+            )
+        } {
+            return Err(Unspecified);
+        }
+
+        // `EC_POINT_cmp` returns 0 if the points are equal, 1 if they differ and -1 on error.
+        if 0 != unsafe {
+            EC_POINT_cmp(
+                *ec_group,
+                *computed_point.as_const(),
+                *public_point,
+                null_mut(),
+            )
+        } {
+            return Err(Unspecified);
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if `public_key` is this key pair's public key.
+    ///
+    /// `public_key` can be an X.509 `SubjectPublicKeyInfo` or an uncompressed or compressed
+    /// SEC 1 point, and is compared to the key pair's public point regardless of its encoding.
+    /// Returns `false` if it cannot be parsed as a public key on the key pair's curve.
+    #[must_use]
+    pub fn public_key_matches(&self, public_key: &[u8]) -> bool {
+        match parse_ec_public_key(public_key, self.algorithm.id.nid()) {
+            Ok(evp_pkey) => marshal_sec1_public_point(&evp_pkey, false)
+                .map_or(false, |point| point == self.pubkey.as_ref()),
+            Err(_) => false,
+        }
+    }
+
+    /// Returns `true` if `other` has the same private key as this key pair.
+    ///
+    /// The private scalars are compared in constant time with respect to their values. Both
+    /// scalars are padded to the size of the largest supported curve before comparison, so
+    /// key pairs on different curves are compared in the same way and then reported as not
+    /// equal. Key pairs on the same curve with different signing algorithms are equal if their
+    /// private scalars are equal.
+    ///
+    /// Returns `false` on internal error.
+    #[must_use]
+    pub fn private_key_equals(&self, other: &EcdsaKeyPair) -> bool {
+        let mut scalar = [0u8; ec::ELEM_MAX_BYTES];
+        let mut other_scalar = [0u8; ec::ELEM_MAX_BYTES];
+        let serialized = self.padded_private_scalar(&mut scalar).is_ok()
+            & other.padded_private_scalar(&mut other_scalar).is_ok();
+        let same_scalar = 0
+            == unsafe {
+                CRYPTO_memcmp(
+                    scalar.as_ptr().cast(),
+                    other_scalar.as_ptr().cast(),
+                    ec::ELEM_MAX_BYTES,
+                )
+            };
+        scalar.zeroize();
+        other_scalar.zeroize();
+        let same_curve = self.algorithm.id.nid() == other.algorithm.id.nid();
+        serialized & same_scalar & same_curve
+    }
+
+    fn padded_private_scalar(&self, out: &mut [u8; ec::ELEM_MAX_BYTES]) -> Result<(), Unspecified> {
+        let ec_key = self.evp_pkey.get_ec_key()?;
+        let private_bn = ConstPointer::new(unsafe { EC_KEY_get0_private_key(*ec_key) })?;
+        if 1 != unsafe { BN_bn2bin_padded(out.as_mut_ptr(), out.len(), *private_bn) } {
+            return Err(Unspecified);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn format_signature(&self, out_sig: &[u8]) -> Result<Signature, Unspecified> {
         Ok(match self.algorithm.sig_format {
             EcdsaSignatureFormat::ASN1 => Signature::new(|slice| {
-                slice[..out_sig.len()].copy_from_slice(&out_sig);
+                slice[..out_sig.len()].copy_from_slice(out_sig);
                 out_sig.len()
             }),
-            EcdsaSignatureFormat::Fixed => ec::ecdsa_asn1_to_fixed(self.algorithm.id, &out_sig)?,
+            EcdsaSignatureFormat::Fixed => ec::ecdsa_asn1_to_fixed(self.algorithm.id, out_sig)?,
         })
     }
 }
 
+/// A context for signing a message that is provided incrementally, created by
+/// [`EcdsaKeyPair::signing_context`].
+///
+/// # Example
+/// ```
+/// use aws_lc_rs::signature::{self, EcdsaKeyPair, KeyPair, UnparsedPublicKey};
+///
+/// let key_pair = EcdsaKeyPair::generate(&signature::ECDSA_P256_SHA256_ASN1_SIGNING)?;
+/// let mut context = key_pair.signing_context()?;
+/// context.update(b"hello, ")?;
+/// context.update(b"world")?;
+/// let sig = context.finish()?;
+///
+/// UnparsedPublicKey::new(&signature::ECDSA_P256_SHA256_ASN1, key_pair.public_key())
+///     .verify(b"hello, world", sig.as_ref())?;
+/// # Ok::<(), aws_lc_rs::error::Unspecified>(())
+/// ```
+pub struct EcdsaSigningContext<'a> {
+    // The digest context refers to the key pair's key, which the borrow keeps alive.
+    key_pair: &'a EcdsaKeyPair,
+    md_ctx: DigestContext,
+}
+
+impl EcdsaSigningContext<'_> {
+    /// Updates the context with the next chunk of the message.
+    ///
+    /// # Errors
+    /// `error::Unspecified` on internal error.
+    pub fn update(&mut self, data: &[u8]) -> Result<(), Unspecified> {
+        if 1 != unsafe {
+            EVP_DigestSignUpdate(self.md_ctx.as_mut_ptr(), data.as_ptr().cast(), data.len())
+        } {
+            return Err(Unspecified);
+        }
+        Ok(())
+    }
+
+    /// Returns the signature, using a random nonce, of all of the data provided to `update`.
+    ///
+    // # FIPS
+    // The conditions of `EcdsaKeyPair::signing_context` must be met.
+    //
+    /// # Errors
+    /// `error::Unspecified` on internal error.
+    pub fn finish(mut self) -> Result<Signature, Unspecified> {
+        // Determine the maximum length of the signature.
+        let mut sig_len = 0;
+        if 1 != unsafe { EVP_DigestSignFinal(self.md_ctx.as_mut_ptr(), null_mut(), &mut sig_len) } {
+            return Err(Unspecified);
+        }
+        if sig_len == 0 {
+            return Err(Unspecified);
+        }
+
+        let mut out_sig = vec![0u8; sig_len];
+        if 1 != indicator_check!(unsafe {
+            EVP_DigestSignFinal(self.md_ctx.as_mut_ptr(), out_sig.as_mut_ptr(), &mut sig_len)
+        }) {
+            return Err(Unspecified);
+        }
+        out_sig.truncate(sig_len);
+
+        self.key_pair.format_signature(&out_sig)
+    }
+}
+
+impl Debug for EcdsaSigningContext<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("EcdsaSigningContext { .. }")
+    }
+}
+
 /// Elliptic curve private key.
 pub struct PrivateKey<'a>(&'a EcdsaKeyPair);
 
@@ -243,6 +615,10 @@ impl AsBigEndian<EcPrivateKeyBin<'static>> for PrivateKey<'_> {
     ///
     /// For most use-cases, `EcdsaKeyPair::to_pkcs8()` should be preferred.
     ///
+    /// The returned `EcPrivateKeyBin` owns its bytes, which are zeroized when it is dropped. It
+    /// is not `Copy` or `Clone`; the scalar is only copied if the caller copies it out of
+    /// `as_ref()`.
+    ///
     /// # Errors
     /// `error::Unspecified` if serialization failed.
     fn as_be_bytes(&self) -> Result<EcPrivateKeyBin<'static>, Unspecified> {
@@ -295,6 +671,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_generate_matching() {
+        use crate::rand::SystemRandom;
+        use crate::signature::{KeyPair, ECDSA_P384_SHA384_ASN1_SIGNING};
+        use crate::test::rand::FixedByteRandom;
+
+        let rng = SystemRandom::new();
+        for alg in [
+            &ECDSA_P256_SHA256_FIXED_SIGNING,
+            &ECDSA_P384_SHA384_ASN1_SIGNING,
+            &ECDSA_P521_SHA512_FIXED_SIGNING,
+        ] {
+            let key_pair =
+                EcdsaKeyPair::generate_matching(alg, &rng, |public_key| public_key[1] == 0x2a)
+                    .unwrap();
+            assert_eq!(0x2a, key_pair.public_key().as_ref()[1]);
+            assert_eq!(Ok(()), key_pair.verify_public_key_consistency());
+        }
+
+        // The same randomness produces the same key.
+        let rng = FixedByteRandom { byte: 0x01 };
+        let first =
+            EcdsaKeyPair::generate_matching(&ECDSA_P256_SHA256_FIXED_SIGNING, &rng, |_| true)
+                .unwrap();
+        let second =
+            EcdsaKeyPair::generate_matching(&ECDSA_P256_SHA256_FIXED_SIGNING, &rng, |_| true)
+                .unwrap();
+        assert_eq!(first.public_key().as_ref(), second.public_key().as_ref());
+    }
+
     #[test]
     fn test_from_private_key_der() {
         let key_pair = EcdsaKeyPair::generate(&ECDSA_P256_SHA256_FIXED_SIGNING).unwrap();
@@ -2,26 +2,39 @@
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
 use crate::aws_lc::{
-    ECDSA_SIG_new, ECDSA_SIG_set0, ECDSA_SIG_to_bytes, NID_X9_62_prime256v1, NID_secp256k1,
-    NID_secp384r1, NID_secp521r1, BIGNUM, ECDSA_SIG, EVP_PKEY,
+    BN_CTX_new, BN_add, BN_cmp, BN_dup, BN_get_u64, BN_is_zero, BN_mod_inverse, BN_mod_mul,
+    BN_mod_sub, BN_new, BN_num_bits, BN_rshift, BN_rshift1, BN_sub, ECDSA_SIG_from_bytes,
+    ECDSA_SIG_get0_r, ECDSA_SIG_get0_s, ECDSA_SIG_new, ECDSA_SIG_set0, ECDSA_SIG_to_bytes,
+    EC_GROUP_get0_order, EC_GROUP_get_cofactor, EC_GROUP_get_curve_GFp, EC_POINT_is_at_infinity,
+    EC_POINT_mul, EC_POINT_new, EC_POINT_oct2point, EC_POINT_set_compressed_coordinates_GFp,
+    NID_X9_62_prime256v1, NID_secp256k1, NID_secp384r1, NID_secp521r1, BIGNUM, BN_CTX, ECDSA_SIG,
+    EC_GROUP, EC_POINT, EVP_PKEY,
 };
 
-use crate::ec::compressed_public_key_size_bytes;
 use crate::ec::encoding::parse_ec_public_key;
-use crate::ec::encoding::sec1::marshal_sec1_public_point;
+use crate::ec::encoding::sec1::{
+    from_ec_public_point, marshal_sec1_public_point, parse_sec1_public_point,
+};
+use crate::ec::{
+    compressed_public_key_size_bytes, ec_group_from_nid, ecdsa_asn1_to_fixed,
+    uncompressed_public_key_size_bytes,
+};
 use crate::encoding::{
     AsBigEndian, AsDer, EcPublicKeyCompressedBin, EcPublicKeyUncompressedBin, PublicKeyX509Der,
 };
-use crate::error::Unspecified;
+use crate::error::{KeyRejected, Unspecified};
 use crate::evp_pkey::No_EVP_PKEY_CTX_consumer;
-use crate::ptr::{DetachableLcPtr, LcPtr};
-use crate::signature::VerificationAlgorithm;
-use crate::{digest, sealed};
+use crate::ptr::{ConstPointer, DetachableLcPtr, LcPtr};
+use crate::signature::streaming::StreamingSignatureFormat;
+use crate::signature::{
+    Signature, StreamingVerificationAlgorithm, VerificationAlgorithm, VerificationContext,
+};
+use crate::{digest, sealed, signature};
 use core::fmt;
 use core::fmt::{Debug, Formatter};
 use std::mem::MaybeUninit;
 use std::ops::Deref;
-use std::ptr::null_mut;
+use std::ptr::{null, null_mut};
 #[cfg(feature = "ring-sig-verify")]
 use untrusted::Input;
 
@@ -45,6 +58,192 @@ impl Deref for EcdsaSigningAlgorithm {
     }
 }
 
+/// An elliptic curve that ECDSA keys can be generated for, as returned by
+/// [`curve_from_name`] or [`EcdsaVerificationAlgorithm::curve`].
+#[derive(Debug, Eq, PartialEq)]
+pub struct EcdsaCurve {
+    name: &'static str,
+    aliases: &'static [&'static str],
+    signing_algorithm: &'static EcdsaSigningAlgorithm,
+}
+
+impl EcdsaCurve {
+    /// The curve's name as used by NIST or SEC 2, e.g. `"P-256"` or `"secp256k1"`.
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The ASN.1 signing algorithm that pairs the curve with its conventional digest:
+    /// SHA-256 for P-256 and secp256k1, SHA-384 for P-384, and SHA-512 for P-521.
+    #[inline]
+    #[must_use]
+    pub fn signing_algorithm(&self) -> &'static EcdsaSigningAlgorithm {
+        self.signing_algorithm
+    }
+
+    /// The size, in bytes, of an element of the curve's field, i.e. of each coordinate of a
+    /// point. For the supported curves this is also the size of a private key scalar.
+    #[inline]
+    #[must_use]
+    pub fn field_size_bytes(&self) -> usize {
+        self.signing_algorithm.id.private_key_size()
+    }
+
+    /// The order `n` of the curve's base point, big-endian encoded.
+    ///
+    /// Valid private key scalars, and the `r` and `s` components of a signature, are in the
+    /// range `[1, n - 1]`.
+    ///
+    /// # Errors
+    /// `error::Unspecified` on internal error.
+    pub fn order_bytes(&self) -> Result<Vec<u8>, Unspecified> {
+        let ec_group = ec_group_from_nid(self.signing_algorithm.id.nid())?;
+        let order = ConstPointer::new(unsafe { EC_GROUP_get0_order(*ec_group) })?;
+        Ok(order.to_be_bytes())
+    }
+
+    /// The cofactor of the curve's base point, which is 1 for all of the supported curves.
+    ///
+    /// # Errors
+    /// `error::Unspecified` on internal error.
+    pub fn cofactor(&self) -> Result<u64, Unspecified> {
+        let ec_group = ec_group_from_nid(self.signing_algorithm.id.nid())?;
+        let mut cofactor_bn = LcPtr::new(unsafe { BN_new() })?;
+        if 1 != unsafe { EC_GROUP_get_cofactor(*ec_group, *cofactor_bn.as_mut(), null_mut()) } {
+            return Err(Unspecified);
+        }
+        let mut cofactor = 0u64;
+        if 1 != unsafe { BN_get_u64(*cofactor_bn.as_const(), &mut cofactor) } {
+            return Err(Unspecified);
+        }
+        Ok(cofactor)
+    }
+
+    /// Converts an uncompressed SEC 1 point (`0x04 || x || y`) on this curve to its compressed
+    /// form (`0x02 || x` or `0x03 || x`).
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `point` is not an uncompressed encoding of a point on this curve.
+    pub fn compress_point(&self, point: &[u8]) -> Result<Vec<u8>, Unspecified> {
+        if point.len() != self.signing_algorithm.id.uncompressed_pub_key_len() || point[0] != 0x04 {
+            return Err(Unspecified);
+        }
+        let evp_pkey = parse_sec1_public_point(point, self.signing_algorithm.id.nid())?;
+        marshal_sec1_public_point(&evp_pkey, true)
+    }
+
+    /// Converts a compressed SEC 1 point (`0x02 || x` or `0x03 || x`) on this curve to its
+    /// uncompressed form (`0x04 || x || y`).
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `point` is not a compressed encoding of a point on this curve,
+    /// e.g. if `x` has no corresponding `y` coordinate.
+    pub fn decompress_point(&self, point: &[u8]) -> Result<Vec<u8>, Unspecified> {
+        if point.len() != self.signing_algorithm.id.compressed_pub_key_len()
+            || (point[0] != 0x02 && point[0] != 0x03)
+        {
+            return Err(Unspecified);
+        }
+        let evp_pkey = parse_sec1_public_point(point, self.signing_algorithm.id.nid())?;
+        marshal_sec1_public_point(&evp_pkey, false)
+    }
+
+    /// Multiplies a SEC 1 encoded point on this curve by the curve's cofactor, returning the
+    /// result in the same (compressed or uncompressed) form as `point`.
+    ///
+    /// All of the supported curves have a cofactor of 1, so for them this returns `point`
+    /// unchanged once it has been validated as a point on the curve.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `point` is not a valid encoding of a point on this curve, or if
+    /// the result is the point at infinity.
+    pub fn clear_cofactor(&self, point: &[u8]) -> Result<Vec<u8>, Unspecified> {
+        let id = self.signing_algorithm.id;
+        let compressed = match point.first() {
+            Some(0x04) if point.len() == id.uncompressed_pub_key_len() => false,
+            Some(0x02 | 0x03) if point.len() == id.compressed_pub_key_len() => true,
+            _ => return Err(Unspecified),
+        };
+        let ec_group = ec_group_from_nid(id.nid())?;
+        let mut ec_point = LcPtr::new(unsafe { EC_POINT_new(*ec_group) })?;
+        if 1 != unsafe {
+            EC_POINT_oct2point(
+                *ec_group,
+                *ec_point.as_mut(),
+                point.as_ptr(),
+                point.len(),
+                null_mut(),
+            )
+        } {
+            return Err(Unspecified);
+        }
+
+        let mut cofactor = LcPtr::new(unsafe { BN_new() })?;
+        if 1 != unsafe { EC_GROUP_get_cofactor(*ec_group, *cofactor.as_mut(), null_mut()) } {
+            return Err(Unspecified);
+        }
+        let mut cleared = LcPtr::new(unsafe { EC_POINT_new(*ec_group) })?;
+        if 1 != unsafe {
+            EC_POINT_mul(
+                *ec_group,
+                *cleared.as_mut(),
+                null(),
+                *ec_point.as_const(),
+                *cofactor.as_const(),
+                null_mut(),
+            )
+        } || 1 == unsafe { EC_POINT_is_at_infinity(*ec_group, *cleared.as_const()) }
+        {
+            return Err(Unspecified);
+        }
+
+        let evp_pkey = from_ec_public_point(&ec_group, &cleared)?;
+        marshal_sec1_public_point(&evp_pkey, compressed)
+    }
+}
+
+static CURVES: [EcdsaCurve; 4] = [
+    EcdsaCurve {
+        name: "P-256",
+        aliases: &["P-256", "P256", "secp256r1", "prime256v1"],
+        signing_algorithm: &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+    },
+    EcdsaCurve {
+        name: "P-384",
+        aliases: &["P-384", "P384", "secp384r1"],
+        signing_algorithm: &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+    },
+    EcdsaCurve {
+        name: "P-521",
+        aliases: &["P-521", "P521", "secp521r1"],
+        signing_algorithm: &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+    },
+    EcdsaCurve {
+        name: "secp256k1",
+        aliases: &["secp256k1", "P-256K"],
+        signing_algorithm: &signature::ECDSA_P256K1_SHA256_ASN1_SIGNING,
+    },
+];
+
+/// Looks up an ECDSA curve by one of its common names, ignoring ASCII case.
+///
+/// The NIST names (`"P-256"`, `"P-384"`, `"P-521"`, with or without the hyphen), the SEC 2
+/// names (`"secp256r1"`, `"secp384r1"`, `"secp521r1"`, `"secp256k1"`), the ANSI X9.62 name
+/// `"prime256v1"` and the JOSE name `"P-256K"` are recognized.
+///
+/// Returns `None` if `name` is not recognized.
+#[must_use]
+pub fn curve_from_name(name: &str) -> Option<&'static EcdsaCurve> {
+    CURVES.iter().find(|curve| {
+        curve
+            .aliases
+            .iter()
+            .any(|alias| alias.eq_ignore_ascii_case(name))
+    })
+}
+
 impl sealed::Sealed for EcdsaVerificationAlgorithm {}
 impl sealed::Sealed for EcdsaSigningAlgorithm {}
 
@@ -82,7 +281,6 @@ impl AlgorithmID {
     }
     // Compressed public key length in bytes
     #[inline]
-    #[allow(dead_code)]
     const fn compressed_pub_key_len(&self) -> usize {
         match self {
             AlgorithmID::ECDSA_P256 | AlgorithmID::ECDSA_P256K1 => {
@@ -92,20 +290,106 @@ impl AlgorithmID {
             AlgorithmID::ECDSA_P521 => compressed_public_key_size_bytes(521),
         }
     }
+    // Uncompressed public key length in bytes
+    #[inline]
+    const fn uncompressed_pub_key_len(&self) -> usize {
+        match self {
+            AlgorithmID::ECDSA_P256 | AlgorithmID::ECDSA_P256K1 => {
+                uncompressed_public_key_size_bytes(256)
+            }
+            AlgorithmID::ECDSA_P384 => uncompressed_public_key_size_bytes(384),
+            AlgorithmID::ECDSA_P521 => uncompressed_public_key_size_bytes(521),
+        }
+    }
+}
+
+impl EcdsaVerificationAlgorithm {
+    /// Returns the curve this algorithm operates on.
+    #[must_use]
+    pub fn curve(&self) -> &'static EcdsaCurve {
+        match self.id {
+            AlgorithmID::ECDSA_P256 => &CURVES[0],
+            AlgorithmID::ECDSA_P384 => &CURVES[1],
+            AlgorithmID::ECDSA_P521 => &CURVES[2],
+            AlgorithmID::ECDSA_P256K1 => &CURVES[3],
+        }
+    }
+
+    /// Returns the lengths, in bytes, of a public key for this algorithm encoded as a
+    /// compressed and an uncompressed elliptic curve point, respectively.
+    ///
+    /// The encodings are the Elliptic-Curve-Point-to-Octet-String conversion described in
+    /// [SEC 1: Elliptic Curve Cryptography, Version 2.0].
+    ///
+    /// [SEC 1: Elliptic Curve Cryptography, Version 2.0]: http://www.secg.org/sec1-v2.pdf
+    #[inline]
+    #[must_use]
+    pub fn public_key_sizes(&self) -> (usize, usize) {
+        (
+            self.id.compressed_pub_key_len(),
+            self.id.uncompressed_pub_key_len(),
+        )
+    }
+
+    /// Converts a public key stored as the concatenation of its affine coordinates `X || Y`,
+    /// without the leading `0x04` of the SEC 1 uncompressed encoding, into the uncompressed
+    /// encoding accepted by `UnparsedPublicKey`.
+    ///
+    /// Some legacy formats store keys this way, e.g. 64 bytes for P-256. Each coordinate must be
+    /// left-padded with zeros to the curve's field size.
+    ///
+    /// # Errors
+    /// `error::KeyRejected` if `xy` has the wrong length for this algorithm's curve or is not a
+    /// point on the curve.
+    pub fn public_key_from_raw_coordinates(&self, xy: &[u8]) -> Result<Vec<u8>, KeyRejected> {
+        if xy.len() != self.id.uncompressed_pub_key_len() - 1 {
+            return Err(KeyRejected::invalid_encoding());
+        }
+        let mut public_key = Vec::with_capacity(xy.len() + 1);
+        public_key.push(0x04);
+        public_key.extend_from_slice(xy);
+        parse_sec1_public_point(&public_key, self.id.nid())?;
+        Ok(public_key)
+    }
+
+    /// Creates a `VerificationContext` for verifying a signature over a message that is
+    /// provided incrementally, using the given public key.
+    ///
+    /// `public_key` is accepted in the same encodings as `UnparsedPublicKey::verify`.
+    ///
+    // # FIPS
+    // The following conditions must be met:
+    // * NIST Elliptic Curves: P256, P384, P521
+    // * Digest Algorithms: SHA256, SHA384, SHA512
+    //
+    /// # Errors
+    /// `error::Unspecified` if the public key cannot be parsed for this algorithm.
+    pub fn verification_context(
+        &'static self,
+        public_key: &[u8],
+    ) -> Result<VerificationContext, Unspecified> {
+        let evp_pkey = parse_ec_public_key(public_key, self.id.nid())?;
+        let md_ctx = evp_pkey.digest_verify_init(Some(self.digest), No_EVP_PKEY_CTX_consumer)?;
+        let signature_format = match self.sig_format {
+            EcdsaSignatureFormat::ASN1 => StreamingSignatureFormat::Encoded,
+            EcdsaSignatureFormat::Fixed => StreamingSignatureFormat::EcdsaFixed(self.id),
+        };
+        Ok(VerificationContext::new(evp_pkey, md_ctx, signature_format))
+    }
 }
 
 /// Elliptic curve public key.
 #[derive(Clone)]
 pub struct PublicKey {
     #[allow(dead_code)]
-    algorithm: &'static EcdsaSigningAlgorithm,
+    algorithm: &'static EcdsaVerificationAlgorithm,
     evp_pkey: LcPtr<EVP_PKEY>,
     octets: Box<[u8]>,
 }
 
 pub(crate) fn public_key_from_evp_pkey(
     evp_pkey: &LcPtr<EVP_PKEY>,
-    algorithm: &'static EcdsaSigningAlgorithm,
+    algorithm: &'static EcdsaVerificationAlgorithm,
 ) -> Result<PublicKey, Unspecified> {
     let pub_key_bytes = marshal_sec1_public_point(evp_pkey, false)?;
 
@@ -163,6 +447,9 @@ impl AsRef<[u8]> for PublicKey {
     /// Serializes the public key in an uncompressed form (X9.62) using the
     /// Octet-String-to-Elliptic-Curve-Point algorithm in
     /// [SEC 1: Elliptic Curve Cryptography, Version 2.0].
+    ///
+    /// Each coordinate is left-padded with zeros to the size of the curve's field, so the
+    /// encoding always has the same length for a given curve, e.g. 133 bytes for P-521.
     fn as_ref(&self) -> &[u8] {
         self.octets.as_ref()
     }
@@ -171,6 +458,174 @@ impl AsRef<[u8]> for PublicKey {
 unsafe impl Send for PublicKey {}
 unsafe impl Sync for PublicKey {}
 
+/// Recovers the public key that produced an ECDSA signature over a message digest.
+///
+/// `sig_fixed` is the signature as the fixed-length concatenation `r || s`, each padded to
+/// the size of `alg`'s curve, regardless of `alg`'s signature format. `digest` is the hash of
+/// the signed message computed with `alg`'s digest algorithm. `recovery_id` selects which of
+/// the up to four candidate keys is returned: bit 0 is the parity of the y-coordinate of the
+/// signature's ephemeral point `R`, and bit 1 is set when the x-coordinate of `R` is `r + n`,
+/// where `n` is the order of the curve, as in SEC 1 section 4.1.6.
+///
+/// The returned key is a valid point on `alg`'s curve, but the signature is not otherwise
+/// authenticated: every signature recovers *some* key. Callers must compare the recovered key
+/// against an expected key or address.
+///
+// # FIPS
+// This function must not be used.
+//
+/// # Errors
+/// `error::Unspecified` if `recovery_id` is greater than 3, `digest` is not the output length
+/// of `alg`'s digest algorithm, `sig_fixed` has the wrong length or `r` or `s` is not in the
+/// range `[1, n - 1]`, or no public key can be recovered for `recovery_id`.
+pub fn recover_public_key(
+    alg: &'static EcdsaVerificationAlgorithm,
+    digest: &[u8],
+    sig_fixed: &[u8],
+    recovery_id: u8,
+) -> Result<PublicKey, Unspecified> {
+    if recovery_id > 3 || digest.len() != alg.digest.output_len() {
+        return Err(Unspecified);
+    }
+    let scalar_len = alg.id.private_key_size();
+    if sig_fixed.len() != 2 * scalar_len {
+        return Err(Unspecified);
+    }
+    let (r, s) = sig_fixed.split_at(scalar_len);
+
+    let ec_group = ec_group_from_nid(alg.id.nid())?;
+    let order = ConstPointer::new(unsafe { EC_GROUP_get0_order(*ec_group) })?;
+    let mut bn_ctx = LcPtr::new(unsafe { BN_CTX_new() })?;
+    let r = LcPtr::<BIGNUM>::try_from(r)?;
+    let s = LcPtr::<BIGNUM>::try_from(s)?;
+    for scalar in [&r, &s] {
+        if 1 == unsafe { BN_is_zero(*scalar.as_const()) }
+            || unsafe { BN_cmp(*scalar.as_const(), *order) } >= 0
+        {
+            return Err(Unspecified);
+        }
+    }
+
+    let zero = LcPtr::new(unsafe { BN_new() })?;
+    let r_point = ecdsa_recover_r_point(&ec_group, &order, &r, recovery_id, &mut bn_ctx)?;
+
+    // `e` is the leftmost bits of the digest, as many as there are in `n`.
+    let order_bits = unsafe { BN_num_bits(*order) } as usize;
+    let e_len = digest.len().min((order_bits + 7) / 8);
+    let mut e = LcPtr::<BIGNUM>::try_from(&digest[..e_len])?;
+    if e_len * 8 > order_bits {
+        let shift = i32::try_from(e_len * 8 - order_bits).map_err(|_| Unspecified)?;
+        if 1 != unsafe { BN_rshift(*e.as_mut(), *e.as_const(), shift) } {
+            return Err(Unspecified);
+        }
+    }
+
+    // Q = r^-1 * (s * R - e * G) = (-e * r^-1) * G + (s * r^-1) * R
+    let mut r_inv = LcPtr::new(unsafe { BN_new() })?;
+    if unsafe { BN_mod_inverse(*r_inv.as_mut(), *r.as_const(), *order, *bn_ctx.as_mut()) }.is_null()
+    {
+        return Err(Unspecified);
+    }
+    let mut g_scalar = LcPtr::new(unsafe { BN_new() })?;
+    let mut neg_e = LcPtr::new(unsafe { BN_new() })?;
+    let mut r_scalar = LcPtr::new(unsafe { BN_new() })?;
+    if 1 != unsafe {
+        BN_mod_sub(
+            *neg_e.as_mut(),
+            *zero.as_const(),
+            *e.as_const(),
+            *order,
+            *bn_ctx.as_mut(),
+        )
+    } || 1
+        != unsafe {
+            BN_mod_mul(
+                *g_scalar.as_mut(),
+                *neg_e.as_const(),
+                *r_inv.as_const(),
+                *order,
+                *bn_ctx.as_mut(),
+            )
+        }
+        || 1 != unsafe {
+            BN_mod_mul(
+                *r_scalar.as_mut(),
+                *s.as_const(),
+                *r_inv.as_const(),
+                *order,
+                *bn_ctx.as_mut(),
+            )
+        }
+    {
+        return Err(Unspecified);
+    }
+    let mut public_point = LcPtr::new(unsafe { EC_POINT_new(*ec_group) })?;
+    if 1 != unsafe {
+        EC_POINT_mul(
+            *ec_group,
+            *public_point.as_mut(),
+            *g_scalar.as_const(),
+            *r_point.as_const(),
+            *r_scalar.as_const(),
+            *bn_ctx.as_mut(),
+        )
+    } || 1 == unsafe { EC_POINT_is_at_infinity(*ec_group, *public_point.as_const()) }
+    {
+        return Err(Unspecified);
+    }
+
+    let evp_pkey = from_ec_public_point(&ec_group, &public_point)?;
+    public_key_from_evp_pkey(&evp_pkey, alg)
+}
+
+/// Reconstructs the ephemeral point `R` of an ECDSA signature from `r` and `recovery_id`.
+fn ecdsa_recover_r_point(
+    ec_group: &ConstPointer<EC_GROUP>,
+    order: &ConstPointer<BIGNUM>,
+    r: &LcPtr<BIGNUM>,
+    recovery_id: u8,
+    bn_ctx: &mut LcPtr<BN_CTX>,
+) -> Result<LcPtr<EC_POINT>, Unspecified> {
+    // The x-coordinate of `R` is `r`, or `r + n` if it was reduced modulo `n`, and must be an
+    // element of the curve's field.
+    let mut x = LcPtr::new(unsafe { BN_new() })?;
+    let zero = LcPtr::new(unsafe { BN_new() })?;
+    let addend = if recovery_id & 2 == 0 {
+        *zero.as_const()
+    } else {
+        **order
+    };
+    if 1 != unsafe { BN_add(*x.as_mut(), *r.as_const(), addend) } {
+        return Err(Unspecified);
+    }
+    let mut field_prime = LcPtr::new(unsafe { BN_new() })?;
+    if 1 != unsafe {
+        EC_GROUP_get_curve_GFp(
+            **ec_group,
+            *field_prime.as_mut(),
+            null_mut(),
+            null_mut(),
+            *bn_ctx.as_mut(),
+        )
+    } || unsafe { BN_cmp(*x.as_const(), *field_prime.as_const()) } >= 0
+    {
+        return Err(Unspecified);
+    }
+    let mut r_point = LcPtr::new(unsafe { EC_POINT_new(**ec_group) })?;
+    if 1 != unsafe {
+        EC_POINT_set_compressed_coordinates_GFp(
+            **ec_group,
+            *r_point.as_mut(),
+            *x.as_const(),
+            i32::from(recovery_id & 1),
+            *bn_ctx.as_mut(),
+        )
+    } {
+        return Err(Unspecified);
+    }
+    Ok(r_point)
+}
+
 impl VerificationAlgorithm for EcdsaVerificationAlgorithm {
     #[inline]
     #[cfg(feature = "ring-sig-verify")]
@@ -202,6 +657,10 @@ impl VerificationAlgorithm for EcdsaVerificationAlgorithm {
             }
         }
     }
+
+    fn as_streaming(&'static self) -> Option<&'static dyn StreamingVerificationAlgorithm> {
+        Some(self)
+    }
 }
 
 fn verify_fixed_signature(
@@ -211,6 +670,20 @@ fn verify_fixed_signature(
     msg: &[u8],
     signature: &[u8],
 ) -> Result<(), Unspecified> {
+    with_asn1_signature(alg, signature, |signature| {
+        verify_asn1_signature(alg, digest, public_key, msg, signature)
+    })
+}
+
+/// Converts a fixed-length `signature` into its ASN.1 DER encoding and passes it to `f`.
+pub(crate) fn with_asn1_signature<F>(
+    alg: &'static AlgorithmID,
+    signature: &[u8],
+    f: F,
+) -> Result<(), Unspecified>
+where
+    F: FnOnce(&[u8]) -> Result<(), Unspecified>,
+{
     let mut out_bytes = null_mut::<u8>();
     let mut out_bytes_len = MaybeUninit::<usize>::uninit();
     let sig = unsafe { ecdsa_sig_from_fixed(alg, signature)? };
@@ -221,7 +694,32 @@ fn verify_fixed_signature(
     }
     let out_bytes = LcPtr::new(out_bytes)?;
     let signature = unsafe { out_bytes.as_slice(out_bytes_len.assume_init()) };
-    verify_asn1_signature(alg, digest, public_key, msg, signature)
+    f(signature)
+}
+
+/// Converts a fixed-length `r || s` signature into its ASN.1 DER encoding.
+pub(crate) fn fixed_to_asn1(
+    alg: &'static AlgorithmID,
+    signature: &[u8],
+) -> Result<Signature, Unspecified> {
+    let mut asn1 = None;
+    with_asn1_signature(alg, signature, |signature| {
+        asn1 = Some(Signature::new(|slice| {
+            slice[..signature.len()].copy_from_slice(signature);
+            signature.len()
+        }));
+        Ok(())
+    })?;
+    asn1.ok_or(Unspecified)
+}
+
+/// Converts an ASN.1 DER encoded signature into its fixed-length `r || s` form.
+pub(crate) fn asn1_to_fixed(
+    alg: &'static AlgorithmID,
+    signature: &[u8],
+) -> Result<Signature, Unspecified> {
+    reject_zero_asn1_signature(signature)?;
+    ecdsa_asn1_to_fixed(alg, signature)
 }
 
 fn verify_asn1_signature(
@@ -231,10 +729,91 @@ fn verify_asn1_signature(
     msg: &[u8],
     signature: &[u8],
 ) -> Result<(), Unspecified> {
+    reject_zero_asn1_signature(signature)?;
     let evp_pkey = parse_ec_public_key(public_key, alg.nid())?;
     evp_pkey.verify(msg, Some(digest), No_EVP_PKEY_CTX_consumer, signature)
 }
 
+/// Rejects an ASN.1 DER encoded signature with `r == 0` or `s == 0` before it is passed to
+/// AWS-LC, which would also reject it, so that such signatures are never considered for
+/// verification.
+fn reject_zero_asn1_signature(signature: &[u8]) -> Result<(), Unspecified> {
+    let ecdsa_sig =
+        LcPtr::new(unsafe { ECDSA_SIG_from_bytes(signature.as_ptr(), signature.len()) })?;
+    let r_bn = ConstPointer::new(unsafe { ECDSA_SIG_get0_r(*ecdsa_sig.as_const()) })?;
+    let s_bn = ConstPointer::new(unsafe { ECDSA_SIG_get0_s(*ecdsa_sig.as_const()) })?;
+    if 1 == unsafe { BN_is_zero(*r_bn) } || 1 == unsafe { BN_is_zero(*s_bn) } {
+        return Err(Unspecified);
+    }
+    Ok(())
+}
+
+/// Returns `signature` with `s` replaced by `n - s` if `s` is greater than half the order `n`
+/// of `alg`'s curve, so that `s <= n / 2`. Signatures that are already "low-S" are returned
+/// unchanged.
+pub(crate) fn normalize_low_s(
+    alg: &'static EcdsaVerificationAlgorithm,
+    signature: &[u8],
+) -> Result<Signature, Unspecified> {
+    let ecdsa_sig = match alg.sig_format {
+        EcdsaSignatureFormat::ASN1 => {
+            LcPtr::new(unsafe { ECDSA_SIG_from_bytes(signature.as_ptr(), signature.len()) })?
+        }
+        EcdsaSignatureFormat::Fixed => unsafe { ecdsa_sig_from_fixed(alg.id, signature)? },
+    };
+    let ec_group = ec_group_from_nid(alg.id.nid())?;
+    let order = ConstPointer::new(unsafe { EC_GROUP_get0_order(*ec_group) })?;
+    let r_bn = ConstPointer::new(unsafe { ECDSA_SIG_get0_r(*ecdsa_sig.as_const()) })?;
+    let s_bn = ConstPointer::new(unsafe { ECDSA_SIG_get0_s(*ecdsa_sig.as_const()) })?;
+    if 1 == unsafe { BN_is_zero(*s_bn) } || unsafe { BN_cmp(*s_bn, *order) } >= 0 {
+        return Err(Unspecified);
+    }
+
+    let mut half_order = LcPtr::new(unsafe { BN_new() })?;
+    if 1 != unsafe { BN_rshift1(*half_order.as_mut(), *order) } {
+        return Err(Unspecified);
+    }
+    if unsafe { BN_cmp(*s_bn, *half_order.as_const()) } <= 0 {
+        return Ok(Signature::new(|slice| {
+            slice[..signature.len()].copy_from_slice(signature);
+            signature.len()
+        }));
+    }
+
+    let mut r_copy = DetachableLcPtr::new(unsafe { BN_dup(*r_bn) })?;
+    let mut low_s = DetachableLcPtr::new(unsafe { BN_new() })?;
+    if 1 != unsafe { BN_sub(*low_s.as_mut(), *order, *s_bn) } {
+        return Err(Unspecified);
+    }
+    let mut low_s_sig = LcPtr::new(unsafe { ECDSA_SIG_new() })?;
+    if 1 != unsafe { ECDSA_SIG_set0(*low_s_sig.as_mut(), *r_copy.as_mut(), *low_s.as_mut()) } {
+        return Err(Unspecified);
+    }
+    r_copy.detach();
+    low_s.detach();
+
+    let mut out_bytes = null_mut::<u8>();
+    let mut out_bytes_len = MaybeUninit::<usize>::uninit();
+    if 1 != unsafe {
+        ECDSA_SIG_to_bytes(
+            &mut out_bytes,
+            out_bytes_len.as_mut_ptr(),
+            *low_s_sig.as_const(),
+        )
+    } {
+        return Err(Unspecified);
+    }
+    let out_bytes = LcPtr::new(out_bytes)?;
+    let asn1_sig = unsafe { out_bytes.as_slice(out_bytes_len.assume_init()) };
+    match alg.sig_format {
+        EcdsaSignatureFormat::ASN1 => Ok(Signature::new(|slice| {
+            slice[..asn1_sig.len()].copy_from_slice(asn1_sig);
+            asn1_sig.len()
+        })),
+        EcdsaSignatureFormat::Fixed => ecdsa_asn1_to_fixed(alg.id, asn1_sig),
+    }
+}
+
 #[inline]
 unsafe fn ecdsa_sig_from_fixed(
     alg_id: &'static AlgorithmID,
@@ -244,8 +823,12 @@ unsafe fn ecdsa_sig_from_fixed(
     if signature.len() != 2 * num_size_bytes {
         return Err(());
     }
-    let mut r_bn = DetachableLcPtr::<BIGNUM>::try_from(&signature[..num_size_bytes])?;
-    let mut s_bn = DetachableLcPtr::<BIGNUM>::try_from(&signature[num_size_bytes..])?;
+    let (r, s) = signature.split_at(num_size_bytes);
+    if r.iter().all(|b| *b == 0) || s.iter().all(|b| *b == 0) {
+        return Err(());
+    }
+    let mut r_bn = DetachableLcPtr::<BIGNUM>::try_from(r)?;
+    let mut s_bn = DetachableLcPtr::<BIGNUM>::try_from(s)?;
 
     let mut ecdsa_sig = LcPtr::new(ECDSA_SIG_new())?;
 
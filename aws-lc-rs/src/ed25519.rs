@@ -5,11 +5,15 @@
 
 use core::fmt;
 use core::fmt::{Debug, Formatter};
+#[cfg(not(feature = "fips"))]
+use core::ptr::null;
 use std::marker::PhantomData;
 
 #[cfg(feature = "ring-sig-verify")]
 use untrusted::Input;
 
+#[cfg(not(feature = "fips"))]
+use crate::aws_lc::{ED25519ph_sign, ED25519ph_verify};
 use crate::aws_lc::{EVP_PKEY, EVP_PKEY_ED25519};
 
 use crate::buffer::Buffer;
@@ -23,6 +27,8 @@ use crate::ptr::LcPtr;
 use crate::rand::SecureRandom;
 use crate::signature::{KeyPair, Signature, VerificationAlgorithm};
 use crate::{constant_time, hex, sealed};
+#[cfg(not(feature = "fips"))]
+use zeroize::Zeroize;
 
 /// The length of an Ed25519 public key.
 pub const ED25519_PUBLIC_KEY_LEN: usize = crate::aws_lc::ED25519_PUBLIC_KEY_LEN as usize;
@@ -35,6 +41,13 @@ pub struct EdDSAParameters;
 
 impl sealed::Sealed for EdDSAParameters {}
 
+impl crate::signature::pkcs8_sealed::Pkcs8SigningAlgorithm for EdDSAParameters {
+    fn validate_pkcs8_impl(&self, pkcs8: &[u8]) -> Result<(), KeyRejected> {
+        let evp_pkey = LcPtr::<EVP_PKEY>::parse_rfc5208_private_key(pkcs8, EVP_PKEY_ED25519)?;
+        evp_pkey.validate_as_ed25519()
+    }
+}
+
 impl VerificationAlgorithm for EdDSAParameters {
     #[inline]
     #[cfg(feature = "ring-sig-verify")]
@@ -73,6 +86,132 @@ fn try_ed25519_public_key_from_bytes(key_bytes: &[u8]) -> Result<LcPtr<EVP_PKEY>
     LcPtr::<EVP_PKEY>::parse_rfc5280_public_key(key_bytes, EVP_PKEY_ED25519)
 }
 
+/// Verifies a detached Ed25519 `signature` over the contents of the file at `path`.
+///
+/// The file is memory-mapped and verified with a single call, without reading it into a buffer
+/// first. An empty file is verified as an empty message. `public_key` is in the encoding
+/// accepted by [`ED25519`](crate::signature::ED25519): a raw 32-byte key or an X.509
+/// `SubjectPublicKeyInfo`.
+///
+// # FIPS
+// This function must not be used.
+//
+/// # Safety
+/// The file must not be modified or truncated, by this or any other process, until this
+/// function returns. Doing so while the file is mapped is undefined behavior.
+///
+/// # Errors
+/// `error::Unspecified` if the file cannot be opened or mapped, `public_key` is not a valid
+/// Ed25519 public key, or the signature is not valid.
+#[cfg(feature = "mmap")]
+pub unsafe fn verify_file_ed25519<P: AsRef<std::path::Path>>(
+    public_key: &[u8],
+    path: P,
+    signature: &[u8],
+) -> Result<(), Unspecified> {
+    let evp_pkey = try_ed25519_public_key_from_bytes(public_key)?;
+    let file = std::fs::File::open(path).map_err(|_| Unspecified)?;
+    let file_len = file.metadata().map_err(|_| Unspecified)?.len();
+    // Mapping a zero-length file fails on some platforms.
+    if file_len == 0 {
+        return evp_pkey.verify(&[], None, No_EVP_PKEY_CTX_consumer, signature);
+    }
+    // SAFETY: The mapping is read-only and dropped before returning. The caller guarantees that
+    // the file is not modified while it is mapped.
+    let message = unsafe { memmap2::Mmap::map(&file) }.map_err(|_| Unspecified)?;
+    evp_pkey.verify(&message, None, No_EVP_PKEY_CTX_consumer, signature)
+}
+
+/// Signs `payload` with Ed25519ph, the prehashed variant of Ed25519 used by Sigstore for
+/// `hashedrekord` entries (the `PKIX_ED25519_PH` key details) and by cosign's Ed25519ph
+/// signer.
+///
+/// The payload is hashed with SHA-512 and the digest is signed as specified in
+/// [RFC 8032, Section 5.1], with an empty context. The signature is not a plain Ed25519
+/// signature over `payload`; verify it with [`ed25519_verify_sigstore`].
+///
+/// [RFC 8032, Section 5.1]: https://www.rfc-editor.org/rfc/rfc8032#section-5.1
+///
+/// Not available in FIPS builds, as the FIPS module does not provide Ed25519ph.
+///
+/// # Errors
+/// `error::Unspecified` if signing fails.
+#[cfg(not(feature = "fips"))]
+pub fn ed25519_sign_sigstore(
+    key_pair: &Ed25519KeyPair,
+    payload: &[u8],
+) -> Result<Signature, Unspecified> {
+    let mut seed = key_pair.evp_pkey.marshal_raw_private_key()?;
+    if seed.len() != ED25519_SEED_LEN {
+        seed.zeroize();
+        return Err(Unspecified);
+    }
+    // AWS-LC's Ed25519 private keys are the seed followed by the public key.
+    let mut private_key = [0u8; ED25519_SEED_LEN + ED25519_PUBLIC_KEY_LEN];
+    private_key[..ED25519_SEED_LEN].copy_from_slice(&seed);
+    private_key[ED25519_SEED_LEN..].copy_from_slice(&key_pair.public_key.public_key_bytes);
+    seed.zeroize();
+
+    let mut signature = [0u8; ED25519_SIGNATURE_LEN];
+    let result = unsafe {
+        ED25519ph_sign(
+            signature.as_mut_ptr(),
+            payload.as_ptr(),
+            payload.len(),
+            private_key.as_ptr(),
+            null(),
+            0,
+        )
+    };
+    private_key.zeroize();
+    if 1 != result {
+        return Err(Unspecified);
+    }
+    Ok(Signature::new(|slice| {
+        slice[..ED25519_SIGNATURE_LEN].copy_from_slice(&signature);
+        ED25519_SIGNATURE_LEN
+    }))
+}
+
+/// Verifies an Ed25519ph `signature` over `payload` produced by [`ed25519_sign_sigstore`] or
+/// by Sigstore tooling.
+///
+/// `public_key` is a raw 32-byte Ed25519 public key or an X.509 `SubjectPublicKeyInfo`.
+///
+/// Not available in FIPS builds, as the FIPS module does not provide Ed25519ph.
+///
+/// # Errors
+/// `error::Unspecified` if `public_key` is not a valid Ed25519 public key or the signature is
+/// not valid.
+#[cfg(not(feature = "fips"))]
+pub fn ed25519_verify_sigstore(
+    public_key: &[u8],
+    payload: &[u8],
+    signature: &[u8],
+) -> Result<(), Unspecified> {
+    let evp_pkey = try_ed25519_public_key_from_bytes(public_key)?;
+    let mut raw_public_key = [0u8; ED25519_PUBLIC_KEY_LEN];
+    if ED25519_PUBLIC_KEY_LEN != evp_pkey.marshal_raw_public_to_buffer(&mut raw_public_key)? {
+        return Err(Unspecified);
+    }
+    if signature.len() != ED25519_SIGNATURE_LEN {
+        return Err(Unspecified);
+    }
+    if 1 != unsafe {
+        ED25519ph_verify(
+            payload.as_ptr(),
+            payload.len(),
+            signature.as_ptr(),
+            raw_public_key.as_ptr(),
+            null(),
+            0,
+        )
+    } {
+        return Err(Unspecified);
+    }
+    Ok(())
+}
+
 /// An Ed25519 key pair, for signing.
 #[allow(clippy::module_name_repetitions)]
 pub struct Ed25519KeyPair {
@@ -118,6 +257,10 @@ impl Debug for Seed<'_> {
 #[derive(Clone)]
 #[allow(clippy::module_name_repetitions)]
 /// Ed25519 Public Key
+///
+/// `as_ref()` returns the raw 32-byte public key encoding defined in
+/// [RFC 8032, Section 5.1.5](https://www.rfc-editor.org/rfc/rfc8032#section-5.1.5), not a
+/// `SubjectPublicKeyInfo`. Use [`AsDer<PublicKeyX509Der>`](AsDer) for the latter.
 pub struct PublicKey {
     evp_pkey: LcPtr<EVP_PKEY>,
     public_key_bytes: [u8; ED25519_PUBLIC_KEY_LEN],
@@ -164,6 +307,11 @@ impl KeyPair for Ed25519KeyPair {
     fn public_key(&self) -> &Self::PublicKey {
         &self.public_key
     }
+
+    #[inline]
+    fn verification_algorithm(&self) -> Option<&'static dyn VerificationAlgorithm> {
+        Some(&crate::signature::ED25519)
+    }
 }
 
 unsafe impl Send for Ed25519KeyPair {}
@@ -357,6 +505,26 @@ impl Ed25519KeyPair {
         Self::parse_pkcs8(pkcs8)
     }
 
+    /// Returns the 32-byte public key of the Ed25519 key pair encoded in the unencrypted
+    /// PKCS#8 v1 or v2 document `pkcs8`.
+    ///
+    /// The private key is parsed and validated as in `Ed25519KeyPair::from_pkcs8`, but it is
+    /// released before this function returns rather than being kept in a key pair.
+    ///
+    /// # Errors
+    /// `error::KeyRejected` on parse error, or if key is otherwise unacceptable.
+    pub fn public_from_pkcs8(pkcs8: &[u8]) -> Result<Vec<u8>, KeyRejected> {
+        let evp_pkey = LcPtr::<EVP_PKEY>::parse_rfc5208_private_key(pkcs8, EVP_PKEY_ED25519)?;
+
+        evp_pkey.validate_as_ed25519()?;
+
+        let mut public_key = [0u8; ED25519_PUBLIC_KEY_LEN];
+        let out_len: usize = evp_pkey.marshal_raw_public_to_buffer(&mut public_key)?;
+        debug_assert_eq!(public_key.len(), out_len);
+
+        Ok(public_key.to_vec())
+    }
+
     fn parse_pkcs8(pkcs8: &[u8]) -> Result<Self, KeyRejected> {
         let evp_pkey = LcPtr::<EVP_PKEY>::parse_rfc5208_private_key(pkcs8, EVP_PKEY_ED25519)?;
 
@@ -410,6 +578,23 @@ impl Ed25519KeyPair {
             phantom: PhantomData,
         })
     }
+
+    /// Returns `true` if `public_key` is this key pair's public key.
+    ///
+    /// `public_key` can be a raw 32-byte Ed25519 public key or an X.509
+    /// `SubjectPublicKeyInfo`. Returns `false` if it cannot be parsed.
+    #[must_use]
+    pub fn public_key_matches(&self, public_key: &[u8]) -> bool {
+        let evp_pkey = match try_ed25519_public_key_from_bytes(public_key) {
+            Ok(evp_pkey) => evp_pkey,
+            Err(_) => return false,
+        };
+        let mut raw_public_key = [0u8; ED25519_PUBLIC_KEY_LEN];
+        matches!(
+            evp_pkey.marshal_raw_public_to_buffer(&mut raw_public_key),
+            Ok(ED25519_PUBLIC_KEY_LEN)
+        ) && raw_public_key == self.public_key.public_key_bytes
+    }
 }
 
 impl AsDer<Pkcs8V1Der<'static>> for Ed25519KeyPair {
@@ -5,6 +5,8 @@
 
 use crate::buffer::Buffer;
 
+pub mod ssh;
+
 macro_rules! generated_encodings {
     ($(($name:ident, $name_type:ident)),*) => {
         use core::fmt::{Debug, Error, Formatter};
@@ -55,6 +57,7 @@ macro_rules! generated_encodings {
 }
 pub(crate) use generated_encodings;
 generated_encodings!(
+    (AeadKeyBin, AeadKeyBinType),
     (Curve25519SeedBin, Curve25519SeedBinType),
     (EcPrivateKeyBin, EcPrivateKeyBinType),
     (EcPrivateKeyRfc5915Der, EcPrivateKeyRfc5915DerType),
@@ -67,6 +70,43 @@ generated_encodings!(
     (PublicKeyX509Der, PublicKeyX509DerType)
 );
 
+impl PublicKeyX509Der<'_> {
+    /// Encodes the `SubjectPublicKeyInfo` in the PEM textual encoding with the `PUBLIC KEY`
+    /// label ([RFC 7468, Section 13]), as read by tools such as `openssl pkey -pubin`.
+    ///
+    /// [RFC 7468, Section 13]: https://www.rfc-editor.org/rfc/rfc7468#section-13
+    ///
+    /// # Errors
+    /// Returns Unspecified if encoding fails.
+    pub fn to_pem(&self) -> Result<String, crate::error::Unspecified> {
+        pem_encode("PUBLIC KEY", self.as_ref())
+    }
+}
+
+fn pem_encode(label: &str, der: &[u8]) -> Result<String, crate::error::Unspecified> {
+    const LINE_LEN: usize = 64;
+
+    let mut encoded_len = 0;
+    if 1 != unsafe { crate::aws_lc::EVP_EncodedLength(&mut encoded_len, der.len()) } {
+        return Err(crate::error::Unspecified);
+    }
+    let mut encoded = vec![0u8; encoded_len];
+    // The returned length excludes the trailing NUL byte.
+    let encoded_len =
+        unsafe { crate::aws_lc::EVP_EncodeBlock(encoded.as_mut_ptr(), der.as_ptr(), der.len()) };
+    encoded.truncate(encoded_len);
+
+    let mut pem = format!("-----BEGIN {label}-----\n");
+    for line in encoded.chunks(LINE_LEN) {
+        pem.push_str(core::str::from_utf8(line).map_err(|_| crate::error::Unspecified)?);
+        pem.push('\n');
+    }
+    pem.push_str("-----END ");
+    pem.push_str(label);
+    pem.push_str("-----\n");
+    Ok(pem)
+}
+
 /// Trait for types that can be serialized into a DER format.
 pub trait AsDer<T> {
     /// Serializes into a DER format.
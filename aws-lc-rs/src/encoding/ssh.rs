@@ -0,0 +1,167 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! OpenSSH public key formats.
+//!
+//! # Example
+//! ```
+//! use aws_lc_rs::encoding::ssh;
+//! use aws_lc_rs::signature::{self, UnparsedPublicKey};
+//!
+//! let line = "ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAABAQDY0DlH7fOUQXss0NgkyaQIQ8vjTBzecZZnYidPMfIk9C7rAPg6J0xU8g21VDSjVb9EftKITYdF+5Iw9qsvWeuamH2rWIbG5BN6pn1Q+2KwwZz8Ykd+cdIc+VOrpSxpRYvCoTk+nEbnSKBFvq5zT2c6PvSFauG7wsQAUaFroIrjlsqgE9BPNfN5C4bhpQgLBzAAe8EXw3df83ZmIlStP05zql22jwnaIZ1/vG6FvoNUHPy0gzievHawy+z49TUhsK0+mqTh0dWdqWsp2tnmCXgC8X+pgipwowaUMYeUUgBMdDp6QClfUQ60LEQU0OMPMwVDhdcy7b2kL8/veqCNWib7 test@aws-lc-rs";
+//! let public_key = ssh::parse_rsa_authorized_key(line)?;
+//!
+//! let public_key =
+//!     UnparsedPublicKey::new(&signature::RSA_PKCS1_2048_8192_SHA256, public_key.as_ref());
+//! # Ok::<(), aws_lc_rs::error::KeyRejected>(())
+//! ```
+
+use crate::aws_lc::{EVP_DecodeBase64, EVP_DecodedLength};
+use crate::error::KeyRejected;
+use crate::rsa::PublicKey as RsaPublicKey;
+
+const SSH_RSA_KEY_TYPE: &[u8] = b"ssh-rsa";
+
+/// Parses an `ssh-rsa` public key from a line in the OpenSSH `authorized_keys` format,
+/// i.e., `ssh-rsa <base64-encoded key> [comment]`.
+///
+/// Lines with leading options (e.g., `from="..."`) are not supported.
+///
+/// # Errors
+/// `KeyRejected` if the line is not an `ssh-rsa` key, or the key is not a valid RSA public key.
+pub fn parse_rsa_authorized_key(line: &str) -> Result<RsaPublicKey, KeyRejected> {
+    let mut fields = line.split_ascii_whitespace();
+    if Some("ssh-rsa") != fields.next() {
+        return Err(KeyRejected::wrong_algorithm());
+    }
+    let encoded = fields.next().ok_or_else(KeyRejected::invalid_encoding)?;
+    let blob = decode_base64(encoded.as_bytes())?;
+    parse_rsa_public_key(&blob)
+}
+
+/// Parses an `ssh-rsa` public key from its SSH wire format (RFC 4253, Section 6.6): the
+/// string `ssh-rsa` followed by the public exponent `e` and the modulus `n` as `mpint`s.
+///
+/// # Errors
+/// `KeyRejected` if the input is not an `ssh-rsa` key, or the key is not a valid RSA public key.
+pub fn parse_rsa_public_key(blob: &[u8]) -> Result<RsaPublicKey, KeyRejected> {
+    let mut reader = WireReader(blob);
+    if SSH_RSA_KEY_TYPE != reader.read_string()? {
+        return Err(KeyRejected::wrong_algorithm());
+    }
+    let e = reader.read_positive_mpint()?;
+    let n = reader.read_positive_mpint()?;
+    if !reader.0.is_empty() {
+        return Err(KeyRejected::invalid_encoding());
+    }
+    RsaPublicKey::from_modulus_and_exponent(n, e)
+}
+
+struct WireReader<'a>(&'a [u8]);
+
+impl<'a> WireReader<'a> {
+    fn read_string(&mut self) -> Result<&'a [u8], KeyRejected> {
+        if self.0.len() < 4 {
+            return Err(KeyRejected::invalid_encoding());
+        }
+        let (len, rest) = self.0.split_at(4);
+        let len = u32::from_be_bytes([len[0], len[1], len[2], len[3]]);
+        let len = usize::try_from(len).map_err(|_| KeyRejected::invalid_encoding())?;
+        if rest.len() < len {
+            return Err(KeyRejected::invalid_encoding());
+        }
+        let (value, rest) = rest.split_at(len);
+        self.0 = rest;
+        Ok(value)
+    }
+
+    /// Reads a positive `mpint`, returning its big-endian bytes without leading zeros.
+    fn read_positive_mpint(&mut self) -> Result<&'a [u8], KeyRejected> {
+        let value = self.read_string()?;
+        match value {
+            // A leading zero is only allowed to clear the sign bit.
+            [0, second, ..] if second & 0x80 != 0 => Ok(&value[1..]),
+            // Zero, negative values in two's complement, and non-minimal encodings.
+            [] | [0, ..] => Err(KeyRejected::invalid_encoding()),
+            [first, ..] if first & 0x80 != 0 => Err(KeyRejected::invalid_encoding()),
+            _ => Ok(value),
+        }
+    }
+}
+
+fn decode_base64(encoded: &[u8]) -> Result<Vec<u8>, KeyRejected> {
+    let mut max_len = 0;
+    if 1 != unsafe { EVP_DecodedLength(&mut max_len, encoded.len()) } {
+        return Err(KeyRejected::invalid_encoding());
+    }
+    let mut decoded = vec![0u8; max_len];
+    let mut decoded_len = 0;
+    if 1 != unsafe {
+        EVP_DecodeBase64(
+            decoded.as_mut_ptr(),
+            &mut decoded_len,
+            decoded.len(),
+            encoded.as_ptr(),
+            encoded.len(),
+        )
+    } {
+        return Err(KeyRejected::invalid_encoding());
+    }
+    decoded.truncate(decoded_len);
+    Ok(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::encoding::ssh::{parse_rsa_authorized_key, parse_rsa_public_key};
+    use crate::signature::{self, UnparsedPublicKey};
+    use crate::test::from_dirty_hex;
+
+    // Generated by `ssh-keygen -t rsa -b 2048 -C test@aws-lc-rs`.
+    const AUTHORIZED_KEY: &str = "ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAABAQDY0DlH7fOUQXss0NgkyaQIQ8vjTBzecZZnYidPMfIk9C7rAPg6J0xU8g21VDSjVb9EftKITYdF+5Iw9qsvWeuamH2rWIbG5BN6pn1Q+2KwwZz8Ykd+cdIc+VOrpSxpRYvCoTk+nEbnSKBFvq5zT2c6PvSFauG7wsQAUaFroIrjlsqgE9BPNfN5C4bhpQgLBzAAe8EXw3df83ZmIlStP05zql22jwnaIZ1/vG6FvoNUHPy0gzievHawy+z49TUhsK0+mqTh0dWdqWsp2tnmCXgC8X+pgipwowaUMYeUUgBMdDp6QClfUQ60LEQU0OMPMwVDhdcy7b2kL8/veqCNWib7 test@aws-lc-rs";
+
+    // The same key as an `RSAPublicKey` (RFC 8017), from `openssl rsa -RSAPublicKey_out`.
+    const RSA_PUBLIC_KEY_DER: &str = "3082010a0282010100d8d03947edf394417b2cd0d824c9a40843cbe34c1cde71966762274f31f224f42eeb00f83a274c54f20db55434a355bf447ed2884d8745fb9230f6ab2f59eb9a987dab5886c6e4137aa67d50fb62b0c19cfc62477e71d21cf953aba52c69458bc2a1393e9c46e748a045beae734f673a3ef4856ae1bbc2c40051a16ba08ae396caa013d04f35f3790b86e1a5080b0730007bc117c3775ff376662254ad3f4e73aa5db68f09da219d7fbc6e85be83541cfcb483389ebc76b0cbecf8f53521b0ad3e9aa4e1d1d59da96b29dad9e6097802f17fa9822a70a3069431879452004c743a7a40295f510eb42c4414d0e30f33054385d732edbda42fcfef7aa08d5a26fb0203010001";
+
+    // Generated by `openssl dgst -sha256 -sign` with the private key.
+    const MESSAGE: &[u8] = b"authorized_keys test message";
+    const SIGNATURE: &str = "38de9b395dfa913e30838e19c70f605fa8851bc2f23d38fd30353dd3dd9c54a9fecc1bd530d92ce42b38c1e2c260d6b900ba8da568a7104fdbf47edcc0990853e8a519e3fbbdc30bee0879fee481ee5e8569198f6eed6ba8363ab5452321ed588b4ca4d4ea63c949bcf1bd7bd643168501b7eb157b764d77cc4b21995758bd99009802bd93a7fd1ee9c058f5d6022b31e70bb68a394a8f0a2993bb96b278ff521cde13fe3e62e7f2cfd1b27b91d5a2076488ac3a8f2390c970381251d5d3a51c34d0d9fbaf15dfe45d8b1041fd5e5c76dde69fa432422d379fd2f24f7aed6a10dad834caafac7c886f7f820d70cb913ec0b9b819bbde02f146393a617b34bc49";
+
+    #[test]
+    fn test_parse_rsa_authorized_key() {
+        let public_key = parse_rsa_authorized_key(AUTHORIZED_KEY).unwrap();
+        assert_eq!(from_dirty_hex(RSA_PUBLIC_KEY_DER), public_key.as_ref());
+
+        let public_key =
+            UnparsedPublicKey::new(&signature::RSA_PKCS1_2048_8192_SHA256, public_key.as_ref());
+        let signature = from_dirty_hex(SIGNATURE);
+        public_key.verify(MESSAGE, &signature).unwrap();
+        assert!(public_key.verify(b"another message", &signature).is_err());
+    }
+
+    #[test]
+    fn test_parse_rsa_public_key_rejects_invalid_input() {
+        assert!(parse_rsa_authorized_key("").is_err());
+        assert!(parse_rsa_authorized_key("ssh-rsa").is_err());
+        assert!(parse_rsa_authorized_key("ssh-rsa !!!").is_err());
+        assert!(
+            parse_rsa_authorized_key(&AUTHORIZED_KEY.replacen("ssh-rsa", "ssh-dss", 1)).is_err()
+        );
+
+        let blob =
+            super::decode_base64(AUTHORIZED_KEY.split(' ').nth(1).unwrap().as_bytes()).unwrap();
+        assert!(parse_rsa_public_key(&blob).is_ok());
+        // Truncated or trailing data.
+        assert!(parse_rsa_public_key(&blob[..blob.len() - 1]).is_err());
+        let mut trailing = blob.clone();
+        trailing.push(0);
+        assert!(parse_rsa_public_key(&trailing).is_err());
+
+        // `ssh-rsa`, e = -1, n = 1
+        let negative_exponent = from_dirty_hex("00000007 7373682d727361 00000001 ff 00000001 01");
+        assert!(parse_rsa_public_key(&negative_exponent).is_err());
+        // `ssh-rsa`, e = 0 (non-minimal), n = 1
+        let zero_exponent = from_dirty_hex("00000007 7373682d727361 00000001 00 00000001 01");
+        assert!(parse_rsa_public_key(&zero_exponent).is_err());
+    }
+}
@@ -166,10 +166,7 @@ impl LcPtr<EVP_PKEY> {
         bytes: &[u8],
         evp_pkey_type: c_int,
     ) -> Result<Self, KeyRejected> {
-        let mut cbs = cbs::build_CBS(bytes);
-        // Also checks the validity of the key
-        let evp_pkey = LcPtr::new(unsafe { EVP_parse_private_key(&mut cbs) })
-            .map_err(|()| KeyRejected::invalid_encoding())?;
+        let evp_pkey = Self::parse_rfc5208_private_key_any(bytes)?;
         evp_pkey
             .id()
             .eq(&evp_pkey_type)
@@ -177,6 +174,14 @@ impl LcPtr<EVP_PKEY> {
             .ok_or(KeyRejected::wrong_algorithm())
     }
 
+    /// Parses a PKCS#8 private key of any type. Use `id` to determine the type of key.
+    pub(crate) fn parse_rfc5208_private_key_any(bytes: &[u8]) -> Result<Self, KeyRejected> {
+        let mut cbs = cbs::build_CBS(bytes);
+        // Also checks the validity of the key
+        LcPtr::new(unsafe { EVP_parse_private_key(&mut cbs) })
+            .map_err(|()| KeyRejected::invalid_encoding())
+    }
+
     #[allow(non_snake_case)]
     pub(crate) fn create_EVP_PKEY_CTX(&self) -> Result<LcPtr<EVP_PKEY_CTX>, ()> {
         // The only modification made by EVP_PKEY_CTX_new to `priv_key` is to increment its
@@ -302,31 +307,7 @@ impl LcPtr<EVP_PKEY> {
     where
         F: EVP_PKEY_CTX_consumer,
     {
-        let mut md_ctx = DigestContext::new_uninit();
-        let evp_md = if let Some(alg) = digest {
-            *digest::match_digest_type(&alg.id)
-        } else {
-            null()
-        };
-        let mut pctx = null_mut::<EVP_PKEY_CTX>();
-        if 1 != unsafe {
-            // EVP_DigestSignInit does not mutate |pkey| for thread-safety purposes and may be
-            // used concurrently with other non-mutating functions on |pkey|.
-            // https://github.com/aws/aws-lc/blob/9b4b5a15a97618b5b826d742419ccd54c819fa42/include/openssl/evp.h#L297-L313
-            EVP_DigestSignInit(
-                md_ctx.as_mut_ptr(),
-                &mut pctx,
-                evp_md,
-                null_mut(),
-                *self.as_mut_unsafe(),
-            )
-        } {
-            return Err(Unspecified);
-        }
-
-        if let Some(pad_fn) = padding_fn {
-            pad_fn(pctx)?;
-        }
+        let mut md_ctx = self.digest_sign_init(digest, padding_fn)?;
 
         // Determine the maximum length of the signature.
         let mut sig_len = 0;
@@ -361,28 +342,28 @@ impl LcPtr<EVP_PKEY> {
         Ok(signature.into_boxed_slice())
     }
 
-    pub(crate) fn verify<F>(
+    /// Returns a digest context initialized for signing with this key, which can be used with
+    /// `EVP_DigestSign`, or `EVP_DigestSignUpdate` and `EVP_DigestSignFinal`.
+    pub(crate) fn digest_sign_init<F>(
         &self,
-        msg: &[u8],
         digest: Option<&'static digest::Algorithm>,
         padding_fn: Option<F>,
-        signature: &[u8],
-    ) -> Result<(), Unspecified>
+    ) -> Result<DigestContext, Unspecified>
     where
         F: EVP_PKEY_CTX_consumer,
     {
         let mut md_ctx = DigestContext::new_uninit();
-
         let evp_md = if let Some(alg) = digest {
             *digest::match_digest_type(&alg.id)
         } else {
             null()
         };
-
         let mut pctx = null_mut::<EVP_PKEY_CTX>();
-
         if 1 != unsafe {
-            EVP_DigestVerifyInit(
+            // EVP_DigestSignInit does not mutate |pkey| for thread-safety purposes and may be
+            // used concurrently with other non-mutating functions on |pkey|.
+            // https://github.com/aws/aws-lc/blob/9b4b5a15a97618b5b826d742419ccd54c819fa42/include/openssl/evp.h#L297-L313
+            EVP_DigestSignInit(
                 md_ctx.as_mut_ptr(),
                 &mut pctx,
                 evp_md,
@@ -392,10 +373,26 @@ impl LcPtr<EVP_PKEY> {
         } {
             return Err(Unspecified);
         }
+
         if let Some(pad_fn) = padding_fn {
             pad_fn(pctx)?;
         }
 
+        Ok(md_ctx)
+    }
+
+    pub(crate) fn verify<F>(
+        &self,
+        msg: &[u8],
+        digest: Option<&'static digest::Algorithm>,
+        padding_fn: Option<F>,
+        signature: &[u8],
+    ) -> Result<(), Unspecified>
+    where
+        F: EVP_PKEY_CTX_consumer,
+    {
+        let mut md_ctx = self.digest_verify_init(digest, padding_fn)?;
+
         if 1 != indicator_check!(unsafe {
             EVP_DigestVerify(
                 md_ctx.as_mut_ptr(),
@@ -411,6 +408,44 @@ impl LcPtr<EVP_PKEY> {
         Ok(())
     }
 
+    /// Returns a digest context initialized for verification with this key, which can be used
+    /// with `EVP_DigestVerify`, or `EVP_DigestVerifyUpdate` and `EVP_DigestVerifyFinal`.
+    pub(crate) fn digest_verify_init<F>(
+        &self,
+        digest: Option<&'static digest::Algorithm>,
+        padding_fn: Option<F>,
+    ) -> Result<DigestContext, Unspecified>
+    where
+        F: EVP_PKEY_CTX_consumer,
+    {
+        let mut md_ctx = DigestContext::new_uninit();
+
+        let evp_md = if let Some(alg) = digest {
+            *digest::match_digest_type(&alg.id)
+        } else {
+            null()
+        };
+
+        let mut pctx = null_mut::<EVP_PKEY_CTX>();
+
+        if 1 != unsafe {
+            EVP_DigestVerifyInit(
+                md_ctx.as_mut_ptr(),
+                &mut pctx,
+                evp_md,
+                null_mut(),
+                *self.as_mut_unsafe(),
+            )
+        } {
+            return Err(Unspecified);
+        }
+        if let Some(pad_fn) = padding_fn {
+            pad_fn(pctx)?;
+        }
+
+        Ok(md_ctx)
+    }
+
     pub(crate) fn generate<F>(pkey_type: c_int, params_fn: Option<F>) -> Result<Self, Unspecified>
     where
         F: EVP_PKEY_CTX_consumer,
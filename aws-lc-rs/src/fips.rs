@@ -1,6 +1,23 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
+//! FIPS service indicator.
+//!
+//! When built with the `fips-indicator` feature, each cryptographic operation records whether
+//! AWS-LC's service indicator reported it as an approved service. The status is tracked per
+//! thread. Recording costs two FFI calls and a thread-local write per operation, so release
+//! builds only do it when the feature is enabled.
+
+/// Returns whether the most recent cryptographic operation performed on the current thread was
+/// reported as approved by the FIPS service indicator.
+///
+/// Returns `false` if no operation has been recorded on the current thread.
+#[cfg(feature = "fips-indicator")]
+#[must_use]
+pub fn last_operation_approved() -> bool {
+    indicator::peek_status() == Some(true)
+}
+
 /// Retrieve the FIPS module service status.
 #[allow(dead_code)] // appease clippy
 #[cfg(all(feature = "fips", debug_assertions))]
@@ -18,7 +35,7 @@ pub(crate) fn get_fips_service_status() -> FipsServiceStatus<()> {
 
 #[inline]
 pub(crate) fn set_fips_service_status_unapproved() {
-    #[cfg(all(feature = "fips", debug_assertions))]
+    #[cfg(all(feature = "fips", any(debug_assertions, feature = "fips-indicator")))]
     indicator::set_unapproved();
 }
 
@@ -29,7 +46,7 @@ pub(crate) fn clear_fips_service_status() {
     indicator::clear();
 }
 
-#[cfg(all(feature = "fips", debug_assertions))]
+#[cfg(all(feature = "fips", any(debug_assertions, feature = "fips-indicator")))]
 pub(crate) mod indicator {
     use core::cell::Cell;
 
@@ -47,6 +64,12 @@ pub(crate) mod indicator {
         })
     }
 
+    // Returns the current indicator status without resetting it.
+    #[cfg(feature = "fips-indicator")]
+    pub fn peek_status() -> Option<bool> {
+        STATUS_INDICATOR.with(Cell::get)
+    }
+
     pub fn set_approved() {
         STATUS_INDICATOR.with(|v| v.set(Some(true)));
     }
@@ -60,13 +83,13 @@ pub(crate) mod indicator {
     }
 }
 
-#[cfg(all(feature = "fips", debug_assertions))]
+#[cfg(all(feature = "fips", any(debug_assertions, feature = "fips-indicator")))]
 #[inline]
 pub(crate) fn service_indicator_before_call() -> u64 {
     unsafe { aws_lc::FIPS_service_indicator_before_call() }
 }
 
-#[cfg(all(feature = "fips", debug_assertions))]
+#[cfg(all(feature = "fips", any(debug_assertions, feature = "fips-indicator")))]
 #[inline]
 pub(crate) fn service_indicator_after_call() -> u64 {
     unsafe { aws_lc::FIPS_service_indicator_after_call() }
@@ -109,7 +132,7 @@ impl<R> FipsServiceStatus<R> {
 
 macro_rules! indicator_check {
     ($function:expr) => {{
-        #[cfg(all(feature = "fips", debug_assertions))]
+        #[cfg(all(feature = "fips", any(debug_assertions, feature = "fips-indicator")))]
         {
             use crate::fips::{service_indicator_after_call, service_indicator_before_call};
             let before = service_indicator_before_call();
@@ -123,7 +146,7 @@ macro_rules! indicator_check {
                 result
             }
         }
-        #[cfg(any(not(feature = "fips"), not(debug_assertions)))]
+        #[cfg(not(all(feature = "fips", any(debug_assertions, feature = "fips-indicator"))))]
         {
             $function
         }
@@ -189,4 +212,24 @@ mod tests {
             FipsServiceStatus::Unset(()).map(|()| true)
         );
     }
+
+    #[cfg(feature = "fips-indicator")]
+    #[test]
+    fn test_last_operation_approved() {
+        use crate::aead::quic::{HeaderProtectionKey, CHACHA20};
+        use crate::digest::{digest, SHA256};
+        use crate::fips::last_operation_approved;
+
+        let _ = digest(&SHA256, b"hello, world");
+        assert!(last_operation_approved());
+        // Querying the status does not reset it.
+        assert!(last_operation_approved());
+
+        let key = HeaderProtectionKey::new(&CHACHA20, &[0u8; 32]).unwrap();
+        let _ = key.new_mask(&[0u8; 16]).unwrap();
+        assert!(!last_operation_approved());
+
+        let _ = digest(&SHA256, b"hello, world");
+        assert!(last_operation_approved());
+    }
 }
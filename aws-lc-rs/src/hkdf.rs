@@ -37,11 +37,11 @@
 //! let aead_unbound_key = aead::UnboundKey::from(aes_keying_material);
 //! ```
 
-use crate::aws_lc::{HKDF_expand, HKDF};
+use crate::aws_lc::{HKDF_expand, HKDF_extract};
+use crate::encoding::AeadKeyBin;
 use crate::error::Unspecified;
-use crate::fips::indicator_check;
-use crate::{digest, hmac};
-use alloc::sync::Arc;
+use crate::fips::{indicator_check, set_fips_service_status_unapproved};
+use crate::{aead, digest, hmac};
 use core::fmt;
 use zeroize::Zeroize;
 
@@ -72,8 +72,9 @@ pub static HKDF_SHA384: Algorithm = Algorithm(hmac::HMAC_SHA384);
 pub static HKDF_SHA512: Algorithm = Algorithm(hmac::HMAC_SHA512);
 
 /// General Salt length's for HKDF don't normally exceed 256 bits.
-/// We set the limit to something tolerable, so that the Salt structure can be stack allocatable.
-const MAX_HKDF_SALT_LEN: usize = 80;
+/// Salts longer than the digest's block length are hashed first (as HMAC does with its key), so
+/// the Salt structure remains stack allocatable while accepting salts of any length.
+const MAX_HKDF_SALT_LEN: usize = digest::MAX_BLOCK_LEN;
 
 /// General Info length's for HKDF don't normally exceed 256 bits.
 /// We set the default capacity to a value larger than should be needed
@@ -116,6 +117,8 @@ impl Salt {
     /// Constructs a new `Salt` with the given value based on the given digest
     /// algorithm.
     ///
+    /// As specified in [RFC 5869], the salt may be of any length, including empty.
+    ///
     /// Constructing a `Salt` is relatively expensive so it is good to reuse a
     /// `Salt` object instead of re-constructing `Salt`s with the same value.
     ///
@@ -128,25 +131,28 @@ impl Salt {
     //   * `HKDF_SHA512`
     // * `value.len() > 0` is true
     //
-    /// # Panics
-    /// `new` panics if the salt length exceeds the limit
+    /// [RFC 5869]: https://tools.ietf.org/html/rfc5869#section-2.2
     #[must_use]
     pub fn new(algorithm: Algorithm, value: &[u8]) -> Self {
-        Salt::try_new(algorithm, value).expect("Salt length limit exceeded.")
-    }
-
-    fn try_new(algorithm: Algorithm, value: &[u8]) -> Result<Salt, Unspecified> {
+        // The salt is used as an HMAC key, and HMAC replaces keys longer than the digest's
+        // block length with their digest. Doing so here yields the same PRK.
+        let digest_algorithm = algorithm.0.digest_algorithm();
+        let hashed_value;
+        let value = if value.len() > digest_algorithm.block_len {
+            hashed_value = digest::digest(digest_algorithm, value);
+            hashed_value.as_ref()
+        } else {
+            value
+        };
+        // `value` is now at most one block long, which always fits.
         let salt_len = value.len();
-        if salt_len > MAX_HKDF_SALT_LEN {
-            return Err(Unspecified);
-        }
         let mut salt_bytes = [0u8; MAX_HKDF_SALT_LEN];
         salt_bytes[0..salt_len].copy_from_slice(value);
-        Ok(Self {
+        Self {
             algorithm,
             bytes: salt_bytes,
             len: salt_len,
-        })
+        }
     }
 
     /// The [HKDF-Extract] operation.
@@ -154,16 +160,41 @@ impl Salt {
     /// [HKDF-Extract]: https://tools.ietf.org/html/rfc5869#section-2.2
     ///
     /// # Panics
-    /// Panics if the extract operation is unable to be performed
+    /// Panics if AWS-LC's `HKDF_extract` fails. This does not happen for any input: HKDF-Extract
+    /// is a single HMAC, the digest is always one that AWS-LC supports, and the salt and the
+    /// secret may have any length.
     #[inline]
     #[must_use]
     pub fn extract(&self, secret: &[u8]) -> Prk {
+        let digest = *digest::match_digest_type(&self.algorithm.0.digest_algorithm().id);
+        let mut key_bytes = [0u8; MAX_HKDF_PRK_LEN];
+        let mut key_len = 0;
+        if 1 != indicator_check!(unsafe {
+            HKDF_extract(
+                key_bytes.as_mut_ptr(),
+                &mut key_len,
+                digest,
+                secret.as_ptr(),
+                secret.len(),
+                self.bytes.as_ptr(),
+                self.len,
+            )
+        }) {
+            key_bytes.zeroize();
+            // Only reachable on an internal error in AWS-LC; see `# Panics` above.
+            panic!("HKDF_extract failed");
+        }
+        let empty_salt = self.len == 0;
+        if empty_salt {
+            set_fips_service_status_unapproved();
+        }
+
         Prk {
             algorithm: self.algorithm,
             mode: PrkMode::ExtractExpand {
-                secret: Arc::from(ZeroizeBoxSlice::from(secret)),
-                salt: self.bytes,
-                salt_len: self.len,
+                key_bytes,
+                key_len,
+                empty_salt,
             },
         }
     }
@@ -207,55 +238,59 @@ enum PrkMode {
         key_len: usize,
     },
     ExtractExpand {
-        secret: Arc<ZeroizeBoxSlice<u8>>,
-        salt: [u8; MAX_HKDF_SALT_LEN],
-        salt_len: usize,
+        key_bytes: [u8; MAX_HKDF_PRK_LEN],
+        key_len: usize,
+        // FIPS requires a non-empty salt; HKDF_expand cannot tell how the PRK was extracted.
+        empty_salt: bool,
     },
 }
 
+impl Drop for PrkMode {
+    fn drop(&mut self) {
+        match self {
+            PrkMode::Expand { key_bytes, .. } | PrkMode::ExtractExpand { key_bytes, .. } => {
+                key_bytes.zeroize();
+            }
+        }
+    }
+}
+
 impl PrkMode {
     fn fill(&self, algorithm: Algorithm, out: &mut [u8], info: &[u8]) -> Result<(), Unspecified> {
         let digest = *digest::match_digest_type(&algorithm.0.digest_algorithm().id);
-
-        match &self {
-            PrkMode::Expand { key_bytes, key_len } => unsafe {
-                if 1 != indicator_check!(HKDF_expand(
-                    out.as_mut_ptr(),
-                    out.len(),
-                    digest,
-                    key_bytes.as_ptr(),
-                    *key_len,
-                    info.as_ptr(),
-                    info.len(),
-                )) {
-                    return Err(Unspecified);
-                }
-            },
-            PrkMode::ExtractExpand {
-                secret,
-                salt,
-                salt_len,
-            } => {
-                if 1 != indicator_check!(unsafe {
-                    HKDF(
-                        out.as_mut_ptr(),
-                        out.len(),
-                        digest,
-                        secret.as_ptr(),
-                        secret.len(),
-                        salt.as_ptr(),
-                        *salt_len,
-                        info.as_ptr(),
-                        info.len(),
-                    )
-                }) {
-                    return Err(Unspecified);
-                }
-            }
+        let key = self.as_bytes();
+
+        if 1 != indicator_check!(unsafe {
+            HKDF_expand(
+                out.as_mut_ptr(),
+                out.len(),
+                digest,
+                key.as_ptr(),
+                key.len(),
+                info.as_ptr(),
+                info.len(),
+            )
+        }) {
+            return Err(Unspecified);
+        }
+        if let PrkMode::ExtractExpand {
+            empty_salt: true, ..
+        } = self
+        {
+            set_fips_service_status_unapproved();
         }
 
         Ok(())
     }
+
+    fn as_bytes(&self) -> &[u8] {
+        match self {
+            PrkMode::Expand { key_bytes, key_len }
+            | PrkMode::ExtractExpand {
+                key_bytes, key_len, ..
+            } => &key_bytes[..*key_len],
+        }
+    }
 }
 
 impl fmt::Debug for PrkMode {
@@ -267,29 +302,10 @@ impl fmt::Debug for PrkMode {
     }
 }
 
-struct ZeroizeBoxSlice<T: Zeroize>(Box<[T]>);
-
-impl<T: Zeroize> core::ops::Deref for ZeroizeBoxSlice<T> {
-    type Target = [T];
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-
-impl<T: Clone + Zeroize> From<&[T]> for ZeroizeBoxSlice<T> {
-    fn from(value: &[T]) -> Self {
-        Self(Vec::from(value).into_boxed_slice())
-    }
-}
-
-impl<T: Zeroize> Drop for ZeroizeBoxSlice<T> {
-    fn drop(&mut self) {
-        self.0.zeroize();
-    }
-}
-
 /// A HKDF PRK (pseudorandom key).
+///
+/// A `Prk` is `Clone`, `Send` and `Sync`, so the result of a single extract can be shared to
+/// expand several keys, including from different threads.
 #[derive(Clone)]
 pub struct Prk {
     algorithm: Algorithm,
@@ -338,8 +354,23 @@ impl Prk {
         })
     }
 
+    /// Exposes the raw bytes of this PRK.
+    ///
+    /// **The returned value is secret key material.** Usually one can avoid using this. It is
+    /// useful when the PRK must be passed to another system that performs HKDF-Expand itself.
+    /// The length of the returned slice is equal to the output length of the algorithm's
+    /// digest, unless the `Prk` was constructed with [`Prk::new_less_safe`], in which case it is
+    /// the length of the value given.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.mode.as_bytes()
+    }
+
     /// The [HKDF-Expand] operation.
     ///
+    /// The HKDF `info` parameter is the concatenation of the slices in `info`, so it may be
+    /// passed as separate segments (e.g. label, context and length).
+    ///
     /// [HKDF-Expand]: https://tools.ietf.org/html/rfc5869#section-2.3
     ///
     /// # Errors
@@ -451,9 +482,90 @@ impl<L: KeyType> Okm<'_, L> {
     }
 }
 
+pub(crate) struct OutputLen(pub(crate) usize);
+
+impl KeyType for OutputLen {
+    fn len(&self) -> usize {
+        self.0
+    }
+}
+
+/// The TLS 1.3 `HKDF-Expand-Label` function of [RFC 8446 Section 7.1] with an empty context.
+///
+/// [RFC 8446 Section 7.1]: https://www.rfc-editor.org/rfc/rfc8446#section-7.1
+fn expand_label(prk: &Prk, label: &[u8], out: &mut [u8]) -> Result<(), Unspecified> {
+    const LABEL_PREFIX: &[u8] = b"tls13 ";
+    let out_len = u16::try_from(out.len()).map_err(|_| Unspecified)?;
+    let label_len = u8::try_from(LABEL_PREFIX.len() + label.len()).map_err(|_| Unspecified)?;
+    let out_len = out_len.to_be_bytes();
+    let label_len = [label_len];
+    let info: [&[u8]; 5] = [&out_len, &label_len, LABEL_PREFIX, label, &[0]];
+    let okm = prk.expand(&info, OutputLen(out.len()))?;
+    okm.fill(out)
+}
+
+/// Derives the AEAD key and IV for a TLS 1.3 traffic secret.
+///
+/// `prk` is the traffic secret, e.g. a `client_handshake_traffic_secret`, constructed with
+/// [`Prk::new_less_safe`] using the hash of the cipher suite. The key and IV are expanded with
+/// the `"key"` and `"iv"` labels as specified in [RFC 8446 Section 7.3]. The returned key bytes
+/// have length `aead_alg.key_len()` and can be passed to [`aead::UnboundKey::new`]; the IV is
+/// XORed with the record sequence number to form each record's nonce. The key bytes are
+/// zeroized when dropped.
+///
+/// [RFC 8446 Section 7.3]: https://www.rfc-editor.org/rfc/rfc8446#section-7.3
+///
+/// # Errors
+/// `error::Unspecified` if the expansion fails.
+pub fn derive_aead_material(
+    prk: &Prk,
+    aead_alg: &'static aead::Algorithm,
+) -> Result<(AeadKeyBin<'static>, [u8; aead::NONCE_LEN]), Unspecified> {
+    expand_aead_material(
+        aead_alg,
+        |key| expand_label(prk, b"key", key),
+        |iv| expand_label(prk, b"iv", iv),
+    )
+}
+
+// Fills an `aead_alg` key with `expand_key` and then an IV with `expand_iv`. The key bytes are
+// zeroized on drop, including when either expansion fails.
+pub(crate) fn expand_aead_material(
+    aead_alg: &'static aead::Algorithm,
+    expand_key: impl FnOnce(&mut [u8]) -> Result<(), Unspecified>,
+    expand_iv: impl FnOnce(&mut [u8]) -> Result<(), Unspecified>,
+) -> Result<(AeadKeyBin<'static>, [u8; aead::NONCE_LEN]), Unspecified> {
+    let mut key_bytes = vec![0u8; aead_alg.key_len()];
+    let result = expand_key(&mut key_bytes);
+    let key_bytes = AeadKeyBin::new(key_bytes);
+    result?;
+    let mut iv_bytes = [0u8; aead::NONCE_LEN];
+    expand_iv(&mut iv_bytes)?;
+    Ok((key_bytes, iv_bytes))
+}
+
+/// Derives an AEAD nonce by expanding `prk` with `info`.
+///
+/// This is intended for schemes that derive each nonce deterministically, e.g. from a message
+/// counter included in `info`. The caller is responsible for ensuring that `info` is never
+/// repeated for the same `prk` and key, since the derived nonce is only as unique as its input.
+///
+/// The nonce is the first [`aead::NONCE_LEN`] bytes of HKDF-Expand output.
+///
+/// # Errors
+/// `error::Unspecified` if the expansion fails.
+pub fn derive_nonce(prk: &Prk, info: &[&[u8]]) -> Result<aead::Nonce, Unspecified> {
+    let mut nonce_bytes = [0u8; aead::NONCE_LEN];
+    prk.expand(info, OutputLen(aead::NONCE_LEN))?
+        .fill(&mut nonce_bytes)?;
+    Ok(aead::Nonce::assume_unique_for_key(nonce_bytes))
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::hkdf::{Salt, HKDF_SHA256, HKDF_SHA384};
+    use crate::aead::{Aad, LessSafeKey, UnboundKey, AES_128_GCM, AES_256_GCM, NONCE_LEN};
+    use crate::hkdf::{derive_aead_material, derive_nonce, Prk, Salt, HKDF_SHA256, HKDF_SHA384};
+    use crate::test::from_hex;
 
     #[cfg(feature = "fips")]
     mod fips;
@@ -504,4 +616,86 @@ mod tests {
             format!("{okm:?}")
         );
     }
+
+    #[test]
+    fn test_derive_aead_material() {
+        // RFC 8448 Section 3, client and server handshake traffic secrets with
+        // TLS_AES_128_GCM_SHA256.
+        let cases = [
+            (
+                "b3eddb126e067f35a780b3abf45e2d8f3b1a950738f52e9600746a0e27a55a21",
+                "dbfaa693d1762c5b666af5d950258d01",
+                "5bd3c71b836e0b76bb73265f",
+            ),
+            (
+                "b67b7d690cc16c4e75e54213cb2d37b4e9c912bcded9105d42befd59d391ad38",
+                "3fce516009c21727d0f2e4e86ee403bc",
+                "5d313eb2671276ee13000b30",
+            ),
+        ];
+        for (secret, key, iv) in cases {
+            let prk = Prk::new_less_safe(HKDF_SHA256, &from_hex(secret).unwrap());
+            let (key_bytes, iv_bytes) = derive_aead_material(&prk, &AES_128_GCM).unwrap();
+            assert_eq!(from_hex(key).unwrap(), key_bytes.as_ref());
+            assert_eq!(from_hex(iv).unwrap(), iv_bytes);
+        }
+
+        let prk = Prk::new_less_safe(HKDF_SHA384, &[0u8; 48]);
+        let (key_bytes, _) = derive_aead_material(&prk, &AES_256_GCM).unwrap();
+        assert_eq!(32, key_bytes.as_ref().len());
+    }
+
+    #[test]
+    fn test_derive_nonce_kat() {
+        // RFC 5869 Appendix A.1, truncated to the first `NONCE_LEN` bytes of the OKM.
+        let ikm = from_hex("0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b").unwrap();
+        let salt = from_hex("000102030405060708090a0b0c").unwrap();
+        let prk = Salt::new(HKDF_SHA256, &salt).extract(&ikm);
+
+        let nonce = derive_nonce(
+            &prk,
+            &[&[0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9]],
+        )
+        .unwrap();
+        assert_eq!(
+            from_hex("3cb25f25faacd57a90434f64").unwrap(),
+            nonce.as_ref()
+        );
+
+        // The info may be split into segments.
+        let nonce = derive_nonce(
+            &prk,
+            &[
+                &[0xf0, 0xf1, 0xf2, 0xf3, 0xf4],
+                &[0xf5, 0xf6, 0xf7, 0xf8, 0xf9],
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            from_hex("3cb25f25faacd57a90434f64").unwrap(),
+            nonce.as_ref()
+        );
+    }
+
+    #[test]
+    fn test_derive_nonce() {
+        let prk = Salt::new(HKDF_SHA256, b"salt").extract(b"secret");
+        let counter = 7u64.to_be_bytes();
+        let info: &[&[u8]] = &[b"nonce", &counter];
+
+        let nonce = derive_nonce(&prk, info).unwrap();
+        assert_eq!(NONCE_LEN, nonce.as_ref().len());
+
+        let next_counter = 8u64.to_be_bytes();
+        let next_nonce = derive_nonce(&prk, &[b"nonce", &next_counter]).unwrap();
+        assert_ne!(nonce.as_ref(), next_nonce.as_ref());
+
+        let key = LessSafeKey::new(UnboundKey::new(&AES_128_GCM, &[0x42; 16]).unwrap());
+        let mut in_out = b"hello, world".to_vec();
+        key.seal_in_place_append_tag(nonce, Aad::empty(), &mut in_out)
+            .unwrap();
+        let nonce = derive_nonce(&prk, info).unwrap();
+        let plaintext = key.open_in_place(nonce, Aad::empty(), &mut in_out).unwrap();
+        assert_eq!(b"hello, world", plaintext);
+    }
 }
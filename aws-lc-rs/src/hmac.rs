@@ -149,6 +149,9 @@ pub static HMAC_SHA384: Algorithm = Algorithm(&digest::SHA384);
 /// HMAC using SHA-512.
 pub static HMAC_SHA512: Algorithm = Algorithm(&digest::SHA512);
 
+/// HMAC using SHA-512/256.
+pub static HMAC_SHA512_256: Algorithm = Algorithm(&digest::SHA512_256);
+
 /// An HMAC tag.
 ///
 /// For a given tag `t`, use `t.as_ref()` to get the tag value as a byte slice.
@@ -484,6 +487,49 @@ pub fn verify(key: &Key, data: &[u8], tag: &[u8]) -> Result<(), Unspecified> {
     constant_time::verify_slices_are_equal(sign(key, data).as_ref(), tag)
 }
 
+/// Computes an HMAC-based one-time password as specified in [RFC 4226].
+///
+/// The HMAC of the big-endian `counter` is computed with `key` and reduced to a
+/// `digits`-long decimal value using the RFC 4226 dynamic truncation. RFC 4226
+/// uses `HMAC_SHA1_FOR_LEGACY_USE_ONLY`; other algorithms are permitted as in
+/// [RFC 6238].
+///
+/// [RFC 4226]: https://www.rfc-editor.org/rfc/rfc4226
+/// [RFC 6238]: https://www.rfc-editor.org/rfc/rfc6238
+///
+/// # Errors
+/// `error::Unspecified` if `digits` is not in the range `6..=9`.
+pub fn hotp(key: &Key, counter: u64, digits: u32) -> Result<u32, Unspecified> {
+    if !(6..=9).contains(&digits) {
+        return Err(Unspecified);
+    }
+    let tag = sign(key, &counter.to_be_bytes());
+    let tag = tag.as_ref();
+    let offset = usize::from(tag[tag.len() - 1] & 0x0f);
+    let truncated = u32::from_be_bytes([
+        tag[offset] & 0x7f,
+        tag[offset + 1],
+        tag[offset + 2],
+        tag[offset + 3],
+    ]);
+    Ok(truncated % 10u32.pow(digits))
+}
+
+/// Computes a time-based one-time password as specified in [RFC 6238].
+///
+/// `unix_time` is the number of seconds since the Unix epoch and `time_step`
+/// is the validity period of each password in seconds (commonly 30). The
+/// counter passed to [`hotp`] is `unix_time / time_step`.
+///
+/// [RFC 6238]: https://www.rfc-editor.org/rfc/rfc6238
+///
+/// # Errors
+/// `error::Unspecified` if `time_step` is zero or `digits` is not in the range `6..=9`.
+pub fn totp(key: &Key, unix_time: u64, time_step: u64, digits: u32) -> Result<u32, Unspecified> {
+    let counter = unix_time.checked_div(time_step).ok_or(Unspecified)?;
+    hotp(key, counter, digits)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{hmac, rand};
@@ -506,6 +552,7 @@ mod tests {
             hmac::HMAC_SHA256,
             hmac::HMAC_SHA384,
             hmac::HMAC_SHA512,
+            hmac::HMAC_SHA512_256,
         ] {
             let key = hmac::Key::generate(*algorithm, &rng).unwrap();
             let tag = hmac::sign(&key, HELLO_WORLD_GOOD);
@@ -527,6 +574,7 @@ mod tests {
             hmac::HMAC_SHA256,
             hmac::HMAC_SHA384,
             hmac::HMAC_SHA512,
+            hmac::HMAC_SHA512_256,
         ] {
             // Clone after updating context with message, then check if the final Tag is the same.
             let key = hmac::Key::new(alg, &[0; 32]);
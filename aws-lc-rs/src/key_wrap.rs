@@ -54,6 +54,9 @@ pub enum BlockCipherId {
 
     /// AES Block Cipher with 256-bit key.
     Aes256,
+
+    /// AES Block Cipher with 192-bit key.
+    Aes192,
 }
 
 /// A key wrap block cipher.
@@ -99,6 +102,12 @@ pub const AES_128: AesBlockCipher = AesBlockCipher {
     key_len: 16,
 };
 
+/// AES Block Cipher with 192-bit key.
+pub const AES_192: AesBlockCipher = AesBlockCipher {
+    id: BlockCipherId::Aes192,
+    key_len: 24,
+};
+
 /// AES Block Cipher with 256-bit key.
 pub const AES_256: AesBlockCipher = AesBlockCipher {
     id: BlockCipherId::Aes256,
@@ -267,7 +276,7 @@ impl KeyWrap for KeyEncryptionKey<AesBlockCipher> {
         ciphertext: &[u8],
         output: &'output mut [u8],
     ) -> Result<&'output mut [u8], Unspecified> {
-        if output.len() < ciphertext.len() - 8 {
+        if output.len() < ciphertext.len().checked_sub(8).ok_or(Unspecified)? {
             return Err(Unspecified);
         }
 
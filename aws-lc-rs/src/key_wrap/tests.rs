@@ -8,7 +8,7 @@ mod fips;
 
 use crate::key_wrap::AesKek;
 
-use super::{BlockCipher, BlockCipherId, KeyWrap, KeyWrapPadded, AES_128, AES_256};
+use super::{BlockCipher, BlockCipherId, KeyWrap, KeyWrapPadded, AES_128, AES_192, AES_256};
 
 macro_rules! block_cipher_test {
     ($name:ident, $alg:expr, $id:expr, $key_len:literal) => {
@@ -22,6 +22,7 @@ macro_rules! block_cipher_test {
 }
 
 block_cipher_test!(aes_128_cipher, &AES_128, BlockCipherId::Aes128, 16);
+block_cipher_test!(aes_192_cipher, &AES_192, BlockCipherId::Aes192, 24);
 block_cipher_test!(aes_256_cipher, &AES_256, BlockCipherId::Aes256, 32);
 
 #[test]
@@ -682,3 +683,131 @@ unwrap_with_padding_input_output_invalid_test!(unwrap_padded_input_len_smaller_t
 
 // Output length < Input length - 8
 unwrap_with_padding_input_output_invalid_test!(unwrap_padded_output_len_too_small, 24, 15);
+
+// RFC 3394 Section 4 test vectors.
+
+nist_aes_key_wrap_test!(
+    kw_rfc3394_4_1,
+    &AES_128,
+    &[
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f
+    ],
+    &[
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee,
+        0xff
+    ],
+    &[
+        0x1f, 0xa6, 0x8b, 0x0a, 0x81, 0x12, 0xb4, 0x47, 0xae, 0xf3, 0x4b, 0xd8, 0xfb, 0x5a, 0x7b,
+        0x82, 0x9d, 0x3e, 0x86, 0x23, 0x71, 0xd2, 0xcf, 0xe5
+    ]
+);
+
+nist_aes_key_wrap_test!(
+    kw_rfc3394_4_2,
+    &AES_192,
+    &[
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17
+    ],
+    &[
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee,
+        0xff
+    ],
+    &[
+        0x96, 0x77, 0x8b, 0x25, 0xae, 0x6c, 0xa4, 0x35, 0xf9, 0x2b, 0x5b, 0x97, 0xc0, 0x50, 0xae,
+        0xd2, 0x46, 0x8a, 0xb8, 0xa1, 0x7a, 0xd8, 0x4e, 0x5d
+    ]
+);
+
+nist_aes_key_wrap_test!(
+    kw_rfc3394_4_3,
+    &AES_256,
+    &[
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d,
+        0x1e, 0x1f
+    ],
+    &[
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee,
+        0xff
+    ],
+    &[
+        0x64, 0xe8, 0xc3, 0xf9, 0xce, 0x0f, 0x5b, 0xa2, 0x63, 0xe9, 0x77, 0x79, 0x05, 0x81, 0x8a,
+        0x2a, 0x93, 0xc8, 0x19, 0x1e, 0x7d, 0x6e, 0x8a, 0xe7
+    ]
+);
+
+nist_aes_key_wrap_test!(
+    kw_rfc3394_4_4,
+    &AES_192,
+    &[
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17
+    ],
+    &[
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee,
+        0xff, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07
+    ],
+    &[
+        0x03, 0x1d, 0x33, 0x26, 0x4e, 0x15, 0xd3, 0x32, 0x68, 0xf2, 0x4e, 0xc2, 0x60, 0x74, 0x3e,
+        0xdc, 0xe1, 0xc6, 0xc7, 0xdd, 0xee, 0x72, 0x5a, 0x93, 0x6b, 0xa8, 0x14, 0x91, 0x5c, 0x67,
+        0x62, 0xd2
+    ]
+);
+
+nist_aes_key_wrap_test!(
+    kw_rfc3394_4_5,
+    &AES_256,
+    &[
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d,
+        0x1e, 0x1f
+    ],
+    &[
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee,
+        0xff, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07
+    ],
+    &[
+        0xa8, 0xf9, 0xbc, 0x16, 0x12, 0xc6, 0x8b, 0x3f, 0xf6, 0xe6, 0xf4, 0xfb, 0xe3, 0x0e, 0x71,
+        0xe4, 0x76, 0x9c, 0x8b, 0x80, 0xa3, 0x2c, 0xb8, 0x95, 0x8c, 0xd5, 0xd1, 0x7d, 0x6b, 0x25,
+        0x4d, 0xa1
+    ]
+);
+
+nist_aes_key_wrap_test!(
+    kw_rfc3394_4_6,
+    &AES_256,
+    &[
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d,
+        0x1e, 0x1f
+    ],
+    &[
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee,
+        0xff, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d,
+        0x0e, 0x0f
+    ],
+    &[
+        0x28, 0xc9, 0xf4, 0x04, 0xc4, 0xb8, 0x10, 0xf4, 0xcb, 0xcc, 0xb3, 0x5c, 0xfb, 0x87, 0xf8,
+        0x26, 0x3f, 0x57, 0x86, 0xe2, 0xd8, 0x0e, 0xd3, 0x26, 0xcb, 0xc7, 0xf0, 0xe7, 0x1a, 0x99,
+        0xf4, 0x3b, 0xfb, 0x98, 0x8b, 0x9b, 0x7a, 0x02, 0xdd, 0x21
+    ]
+);
+
+#[test]
+fn aes_192_kek_length_mismatch() {
+    AesKek::new(&AES_192, &[42u8; 16]).expect_err("key length mismatch");
+    AesKek::new(&AES_192, &[42u8; 32]).expect_err("key length mismatch");
+}
+
+#[test]
+fn unwrap_input_len_invalid() {
+    for input_len in [0, 7, 8, 16, 25] {
+        let kek = AesKek::new(&AES_128, &[16u8; 16]).expect("key creation successful");
+        let input = vec![42u8; input_len];
+        let mut output = vec![0u8; 32];
+        kek.unwrap(input.as_slice(), output.as_mut_slice())
+            .expect_err("failure");
+    }
+}
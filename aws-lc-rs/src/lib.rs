@@ -44,6 +44,16 @@
 //! Enable feature to preserve compatibility with ring's `signature::VerificationAlgorithm::verify`
 //! function. This adds a requirement on `untrusted = "0.7.1"`.
 //!
+//! #### legacy-cbc-hmac
+//!
+//! Enable feature to access the `aead::cbc_hmac` module, providing AES-CBC with HMAC-SHA256 in
+//! encrypt-then-MAC order for interoperability with legacy protocols.
+//!
+//! #### mmap
+//!
+//! Enable feature to access `signature::verify_file_ed25519`, which memory-maps the file being
+//! verified. This adds a requirement on `memmap2`.
+//!
 //! #### fips
 //!
 //! Enable this feature to have aws-lc-rs use the [*aws-lc-fips-sys*](https://crates.io/crates/aws-lc-fips-sys)
@@ -54,6 +64,12 @@
 //! Currently, aws-lc-fips-sys binds to
 //! [AWS-LC-FIPS 3.0.x](https://github.com/aws/aws-lc/tree/fips-2024-09-27).
 //!
+//! #### fips-indicator
+//!
+//! Implies "fips". Enable this feature to record the FIPS service indicator status of each
+//! operation in release builds, and to access `fips::last_operation_approved`. Without it, the
+//! status is only recorded in debug builds.
+//!
 //! Consult with your local FIPS compliance team to determine the version of AWS-LC-FIPS module that you require. Consumers
 //! needing to remain on a previous version of the AWS-LC-FIPS module should pin to specific versions of aws-lc-rs to avoid
 //! automatically being upgraded to a newer module version.
@@ -188,6 +204,9 @@ mod ed25519;
 pub mod encoding;
 mod endian;
 mod evp_pkey;
+#[cfg(feature = "fips-indicator")]
+pub mod fips;
+#[cfg(not(feature = "fips-indicator"))]
 mod fips;
 mod hex;
 pub mod iv;
@@ -201,7 +220,7 @@ pub mod rsa;
 pub mod tls_prf;
 pub mod unstable;
 
-pub(crate) use debug::derive_debug_via_id;
+pub(crate) use debug::{derive_debug_via_id, derive_hex_via_as_ref};
 // TODO: Uncomment when MSRV >= 1.64
 // use core::ffi::CStr;
 use std::ffi::CStr;
@@ -7,6 +7,14 @@
 //!
 //! [RFC 5208]: https://tools.ietf.org/html/rfc5208.
 
+use crate::aws_lc::{
+    NID_X9_62_prime256v1, NID_secp256k1, NID_secp384r1, NID_secp521r1, EVP_PKEY, EVP_PKEY_EC,
+    EVP_PKEY_ED25519, EVP_PKEY_RSA,
+};
+use crate::ec::evp_key_curve_nid;
+use crate::error::KeyRejected;
+use crate::ptr::LcPtr;
+use crate::signature::{self, EcdsaKeyPair, Ed25519KeyPair, RsaKeyPair};
 use zeroize::Zeroize;
 
 /// A generated PKCS#8 document.
@@ -38,3 +46,104 @@ pub(crate) enum Version {
     V1,
     V2,
 }
+
+/// A key pair parsed by [`parse_pkcs8_any`].
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum AnyKeyPair {
+    /// An ECDSA key pair.
+    Ecdsa(EcdsaKeyPair),
+
+    /// An Ed25519 key pair.
+    Ed25519(Ed25519KeyPair),
+
+    /// An RSA key pair.
+    Rsa(RsaKeyPair),
+}
+
+/// Parses an unencrypted PKCS#8 document holding an ECDSA, Ed25519 or RSA private key, using
+/// the algorithm identifier of the document to determine the type of key.
+///
+/// An ECDSA key pair signs with ASN.1 DER encoded signatures using the digest that matches its
+/// curve: SHA-256 for P-256 and secp256k1, SHA-384 for P-384 and SHA-512 for P-521. Use
+/// [`EcdsaKeyPair::from_pkcs8`] directly to select a different signing algorithm. Ed25519 keys
+/// are accepted in PKCS#8 v1 or v2 documents, as with
+/// [`Ed25519KeyPair::from_pkcs8_maybe_unchecked`].
+///
+/// # Errors
+/// `error::KeyRejected` if `pkcs8` is not a valid PKCS#8 document, holds a key of another type
+/// or on an unsupported curve, or the key is otherwise unacceptable.
+#[allow(non_upper_case_globals)]
+pub fn parse_pkcs8_any(pkcs8: &[u8]) -> Result<AnyKeyPair, KeyRejected> {
+    let evp_pkey = LcPtr::<EVP_PKEY>::parse_rfc5208_private_key_any(pkcs8)?;
+    match evp_pkey.id() {
+        EVP_PKEY_EC => {
+            let alg = match evp_key_curve_nid(&evp_pkey.as_const())? {
+                NID_X9_62_prime256v1 => &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+                NID_secp384r1 => &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+                NID_secp521r1 => &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+                NID_secp256k1 => &signature::ECDSA_P256K1_SHA256_ASN1_SIGNING,
+                _ => return Err(KeyRejected::wrong_algorithm()),
+            };
+            Ok(AnyKeyPair::Ecdsa(EcdsaKeyPair::from_pkcs8(alg, pkcs8)?))
+        }
+        EVP_PKEY_ED25519 => Ok(AnyKeyPair::Ed25519(
+            Ed25519KeyPair::from_pkcs8_maybe_unchecked(pkcs8)?,
+        )),
+        EVP_PKEY_RSA => Ok(AnyKeyPair::Rsa(RsaKeyPair::from_pkcs8(pkcs8)?)),
+        _ => Err(KeyRejected::wrong_algorithm()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pkcs8::{parse_pkcs8_any, AnyKeyPair};
+    use crate::rand::SystemRandom;
+    use crate::signature::{
+        EcdsaKeyPair, Ed25519KeyPair, KeyPair, ECDSA_P256_SHA256_ASN1_SIGNING,
+        ECDSA_P384_SHA384_ASN1_SIGNING,
+    };
+
+    #[test]
+    fn test_parse_pkcs8_any() {
+        let rng = SystemRandom::new();
+
+        for alg in [
+            &ECDSA_P256_SHA256_ASN1_SIGNING,
+            &ECDSA_P384_SHA384_ASN1_SIGNING,
+        ] {
+            let pkcs8 = EcdsaKeyPair::generate_pkcs8(alg, &rng).unwrap();
+            let expected = EcdsaKeyPair::from_pkcs8(alg, pkcs8.as_ref()).unwrap();
+            match parse_pkcs8_any(pkcs8.as_ref()).unwrap() {
+                AnyKeyPair::Ecdsa(key_pair) => {
+                    assert_eq!(
+                        expected.public_key().as_ref(),
+                        key_pair.public_key().as_ref()
+                    );
+                }
+                other => panic!("unexpected key pair: {other:?}"),
+            }
+        }
+
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&rng).unwrap();
+        let expected = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+        match parse_pkcs8_any(pkcs8.as_ref()).unwrap() {
+            AnyKeyPair::Ed25519(key_pair) => {
+                assert_eq!(
+                    expected.public_key().as_ref(),
+                    key_pair.public_key().as_ref()
+                );
+            }
+            other => panic!("unexpected key pair: {other:?}"),
+        }
+
+        let pkcs8 = include_bytes!("../tests/data/rsa_test_private_key_2048.p8");
+        match parse_pkcs8_any(pkcs8).unwrap() {
+            AnyKeyPair::Rsa(key_pair) => assert_eq!(256, key_pair.public_modulus_len()),
+            other => panic!("unexpected key pair: {other:?}"),
+        }
+
+        assert!(parse_pkcs8_any(&pkcs8[..pkcs8.len() - 1]).is_err());
+        assert!(parse_pkcs8_any(b"not a PKCS#8 document").is_err());
+    }
+}
@@ -10,7 +10,7 @@ use crate::pkcs8::{Document, Version};
 use crate::pqdsa::signature::{PqdsaSigningAlgorithm, PublicKey};
 use crate::pqdsa::validate_pqdsa_evp_key;
 use crate::ptr::LcPtr;
-use crate::signature::KeyPair;
+use crate::signature::{KeyPair, VerificationAlgorithm};
 use core::fmt::{Debug, Formatter};
 use std::ffi::c_int;
 
@@ -37,6 +37,10 @@ impl KeyPair for PqdsaKeyPair {
     fn public_key(&self) -> &Self::PublicKey {
         &self.pubkey
     }
+
+    fn verification_algorithm(&self) -> Option<&'static dyn VerificationAlgorithm> {
+        Some(self.algorithm.0)
+    }
 }
 
 /// A PQDSA private key.
@@ -172,7 +176,7 @@ pub(crate) fn evp_key_pqdsa_generate(nid: c_int) -> Result<LcPtr<EVP_PKEY>, Unsp
 mod tests {
     use super::*;
 
-    use crate::signature::{UnparsedPublicKey, VerificationAlgorithm};
+    use crate::signature::UnparsedPublicKey;
     use crate::unstable::signature::{MLDSA_44_SIGNING, MLDSA_65_SIGNING, MLDSA_87_SIGNING};
 
     const TEST_ALGORITHMS: &[&PqdsaSigningAlgorithm] =
@@ -136,6 +136,71 @@ impl SystemRandom {
     }
 }
 
+impl SystemRandom {
+    /// Checks that the random number generator is functional, e.g. for startup diagnostics.
+    ///
+    /// A sample of output is drawn and subjected to the continuous health tests of
+    /// [NIST SP 800-90B, Section 4.4]: the Repetition Count Test and the Adaptive Proportion
+    /// Test. The tests are parameterized for a false-positive probability of 2^-20 per sample
+    /// and a conservatively assumed min-entropy of one bit per byte, so they only detect gross
+    /// failures such as stuck or highly repetitive output. Passing does not demonstrate that
+    /// the output is unpredictable.
+    ///
+    /// [NIST SP 800-90B, Section 4.4]: https://nvlpubs.nist.gov/nistpubs/SpecialPublications/NIST.SP.800-90B.pdf
+    ///
+    /// # Errors
+    /// `error::Unspecified` if random bytes cannot be obtained or a health test fails.
+    pub fn self_test(&self) -> Result<(), Unspecified> {
+        let mut sample = [0u8; HEALTH_TEST_SAMPLE_LEN];
+        sealed::SecureRandom::fill_impl(self, &mut sample)?;
+        repetition_count_test(&sample)?;
+        adaptive_proportion_test(&sample)
+    }
+}
+
+// Two windows of the Adaptive Proportion Test.
+const HEALTH_TEST_SAMPLE_LEN: usize = 2 * APT_WINDOW_SIZE;
+
+// SP 800-90B, Section 4.4.1: C = 1 + ceil(-log2(alpha) / H) with alpha = 2^-20 and H = 1.
+const RCT_CUTOFF: usize = 21;
+
+// SP 800-90B, Section 4.4.2, Table 2: non-binary samples, H = 1.
+const APT_WINDOW_SIZE: usize = 512;
+const APT_CUTOFF: usize = 410;
+
+fn repetition_count_test(sample: &[u8]) -> Result<(), Unspecified> {
+    let mut run = 0;
+    let mut previous = None;
+    for &byte in sample {
+        if previous == Some(byte) {
+            run += 1;
+            if run >= RCT_CUTOFF {
+                return Err(Unspecified);
+            }
+        } else {
+            previous = Some(byte);
+            run = 1;
+        }
+    }
+    Ok(())
+}
+
+fn adaptive_proportion_test(sample: &[u8]) -> Result<(), Unspecified> {
+    for window in sample.chunks(APT_WINDOW_SIZE) {
+        let first = window[0];
+        let mut count = 0;
+        for &byte in window {
+            if byte == first {
+                count += 1;
+                if count >= APT_CUTOFF {
+                    return Err(Unspecified);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
 impl Default for SystemRandom {
     fn default() -> Self {
         SYSTEM_RANDOM
@@ -183,6 +248,45 @@ mod tests {
         println!("Mean: {mean} Variance: {variance}");
     }
 
+    #[test]
+    fn test_system_random_self_test() {
+        SystemRandom::new().self_test().unwrap();
+    }
+
+    #[test]
+    fn test_health_tests() {
+        use super::{adaptive_proportion_test, repetition_count_test, HEALTH_TEST_SAMPLE_LEN};
+
+        let mut sample = [0u8; HEALTH_TEST_SAMPLE_LEN];
+        for (i, byte) in sample.iter_mut().enumerate() {
+            *byte = u8::try_from(i % 251).unwrap();
+        }
+        repetition_count_test(&sample).unwrap();
+        adaptive_proportion_test(&sample).unwrap();
+
+        // A run of 21 identical values fails the Repetition Count Test, but not the Adaptive
+        // Proportion Test.
+        sample[100..121].fill(0xAA);
+        assert!(repetition_count_test(&sample).is_err());
+        adaptive_proportion_test(&sample).unwrap();
+        sample[110] = 0;
+        repetition_count_test(&sample).unwrap();
+
+        // Stuck output fails both.
+        let stuck = [0x5Au8; HEALTH_TEST_SAMPLE_LEN];
+        assert!(repetition_count_test(&stuck).is_err());
+        assert!(adaptive_proportion_test(&stuck).is_err());
+
+        // Alternating values pass the Repetition Count Test, but a value that makes up most
+        // of a window fails the Adaptive Proportion Test.
+        let mut alternating = [0u8; HEALTH_TEST_SAMPLE_LEN];
+        for (i, byte) in alternating.iter_mut().enumerate() {
+            *byte = u8::from(i % 8 == 7);
+        }
+        repetition_count_test(&alternating).unwrap();
+        assert!(adaptive_proportion_test(&alternating).is_err());
+    }
+
     #[test]
     fn test_rand_fill() {
         // Collect enough random values so that the assertions below should never fail again
@@ -95,6 +95,9 @@ impl OaepPublicEncryptingKey {
     /// Encrypts the contents in `plaintext` and writes the corresponding ciphertext to `ciphertext`.
     /// Returns the subslice of `ciphertext` containing the ciphertext output.
     ///
+    /// The optional `label` binds associated data to the ciphertext; the same label must be
+    /// provided to decrypt it. `None` is equivalent to an empty label.
+    ///
     /// # Max Plaintext Length
     /// The provided length of `plaintext` must be at most [`Self::max_plaintext_size`].
     ///
@@ -199,6 +202,9 @@ impl OaepPrivateDecryptingKey {
     /// Decrypts the contents in `ciphertext` and writes the corresponding plaintext to `plaintext`.
     /// Returns the subslice of `plaintext` containing the plaintext output.
     ///
+    /// `label` must be the label that was provided when encrypting. `None` is equivalent to an
+    /// empty label.
+    ///
     /// # Max Ciphertext Length
     /// The provided length of `ciphertext` must be [`Self::key_size_bytes`].
     ///
@@ -207,7 +213,8 @@ impl OaepPrivateDecryptingKey {
     /// length of `output` must be greater then or equal to [`Self::min_output_size`].
     ///
     /// # Errors
-    /// * `Unspecified` for any error that occurs while decrypting `ciphertext`.
+    /// * `Unspecified` for any error that occurs while decrypting `ciphertext`, including a
+    ///   `label` that does not match the one used for encryption.
     pub fn decrypt<'plaintext>(
         &self,
         algorithm: &'static OaepAlgorithm,
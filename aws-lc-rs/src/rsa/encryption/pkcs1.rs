@@ -122,8 +122,16 @@ impl Pkcs1PrivateDecryptingKey {
     /// # Sizing `output`
     /// The length of `output` must be greater than or equal to [`Self::min_output_size`].
     ///
+    /// # Padding Failures
+    /// AWS-LC checks the PKCS#1 v1.5 padding in constant time but does not implement implicit
+    /// rejection: a `ciphertext` with invalid padding is reported as an error rather than
+    /// decrypting to a synthetic plaintext. Whether decryption succeeded is therefore
+    /// observable by the caller, and protocols using this key must not reveal it to an attacker
+    /// (see Bleichenbacher's attack). Prefer RSA-OAEP for new protocols.
+    ///
     /// # Errors
-    /// * `Unspecified` for any error that occurs while decrypting `ciphertext`.
+    /// * `Unspecified` for any error that occurs while decrypting `ciphertext`, including
+    ///   invalid padding.
     pub fn decrypt<'plaintext>(
         &self,
         ciphertext: &[u8],
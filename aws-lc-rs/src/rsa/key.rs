@@ -2,13 +2,13 @@
 // SPDX-License-Identifier: ISC
 // Modifications copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0 OR ISC
-use super::signature::{RsaEncoding, RsaPadding};
+use super::signature::RsaEncoding;
 use super::{encoding, RsaParameters};
 #[cfg(feature = "fips")]
 use crate::aws_lc::RSA;
 use crate::aws_lc::{
-    EVP_PKEY_CTX_set_rsa_keygen_bits, EVP_PKEY_assign_RSA, EVP_PKEY_new, RSA_new, RSA_set0_key,
-    RSA_size, EVP_PKEY, EVP_PKEY_RSA, EVP_PKEY_RSA_PSS,
+    EVP_PKEY_CTX_set_rsa_keygen_bits, EVP_PKEY_assign_RSA, EVP_PKEY_new, RSA_check_key, RSA_new,
+    RSA_set0_key, RSA_size, EVP_PKEY, EVP_PKEY_RSA, EVP_PKEY_RSA_PSS,
 };
 #[cfg(feature = "ring-io")]
 use crate::aws_lc::{RSA_get0_e, RSA_get0_n};
@@ -33,7 +33,7 @@ use std::os::raw::c_int;
 
 use crate::pkcs8::Version;
 use crate::rsa::encoding::{rfc5280, rfc8017};
-use crate::rsa::signature::configure_rsa_pkcs1_pss_padding;
+use crate::rsa::signature::{configure_rsa_pkcs1_pss_padding, pss_salt_len};
 #[cfg(feature = "ring-io")]
 use untrusted::Input;
 use zeroize::Zeroize;
@@ -212,11 +212,12 @@ impl KeyPair {
         signature: &mut [u8],
     ) -> Result<(), Unspecified> {
         let encoding = padding_alg.encoding();
-        let padding_fn = if let RsaPadding::RSA_PKCS1_PSS_PADDING = encoding.padding() {
-            Some(configure_rsa_pkcs1_pss_padding)
-        } else {
-            None
-        };
+        let padding_fn = pss_salt_len(
+            encoding.padding(),
+            encoding.digest_algorithm(),
+            self.evp_pkey.key_size_bits(),
+        )?
+        .map(|salt_len| move |pctx| configure_rsa_pkcs1_pss_padding(pctx, salt_len));
 
         let sig_bytes = self
             .evp_pkey
@@ -240,6 +241,16 @@ impl KeyPair {
             Err(_) => unreachable!(),
         }
     }
+
+    /// Returns `true` if `public_key` is this key pair's public key, i.e. has the same
+    /// modulus (n) and public exponent (e).
+    ///
+    /// `public_key` can be a DER-encoded PKCS#1 `RSAPublicKey` (RFC 8017) or X.509
+    /// `SubjectPublicKeyInfo` (RFC 5280). Returns `false` if it cannot be parsed.
+    #[must_use]
+    pub fn public_key_matches(&self, public_key: &[u8]) -> bool {
+        matches!(PublicKey::from_der(public_key), Ok(public_key) if public_key == self.serialized_public_key)
+    }
 }
 
 impl Debug for KeyPair {
@@ -319,8 +330,45 @@ impl PublicKey {
             &rfc8017::decode_public_key_der(input).or(rfc5280::decode_public_key_der(input))?,
         )
     }
+
+    /// Parses an RSA public key from a DER-encoded X.509 `SubjectPublicKeyInfo` structure
+    /// (RFC 5280).
+    ///
+    /// Unlike [`Self::from_der`], a PKCS#1 `RSAPublicKey` structure is not accepted.
+    ///
+    /// # Errors
+    /// `KeyRejected` if `der` is not a `SubjectPublicKeyInfo` for a valid RSA key.
+    pub fn from_der_spki(der: &[u8]) -> Result<Self, KeyRejected> {
+        PublicKey::new(&rfc5280::decode_public_key_der(der)?)
+    }
+
+    /// Constructs an RSA public key from its public modulus (n) and public exponent (e),
+    /// each encoded in big-endian bytes without leading zeros.
+    ///
+    /// # Errors
+    /// `KeyRejected` if the components are not a valid RSA public key.
+    pub fn from_modulus_and_exponent(n: &[u8], e: &[u8]) -> Result<Self, KeyRejected> {
+        let evp_pkey = PublicKeyComponents { n, e }
+            .build_rsa()
+            .map_err(|()| KeyRejected::invalid_encoding())?;
+        if 1 != unsafe { RSA_check_key(*evp_pkey.get_rsa()?) } {
+            return Err(KeyRejected::inconsistent_components());
+        }
+        PublicKey::new(&evp_pkey)
+    }
 }
 
+/// Two public keys are equal if they have the same modulus (n) and public exponent (e),
+/// regardless of how or from which encoding they were constructed.
+impl PartialEq for PublicKey {
+    fn eq(&self, other: &Self) -> bool {
+        // `key` is AWS-LC's DER encoding of (n, e), which is canonical.
+        self.key == other.key
+    }
+}
+
+impl Eq for PublicKey {}
+
 impl Debug for PublicKey {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
         f.write_str(&format!(
@@ -3,17 +3,25 @@
 
 use std::fmt::{self, Debug, Formatter};
 use std::ops::RangeInclusive;
+// TODO: Uncomment when MSRV >= 1.64
+// use core::ffi::c_int;
+use std::os::raw::c_int;
 
 use crate::aws_lc::{
-    EVP_PKEY_CTX_set_rsa_padding, EVP_PKEY_CTX_set_rsa_pss_saltlen, RSA_bits, EVP_PKEY,
-    EVP_PKEY_CTX, RSA_PKCS1_PSS_PADDING, RSA_PSS_SALTLEN_DIGEST,
+    EVP_PKEY_CTX_set_rsa_padding, EVP_PKEY_CTX_set_rsa_pss_saltlen, RSA_bits, RSA_size,
+    RSA_verify_raw, EVP_PKEY, EVP_PKEY_CTX, RSA, RSA_PKCS1_PADDING, RSA_PKCS1_PSS_PADDING,
+    RSA_PSS_SALTLEN_DIGEST,
 };
 
+use crate::constant_time;
 use crate::digest::{self};
 use crate::error::Unspecified;
 use crate::ptr::LcPtr;
 use crate::sealed::Sealed;
-use crate::signature::VerificationAlgorithm;
+use crate::signature::streaming::StreamingSignatureFormat;
+use crate::signature::{
+    StreamingVerificationAlgorithm, VerificationAlgorithm, VerificationContext,
+};
 
 use super::encoding;
 #[cfg(feature = "ring-sig-verify")]
@@ -21,10 +29,56 @@ use untrusted::Input;
 
 #[allow(non_camel_case_types)]
 #[allow(clippy::module_name_repetitions)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub enum RsaPadding {
     RSA_PKCS1_PADDING,
-    RSA_PKCS1_PSS_PADDING,
+    RSA_PKCS1_PSS_PADDING(RsaPssSaltLength),
+}
+
+/// The length of the salt used for RSA-PSS signatures.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, PartialEq, Eq)]
+pub enum RsaPssSaltLength {
+    /// The salt is the same length as the message digest. This is the default for the
+    /// `RSA_PSS_*` algorithms, and matches the salt length Java uses for RSASSA-PSS when it is
+    /// configured with a `PSSParameterSpec` for the same digest (e.g., 32 bytes for SHA-256).
+    Digest,
+    /// The salt is the maximum length permitted by the size of the RSA modulus, i.e.
+    /// `emLen - hLen - 2` bytes as defined in [RFC 8017 Section 9.1.1].
+    ///
+    /// [RFC 8017 Section 9.1.1]: https://tools.ietf.org/html/rfc8017#section-9.1.1
+    Max,
+    /// Verification only: any salt length is accepted, the length being recovered from the
+    /// signature as described in [RFC 4055 Section 3.1], for when the signer's salt length is
+    /// not known.
+    ///
+    /// [RFC 4055 Section 3.1]: https://tools.ietf.org/html/rfc4055#section-3.1
+    Auto,
+}
+
+// AWS-LC recovers the salt length from the signature when verifying with this value. It is not
+// exported by the bindings.
+const RSA_PSS_SALTLEN_AUTO: c_int = -2;
+
+impl RsaPssSaltLength {
+    fn salt_len(
+        &self,
+        digest: &'static digest::Algorithm,
+        key_size_bits: usize,
+    ) -> Result<c_int, Unspecified> {
+        match self {
+            RsaPssSaltLength::Digest => Ok(RSA_PSS_SALTLEN_DIGEST),
+            RsaPssSaltLength::Max => {
+                // emLen = ceil((modBits - 1) / 8)
+                let em_len = (key_size_bits.checked_sub(1).ok_or(Unspecified)? + 7) / 8;
+                let salt_len = em_len
+                    .checked_sub(digest.output_len() + 2)
+                    .ok_or(Unspecified)?;
+                Ok(c_int::try_from(salt_len)?)
+            }
+            RsaPssSaltLength::Auto => Ok(RSA_PSS_SALTLEN_AUTO),
+        }
+    }
 }
 
 /// Parameters for RSA verification.
@@ -83,6 +137,10 @@ impl VerificationAlgorithm for RsaParameters {
             self.bit_size_range(),
         )
     }
+
+    fn as_streaming(&'static self) -> Option<&'static dyn StreamingVerificationAlgorithm> {
+        Some(self)
+    }
 }
 
 impl Sealed for RsaParameters {}
@@ -123,6 +181,133 @@ impl RsaParameters {
     pub fn max_modulus_len(&self) -> u32 {
         *self.2.end()
     }
+
+    /// Creates a `VerificationContext` for verifying a signature over a message that is
+    /// provided incrementally, using the given DER-encoded `RSAPublicKey` (RFC 8017).
+    ///
+    // # FIPS
+    // The following conditions must be met:
+    // * RSA Key Sizes: 1024, 2048, 3072, 4096
+    // * Digest Algorithms: SHA1, SHA256, SHA384, SHA512
+    //
+    /// # Errors
+    /// `error::Unspecified` if the public key cannot be parsed, or its modulus size is not
+    /// allowed by these parameters.
+    pub fn verification_context(
+        &'static self,
+        public_key: &[u8],
+    ) -> Result<VerificationContext, Unspecified> {
+        let evp_pkey = encoding::rfc8017::decode_public_key_der(public_key)?;
+        if !self
+            .bit_size_range()
+            .contains(&evp_pkey.key_size_bits().try_into()?)
+        {
+            return Err(Unspecified);
+        }
+        let padding_fn = pss_salt_len(
+            self.padding(),
+            self.digest_algorithm(),
+            evp_pkey.key_size_bits(),
+        )?
+        .map(|salt_len| move |pctx| configure_rsa_pkcs1_pss_padding(pctx, salt_len));
+        let md_ctx = evp_pkey.digest_verify_init(Some(self.digest_algorithm()), padding_fn)?;
+        Ok(VerificationContext::new(
+            evp_pkey,
+            md_ctx,
+            StreamingSignatureFormat::Encoded,
+        ))
+    }
+
+    /// Verifies a PKCS#1 v1.5 `signature` over `msg` using the given DER-encoded
+    /// `RSAPublicKey` (RFC 8017), accepting a `DigestInfo` whose `AlgorithmIdentifier`
+    /// either carries an explicit `NULL` parameter or omits the parameters entirely.
+    ///
+    /// [RFC 8017 Section 9.2] requires the `NULL` encoding, and `verify_sig` rejects anything
+    /// else. Some older signers omit the parameters; use this only when signatures from such
+    /// signers must be accepted.
+    ///
+    /// [RFC 8017 Section 9.2]: https://tools.ietf.org/html/rfc8017#section-9.2
+    ///
+    /// # Errors
+    /// `error::Unspecified` if these parameters are not for PKCS#1 v1.5 padding, the public key
+    /// cannot be parsed, its modulus size is not allowed by these parameters, or the signature
+    /// is invalid.
+    pub fn verify_lenient_pkcs1(
+        &self,
+        public_key: &[u8],
+        msg: &[u8],
+        signature: &[u8],
+    ) -> Result<(), Unspecified> {
+        if !matches!(self.padding(), RsaPadding::RSA_PKCS1_PADDING) {
+            return Err(Unspecified);
+        }
+        let evp_pkey = encoding::rfc8017::decode_public_key_der(public_key)?;
+        if !self
+            .bit_size_range()
+            .contains(&evp_pkey.key_size_bits().try_into()?)
+        {
+            return Err(Unspecified);
+        }
+        let rsa = evp_pkey.get_rsa()?;
+
+        let mut recovered = vec![0u8; unsafe { RSA_size(*rsa) } as usize];
+        let mut recovered_len = 0;
+        if 1 != unsafe {
+            RSA_verify_raw(
+                *rsa as *mut RSA,
+                &mut recovered_len,
+                recovered.as_mut_ptr(),
+                recovered.len(),
+                signature.as_ptr(),
+                signature.len(),
+                RSA_PKCS1_PADDING,
+            )
+        } {
+            return Err(Unspecified);
+        }
+        let recovered = &recovered[..recovered_len];
+
+        let digest = digest::digest(self.digest_algorithm(), msg);
+        let with_null = encode_digest_info(&digest, true)?;
+        let without_null = encode_digest_info(&digest, false)?;
+        constant_time::verify_slices_are_equal(recovered, &with_null)
+            .or_else(|_| constant_time::verify_slices_are_equal(recovered, &without_null))
+    }
+}
+
+/// Encodes a `DigestInfo` (RFC 8017 Section 9.2) for `digest`, with or without an explicit
+/// `NULL` in the `AlgorithmIdentifier` parameters.
+fn encode_digest_info(digest: &digest::Digest, with_null: bool) -> Result<Vec<u8>, Unspecified> {
+    const NIST_HASH_ARC: [u8; 8] = [0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02];
+    let (arc, last): (&[u8], u8) = match digest.algorithm().id {
+        digest::AlgorithmID::SHA1 => (&[0x2b, 0x0e, 0x03, 0x02], 0x1a),
+        digest::AlgorithmID::SHA224 => (&NIST_HASH_ARC, 0x04),
+        digest::AlgorithmID::SHA256 => (&NIST_HASH_ARC, 0x01),
+        digest::AlgorithmID::SHA384 => (&NIST_HASH_ARC, 0x02),
+        digest::AlgorithmID::SHA512 => (&NIST_HASH_ARC, 0x03),
+        digest::AlgorithmID::SHA512_256 => (&NIST_HASH_ARC, 0x06),
+        digest::AlgorithmID::SHA3_256 => (&NIST_HASH_ARC, 0x08),
+        digest::AlgorithmID::SHA3_384 => (&NIST_HASH_ARC, 0x09),
+        digest::AlgorithmID::SHA3_512 => (&NIST_HASH_ARC, 0x0a),
+    };
+    let hash = digest.as_ref();
+    let oid_len = arc.len() + 1;
+    let alg_id_len = 2 + oid_len + if with_null { 2 } else { 0 };
+    let body_len = 2 + alg_id_len + 2 + hash.len();
+
+    // Every supported digest keeps each length below 128, so short-form DER lengths suffice.
+    let mut out = Vec::with_capacity(2 + body_len);
+    out.extend_from_slice(&[0x30, u8::try_from(body_len)?]);
+    out.extend_from_slice(&[0x30, u8::try_from(alg_id_len)?]);
+    out.extend_from_slice(&[0x06, u8::try_from(oid_len)?]);
+    out.extend_from_slice(arc);
+    out.push(last);
+    if with_null {
+        out.extend_from_slice(&[0x05, 0x00]);
+    }
+    out.extend_from_slice(&[0x04, u8::try_from(hash.len())?]);
+    out.extend_from_slice(hash);
+    Ok(out)
 }
 
 #[derive(Debug)]
@@ -139,6 +324,12 @@ pub(crate) enum RsaVerificationAlgorithmId {
     RSA_PSS_2048_8192_SHA256,
     RSA_PSS_2048_8192_SHA384,
     RSA_PSS_2048_8192_SHA512,
+    RSA_PSS_2048_8192_SHA256_MAX_SALT,
+    RSA_PSS_2048_8192_SHA384_MAX_SALT,
+    RSA_PSS_2048_8192_SHA512_MAX_SALT,
+    RSA_PSS_2048_8192_SHA256_AUTO_SALT,
+    RSA_PSS_2048_8192_SHA384_AUTO_SALT,
+    RSA_PSS_2048_8192_SHA512_AUTO_SALT,
 }
 
 #[derive(Debug)]
@@ -147,6 +338,9 @@ pub(crate) enum RsaSigningAlgorithmId {
     RSA_PSS_SHA256,
     RSA_PSS_SHA384,
     RSA_PSS_SHA512,
+    RSA_PSS_SHA256_MAX_SALT,
+    RSA_PSS_SHA384_MAX_SALT,
+    RSA_PSS_SHA512_MAX_SALT,
     RSA_PKCS1_SHA256,
     RSA_PKCS1_SHA384,
     RSA_PKCS1_SHA512,
@@ -169,12 +363,12 @@ impl RsaSignatureEncoding {
     }
 
     #[inline]
-    pub(super) fn digest_algorithm(&self) -> &'static digest::Algorithm {
+    pub(crate) fn digest_algorithm(&self) -> &'static digest::Algorithm {
         self.0
     }
 
     #[inline]
-    pub(super) fn padding(&self) -> &'static RsaPadding {
+    pub(crate) fn padding(&self) -> &'static RsaPadding {
         self.1
     }
 }
@@ -202,12 +396,30 @@ impl Debug for RsaSignatureEncoding {
     }
 }
 
+/// Returns the PSS salt length to configure for `padding`, or `None` if `padding` is not PSS.
 #[inline]
-pub(crate) fn configure_rsa_pkcs1_pss_padding(pctx: *mut EVP_PKEY_CTX) -> Result<(), ()> {
+pub(crate) fn pss_salt_len(
+    padding: &'static RsaPadding,
+    digest: &'static digest::Algorithm,
+    key_size_bits: usize,
+) -> Result<Option<c_int>, Unspecified> {
+    match padding {
+        RsaPadding::RSA_PKCS1_PADDING => Ok(None),
+        RsaPadding::RSA_PKCS1_PSS_PADDING(salt_length) => {
+            Ok(Some(salt_length.salt_len(digest, key_size_bits)?))
+        }
+    }
+}
+
+#[inline]
+pub(crate) fn configure_rsa_pkcs1_pss_padding(
+    pctx: *mut EVP_PKEY_CTX,
+    salt_len: c_int,
+) -> Result<(), ()> {
     if 1 != unsafe { EVP_PKEY_CTX_set_rsa_padding(pctx, RSA_PKCS1_PSS_PADDING) } {
         return Err(());
     }
-    if 1 != unsafe { EVP_PKEY_CTX_set_rsa_pss_saltlen(pctx, RSA_PSS_SALTLEN_DIGEST) } {
+    if 1 != unsafe { EVP_PKEY_CTX_set_rsa_pss_saltlen(pctx, salt_len) } {
         return Err(());
     }
     Ok(())
@@ -226,11 +438,8 @@ pub(crate) fn verify_rsa_signature(
         return Err(Unspecified);
     }
 
-    let padding_fn = if let RsaPadding::RSA_PKCS1_PSS_PADDING = padding {
-        Some(configure_rsa_pkcs1_pss_padding)
-    } else {
-        None
-    };
+    let padding_fn = pss_salt_len(padding, algorithm, public_key.key_size_bits())?
+        .map(|salt_len| move |pctx| configure_rsa_pkcs1_pss_padding(pctx, salt_len));
 
     public_key.verify(msg, Some(algorithm), padding_fn, signature)
 }
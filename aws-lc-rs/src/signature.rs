@@ -96,6 +96,28 @@
 //! Additionally, the entire salt is randomly generated separately for each
 //! signature using the secure random number generator passed to `sign()`.
 //!
+//! A salt length equal to the digest length is also what Java produces for
+//! `RSASSA-PSS` when configured with a `PSSParameterSpec` whose salt length
+//! matches its digest, so signatures can be exchanged with Java without any
+//! additional configuration.
+//!
+//! ## `RSA_PSS_*_MAX_SALT` Details: RSA PSS Signatures with a Maximum Salt Length
+//!
+//! These are the same as the `RSA_PSS_*` algorithms, except that the salt is
+//! the maximum length permitted by the size of the public modulus
+//! (`emLen - hLen - 2` bytes). Some implementations, e.g. .NET and OpenSSL's
+//! `rsa_pss_saltlen:max`, produce signatures with this salt length. During
+//! verification, signatures will only be accepted if the salt has exactly
+//! this length.
+//!
+//! ## `RSA_PSS_*_AUTO_SALT` Details: RSA PSS Verification with Any Salt Length
+//!
+//! These verification algorithms accept RSA PSS signatures with any salt
+//! length, which is recovered from the signature as described in
+//! [RFC 4055 Section 3.1]. Use them when the salt length chosen by the signer
+//! is not known. The `RSA_PSS_*` and `RSA_PSS_*_MAX_SALT` algorithms remain
+//! strict about the salt length.
+//!
 //!
 //! [SEC 1: Elliptic Curve Cryptography, Version 2.0]:
 //!     http://www.secg.org/sec1-v2.pdf
@@ -109,6 +131,8 @@
 //!     https://tools.ietf.org/html/rfc3447#section-7.2
 //! [RFC 3447 Section 8.1]:
 //!     https://tools.ietf.org/html/rfc3447#section-8.1
+//! [RFC 4055 Section 3.1]:
+//!     https://tools.ietf.org/html/rfc4055#section-3.1
 //! [RFC 3447 Appendix-A.1.1]:
 //!     https://tools.ietf.org/html/rfc3447#appendix-A.1.1
 //!
@@ -235,6 +259,8 @@
 //! ```
 use core::fmt::{Debug, Formatter};
 
+use crate::aws_lc::ECDSA_SIG_max_len;
+
 #[cfg(feature = "ring-sig-verify")]
 use untrusted::Input;
 
@@ -244,20 +270,34 @@ pub use crate::rsa::{
     PublicKeyComponents as RsaPublicKeyComponents, RsaParameters,
 };
 
-use crate::rsa::signature::{RsaSignatureEncoding, RsaSigningAlgorithmId};
+use crate::rsa::signature::{RsaPssSaltLength, RsaSignatureEncoding, RsaSigningAlgorithmId};
 use crate::rsa::RsaVerificationAlgorithmId;
 
-pub use crate::ec::key_pair::{EcdsaKeyPair, PrivateKey as EcdsaPrivateKey};
+pub use crate::ec::key_pair::{EcdsaKeyPair, EcdsaSigningContext, PrivateKey as EcdsaPrivateKey};
 use crate::ec::signature::EcdsaSignatureFormat;
 pub use crate::ec::signature::{
-    EcdsaSigningAlgorithm, EcdsaVerificationAlgorithm, PublicKey as EcdsaPublicKey,
+    curve_from_name as ecdsa_curve_from_name, recover_public_key as recover_ecdsa_public_key,
+    EcdsaCurve, EcdsaSigningAlgorithm, EcdsaVerificationAlgorithm, PublicKey as EcdsaPublicKey,
 };
+#[cfg(feature = "mmap")]
+pub use crate::ed25519::verify_file_ed25519;
+#[cfg(not(feature = "fips"))]
+pub use crate::ed25519::{ed25519_sign_sigstore, ed25519_verify_sigstore};
 pub use crate::ed25519::{
     Ed25519KeyPair, EdDSAParameters, PublicKey as Ed25519PublicKey, Seed as Ed25519Seed,
     ED25519_PUBLIC_KEY_LEN,
 };
 
-use crate::{digest, ec, error, hex, rsa, sealed};
+use crate::{derive_hex_via_as_ref, digest, ec, error, hex, rsa, sealed};
+
+mod bundle;
+mod counted;
+pub(crate) mod streaming;
+mod tbs;
+pub use bundle::SignatureBundle;
+pub use counted::CountedSigner;
+pub use streaming::{verify_reader, StreamingVerificationAlgorithm, VerificationContext};
+pub use tbs::{sign_tbs, verify_tbs, TbsSigningKey};
 
 /// The longest signature is for ML-DSA-87
 pub(crate) const MAX_LEN: usize = 4627;
@@ -282,6 +322,27 @@ impl Signature {
         r.len = fill(&mut r.value);
         r
     }
+
+    /// Normalizes an ECDSA signature to its "low-S" form.
+    ///
+    /// ECDSA signatures are malleable: if `(r, s)` is a valid signature then so is
+    /// `(r, n - s)`, where `n` is the order of the curve. Some verifiers, e.g. Bitcoin's,
+    /// accept only the form with `s <= n / 2` to prevent this. This returns the signature with
+    /// `s` replaced by `n - s` if `s` is greater than `n / 2`, or an unchanged copy otherwise.
+    ///
+    /// `alg` determines the curve and whether the signature is ASN.1 DER encoded or fixed
+    /// length; the normalized signature has the same format. `alg` can be the
+    /// `EcdsaSigningAlgorithm` that produced the signature.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the signature is not an ECDSA signature in `alg`'s format, or
+    /// if `s` is not in the range `[1, n - 1]`.
+    pub fn normalize_low_s(
+        &self,
+        alg: &'static EcdsaVerificationAlgorithm,
+    ) -> Result<Self, error::Unspecified> {
+        ec::signature::normalize_low_s(alg, self.as_ref())
+    }
 }
 
 impl AsRef<[u8]> for Signature {
@@ -291,6 +352,8 @@ impl AsRef<[u8]> for Signature {
     }
 }
 
+derive_hex_via_as_ref!(Signature);
+
 /// Key pairs for signing messages (private key and public key).
 pub trait KeyPair: Debug + Send + Sized + Sync {
     /// The type of the public key.
@@ -298,6 +361,15 @@ pub trait KeyPair: Debug + Send + Sized + Sync {
 
     /// The public key for the key pair.
     fn public_key(&self) -> &Self::PublicKey;
+
+    /// The algorithm that verifies signatures produced by the key pair, e.g. for logging which
+    /// algorithm a key uses.
+    ///
+    /// Returns `None` if the key pair is not bound to a single algorithm. This is the case for
+    /// `RsaKeyPair`, whose padding and digest are chosen for each signature.
+    fn verification_algorithm(&self) -> Option<&'static dyn VerificationAlgorithm> {
+        None
+    }
 }
 
 /// A signature verification algorithm.
@@ -339,6 +411,14 @@ pub trait VerificationAlgorithm: Debug + Sync + sealed::Sealed {
         msg: &[u8],
         signature: &[u8],
     ) -> Result<(), error::Unspecified>;
+
+    /// Returns this algorithm as a `StreamingVerificationAlgorithm` if it can verify a
+    /// signature over a message that is provided incrementally, or `None` otherwise.
+    ///
+    /// The RSA and ECDSA verification algorithms support incremental verification.
+    fn as_streaming(&'static self) -> Option<&'static dyn StreamingVerificationAlgorithm> {
+        None
+    }
 }
 
 /// An unparsed, possibly malformed, public key for signature verification.
@@ -387,6 +467,56 @@ impl<B: AsRef<[u8]>> UnparsedPublicKey<B> {
         self.algorithm
             .verify_sig(self.bytes.as_ref(), message, signature)
     }
+
+    /// Parses the public key and creates a `VerificationContext` for verifying a signature
+    /// over a message that is provided incrementally.
+    ///
+    /// Verifying with a context to which no data was provided is the same as verifying the
+    /// empty message.
+    ///
+    // # FIPS
+    // The conditions of `UnparsedPublicKey::verify` must be met.
+    //
+    /// # Errors
+    /// `error::Unspecified` if the algorithm does not support incremental verification, e.g.
+    /// Ed25519, or the public key cannot be parsed for the algorithm.
+    pub fn verify_context(&self) -> Result<VerificationContext, error::Unspecified> {
+        self.algorithm
+            .as_streaming()
+            .ok_or(error::Unspecified)?
+            .verification_context(self.bytes.as_ref())
+    }
+}
+
+/// A signing algorithm whose PKCS#8 private keys can be checked with [`validate_pkcs8`].
+pub trait Pkcs8SigningAlgorithm: pkcs8_sealed::Pkcs8SigningAlgorithm {}
+
+impl<T> Pkcs8SigningAlgorithm for T where T: pkcs8_sealed::Pkcs8SigningAlgorithm {}
+
+pub(crate) mod pkcs8_sealed {
+    use crate::error::KeyRejected;
+
+    pub trait Pkcs8SigningAlgorithm: core::fmt::Debug + Sync {
+        /// Parses and validates `pkcs8` as a private key for this algorithm, discarding it.
+        fn validate_pkcs8_impl(&self, pkcs8: &[u8]) -> Result<(), KeyRejected>;
+    }
+}
+
+/// Checks that `pkcs8` is a well-formed, valid unencrypted PKCS#8 private key for `alg`,
+/// without constructing a key pair.
+///
+/// `alg` is an ECDSA signing algorithm, e.g. `ECDSA_P256_SHA256_ASN1_SIGNING`, or `ED25519`.
+/// The key is validated as it would be by `EcdsaKeyPair::from_pkcs8` or
+/// `Ed25519KeyPair::from_pkcs8`, respectively, and then discarded.
+///
+/// # Errors
+/// `error::KeyRejected` describing why `pkcs8` is not acceptable, e.g. because it is not a
+/// valid PKCS#8 document, is a key for a different algorithm or curve, or is not a valid key.
+pub fn validate_pkcs8<A: Pkcs8SigningAlgorithm + ?Sized>(
+    alg: &A,
+    pkcs8: &[u8],
+) -> Result<(), error::KeyRejected> {
+    alg.validate_pkcs8_impl(pkcs8)
 }
 
 /// Verification of signatures using RSA keys of 1024-8192 bits, PKCS#1.5 padding, and SHA-1.
@@ -456,7 +586,7 @@ pub static RSA_PKCS1_3072_8192_SHA384: RsaParameters = RsaParameters::new(
 /// Verification of signatures using RSA keys of 2048-8192 bits, PSS padding, and SHA-256.
 pub static RSA_PSS_2048_8192_SHA256: RsaParameters = RsaParameters::new(
     &digest::SHA256,
-    &rsa::signature::RsaPadding::RSA_PKCS1_PSS_PADDING,
+    &rsa::signature::RsaPadding::RSA_PKCS1_PSS_PADDING(RsaPssSaltLength::Digest),
     2048..=8192,
     &RsaVerificationAlgorithmId::RSA_PSS_2048_8192_SHA256,
 );
@@ -464,7 +594,7 @@ pub static RSA_PSS_2048_8192_SHA256: RsaParameters = RsaParameters::new(
 /// Verification of signatures using RSA keys of 2048-8192 bits, PSS padding, and SHA-384.
 pub static RSA_PSS_2048_8192_SHA384: RsaParameters = RsaParameters::new(
     &digest::SHA384,
-    &rsa::signature::RsaPadding::RSA_PKCS1_PSS_PADDING,
+    &rsa::signature::RsaPadding::RSA_PKCS1_PSS_PADDING(RsaPssSaltLength::Digest),
     2048..=8192,
     &RsaVerificationAlgorithmId::RSA_PSS_2048_8192_SHA384,
 );
@@ -472,32 +602,110 @@ pub static RSA_PSS_2048_8192_SHA384: RsaParameters = RsaParameters::new(
 /// Verification of signatures using RSA keys of 2048-8192 bits, PSS padding, and SHA-512.
 pub static RSA_PSS_2048_8192_SHA512: RsaParameters = RsaParameters::new(
     &digest::SHA512,
-    &rsa::signature::RsaPadding::RSA_PKCS1_PSS_PADDING,
+    &rsa::signature::RsaPadding::RSA_PKCS1_PSS_PADDING(RsaPssSaltLength::Digest),
     2048..=8192,
     &RsaVerificationAlgorithmId::RSA_PSS_2048_8192_SHA512,
 );
 
+/// Verification of signatures using RSA keys of 2048-8192 bits, PSS padding with the maximum
+/// salt length permitted by the modulus, and SHA-256.
+pub static RSA_PSS_2048_8192_SHA256_MAX_SALT: RsaParameters = RsaParameters::new(
+    &digest::SHA256,
+    &rsa::signature::RsaPadding::RSA_PKCS1_PSS_PADDING(RsaPssSaltLength::Max),
+    2048..=8192,
+    &RsaVerificationAlgorithmId::RSA_PSS_2048_8192_SHA256_MAX_SALT,
+);
+
+/// Verification of signatures using RSA keys of 2048-8192 bits, PSS padding with the maximum
+/// salt length permitted by the modulus, and SHA-384.
+pub static RSA_PSS_2048_8192_SHA384_MAX_SALT: RsaParameters = RsaParameters::new(
+    &digest::SHA384,
+    &rsa::signature::RsaPadding::RSA_PKCS1_PSS_PADDING(RsaPssSaltLength::Max),
+    2048..=8192,
+    &RsaVerificationAlgorithmId::RSA_PSS_2048_8192_SHA384_MAX_SALT,
+);
+
+/// Verification of signatures using RSA keys of 2048-8192 bits, PSS padding with the maximum
+/// salt length permitted by the modulus, and SHA-512.
+pub static RSA_PSS_2048_8192_SHA512_MAX_SALT: RsaParameters = RsaParameters::new(
+    &digest::SHA512,
+    &rsa::signature::RsaPadding::RSA_PKCS1_PSS_PADDING(RsaPssSaltLength::Max),
+    2048..=8192,
+    &RsaVerificationAlgorithmId::RSA_PSS_2048_8192_SHA512_MAX_SALT,
+);
+
+/// Verification of signatures using RSA keys of 2048-8192 bits, PSS padding with any salt
+/// length, and SHA-256.
+pub static RSA_PSS_2048_8192_SHA256_AUTO_SALT: RsaParameters = RsaParameters::new(
+    &digest::SHA256,
+    &rsa::signature::RsaPadding::RSA_PKCS1_PSS_PADDING(RsaPssSaltLength::Auto),
+    2048..=8192,
+    &RsaVerificationAlgorithmId::RSA_PSS_2048_8192_SHA256_AUTO_SALT,
+);
+
+/// Verification of signatures using RSA keys of 2048-8192 bits, PSS padding with any salt
+/// length, and SHA-384.
+pub static RSA_PSS_2048_8192_SHA384_AUTO_SALT: RsaParameters = RsaParameters::new(
+    &digest::SHA384,
+    &rsa::signature::RsaPadding::RSA_PKCS1_PSS_PADDING(RsaPssSaltLength::Auto),
+    2048..=8192,
+    &RsaVerificationAlgorithmId::RSA_PSS_2048_8192_SHA384_AUTO_SALT,
+);
+
+/// Verification of signatures using RSA keys of 2048-8192 bits, PSS padding with any salt
+/// length, and SHA-512.
+pub static RSA_PSS_2048_8192_SHA512_AUTO_SALT: RsaParameters = RsaParameters::new(
+    &digest::SHA512,
+    &rsa::signature::RsaPadding::RSA_PKCS1_PSS_PADDING(RsaPssSaltLength::Auto),
+    2048..=8192,
+    &RsaVerificationAlgorithmId::RSA_PSS_2048_8192_SHA512_AUTO_SALT,
+);
+
 /// RSA PSS padding using SHA-256 for RSA signatures.
 pub static RSA_PSS_SHA256: RsaSignatureEncoding = RsaSignatureEncoding::new(
     &digest::SHA256,
-    &rsa::signature::RsaPadding::RSA_PKCS1_PSS_PADDING,
+    &rsa::signature::RsaPadding::RSA_PKCS1_PSS_PADDING(RsaPssSaltLength::Digest),
     &RsaSigningAlgorithmId::RSA_PSS_SHA256,
 );
 
 /// RSA PSS padding using SHA-384 for RSA signatures.
 pub static RSA_PSS_SHA384: RsaSignatureEncoding = RsaSignatureEncoding::new(
     &digest::SHA384,
-    &rsa::signature::RsaPadding::RSA_PKCS1_PSS_PADDING,
+    &rsa::signature::RsaPadding::RSA_PKCS1_PSS_PADDING(RsaPssSaltLength::Digest),
     &RsaSigningAlgorithmId::RSA_PSS_SHA384,
 );
 
 /// RSA PSS padding using SHA-512 for RSA signatures.
 pub static RSA_PSS_SHA512: RsaSignatureEncoding = RsaSignatureEncoding::new(
     &digest::SHA512,
-    &rsa::signature::RsaPadding::RSA_PKCS1_PSS_PADDING,
+    &rsa::signature::RsaPadding::RSA_PKCS1_PSS_PADDING(RsaPssSaltLength::Digest),
     &RsaSigningAlgorithmId::RSA_PSS_SHA512,
 );
 
+/// RSA PSS padding using SHA-256 and the maximum salt length permitted by the modulus for RSA
+/// signatures.
+pub static RSA_PSS_SHA256_MAX_SALT: RsaSignatureEncoding = RsaSignatureEncoding::new(
+    &digest::SHA256,
+    &rsa::signature::RsaPadding::RSA_PKCS1_PSS_PADDING(RsaPssSaltLength::Max),
+    &RsaSigningAlgorithmId::RSA_PSS_SHA256_MAX_SALT,
+);
+
+/// RSA PSS padding using SHA-384 and the maximum salt length permitted by the modulus for RSA
+/// signatures.
+pub static RSA_PSS_SHA384_MAX_SALT: RsaSignatureEncoding = RsaSignatureEncoding::new(
+    &digest::SHA384,
+    &rsa::signature::RsaPadding::RSA_PKCS1_PSS_PADDING(RsaPssSaltLength::Max),
+    &RsaSigningAlgorithmId::RSA_PSS_SHA384_MAX_SALT,
+);
+
+/// RSA PSS padding using SHA-512 and the maximum salt length permitted by the modulus for RSA
+/// signatures.
+pub static RSA_PSS_SHA512_MAX_SALT: RsaSignatureEncoding = RsaSignatureEncoding::new(
+    &digest::SHA512,
+    &rsa::signature::RsaPadding::RSA_PKCS1_PSS_PADDING(RsaPssSaltLength::Max),
+    &RsaSigningAlgorithmId::RSA_PSS_SHA512_MAX_SALT,
+);
+
 /// PKCS#1 1.5 padding using SHA-256 for RSA signatures.
 pub static RSA_PKCS1_SHA256: RsaSignatureEncoding = RsaSignatureEncoding::new(
     &digest::SHA256,
@@ -788,6 +996,263 @@ pub static ECDSA_P256K1_SHA3_256_ASN1_SIGNING: EcdsaSigningAlgorithm =
 /// Verification of Ed25519 signatures.
 pub static ED25519: EdDSAParameters = EdDSAParameters {};
 
+static RSA_VERIFICATION_ALGORITHMS: [&RsaParameters; 17] = [
+    &RSA_PKCS1_1024_8192_SHA1_FOR_LEGACY_USE_ONLY,
+    &RSA_PKCS1_1024_8192_SHA256_FOR_LEGACY_USE_ONLY,
+    &RSA_PKCS1_1024_8192_SHA512_FOR_LEGACY_USE_ONLY,
+    &RSA_PKCS1_2048_8192_SHA1_FOR_LEGACY_USE_ONLY,
+    &RSA_PKCS1_2048_8192_SHA256,
+    &RSA_PKCS1_2048_8192_SHA384,
+    &RSA_PKCS1_2048_8192_SHA512,
+    &RSA_PKCS1_3072_8192_SHA384,
+    &RSA_PSS_2048_8192_SHA256,
+    &RSA_PSS_2048_8192_SHA384,
+    &RSA_PSS_2048_8192_SHA512,
+    &RSA_PSS_2048_8192_SHA256_MAX_SALT,
+    &RSA_PSS_2048_8192_SHA384_MAX_SALT,
+    &RSA_PSS_2048_8192_SHA512_MAX_SALT,
+    &RSA_PSS_2048_8192_SHA256_AUTO_SALT,
+    &RSA_PSS_2048_8192_SHA384_AUTO_SALT,
+    &RSA_PSS_2048_8192_SHA512_AUTO_SALT,
+];
+
+static ECDSA_VERIFICATION_ALGORITHMS: [&EcdsaVerificationAlgorithm; 24] = [
+    &ECDSA_P256_SHA256_FIXED,
+    &ECDSA_P384_SHA384_FIXED,
+    &ECDSA_P384_SHA3_384_FIXED,
+    &ECDSA_P521_SHA1_FIXED,
+    &ECDSA_P521_SHA224_FIXED,
+    &ECDSA_P521_SHA256_FIXED,
+    &ECDSA_P521_SHA384_FIXED,
+    &ECDSA_P521_SHA512_FIXED,
+    &ECDSA_P521_SHA3_512_FIXED,
+    &ECDSA_P256K1_SHA256_FIXED,
+    &ECDSA_P256K1_SHA3_256_FIXED,
+    &ECDSA_P256_SHA256_ASN1,
+    &ECDSA_P256_SHA384_ASN1,
+    &ECDSA_P384_SHA256_ASN1,
+    &ECDSA_P384_SHA384_ASN1,
+    &ECDSA_P384_SHA3_384_ASN1,
+    &ECDSA_P521_SHA1_ASN1,
+    &ECDSA_P521_SHA224_ASN1,
+    &ECDSA_P521_SHA256_ASN1,
+    &ECDSA_P521_SHA384_ASN1,
+    &ECDSA_P521_SHA512_ASN1,
+    &ECDSA_P521_SHA3_512_ASN1,
+    &ECDSA_P256K1_SHA256_ASN1,
+    &ECDSA_P256K1_SHA3_256_ASN1,
+];
+
+/// Returns the verification algorithms that can be used with the given public key.
+///
+/// The key type is detected from `public_key`, which is accepted in the encodings supported by
+/// the corresponding algorithms:
+/// * RSA: a DER-encoded `RSAPublicKey` (RFC 8017). Only algorithms whose allowed modulus sizes
+///   include the key's size are returned.
+/// * ECDSA: an uncompressed or compressed elliptic curve point, or a DER-encoded X.509
+///   `SubjectPublicKeyInfo`. Algorithms for the key's curve are returned.
+/// * Ed25519: a 32-byte public key.
+///
+/// An empty `Vec` is returned if the key type is not recognized.
+#[must_use]
+pub fn compatible_algorithms(public_key: &[u8]) -> Vec<&'static dyn VerificationAlgorithm> {
+    let mut algorithms = Vec::<&'static dyn VerificationAlgorithm>::new();
+
+    if let Ok(bits) = RsaParameters::public_modulus_len(public_key) {
+        for alg in RSA_VERIFICATION_ALGORITHMS {
+            if alg.bit_size_range().contains(&bits) {
+                algorithms.push(alg);
+            }
+        }
+        return algorithms;
+    }
+
+    let curve_id = [
+        &ec::signature::AlgorithmID::ECDSA_P256,
+        &ec::signature::AlgorithmID::ECDSA_P384,
+        &ec::signature::AlgorithmID::ECDSA_P521,
+        &ec::signature::AlgorithmID::ECDSA_P256K1,
+    ]
+    .into_iter()
+    .find(|id| ec::encoding::parse_ec_public_key(public_key, id.nid()).is_ok());
+    if let Some(curve_id) = curve_id {
+        for alg in ECDSA_VERIFICATION_ALGORITHMS {
+            if alg.id == curve_id {
+                algorithms.push(alg);
+            }
+        }
+        return algorithms;
+    }
+
+    if public_key.len() == ED25519_PUBLIC_KEY_LEN {
+        algorithms.push(&ED25519);
+    }
+    algorithms
+}
+
+/// Returns the maximum length, in bytes, of an ASN.1 DER encoded `ECDSA-Sig-Value` (RFC 3279)
+/// for the curve of `alg`, e.g. for sizing a buffer for an `ECDSA_*_ASN1` signature.
+///
+/// The bound is reached when both `r` and `s` are as long as the curve's group order and
+/// require a leading zero byte: 72 bytes for P-256 and secp256k1, 104 bytes for P-384 and
+/// 141 bytes for P-521. Signing algorithms dereference to their verification algorithm, so
+/// `&ECDSA_P256_SHA256_ASN1_SIGNING` may be passed as well.
+#[must_use]
+pub fn max_asn1_signature_len(alg: &EcdsaVerificationAlgorithm) -> usize {
+    unsafe { ECDSA_SIG_max_len(alg.id.private_key_size()) }
+}
+
+/// Converts a WebCrypto ECDSA signature into an ASN.1 DER encoded `ECDSA-Sig-Value`
+/// (RFC 3279), as produced by the `ECDSA_*_ASN1` algorithms.
+///
+/// WebCrypto signatures are `r || s`, with each integer zero-padded to the byte length of the
+/// curve's group order: 64 bytes in total for P-256, 96 for P-384 and 132 for P-521. This is
+/// the same format as the `ECDSA_*_FIXED` algorithms. Only the curve of `alg` is used, so
+/// either the `_ASN1` or `_FIXED` algorithm for the curve may be passed.
+///
+/// # Errors
+/// `error::Unspecified` if `signature` does not have the expected length for the curve or
+/// `r` or `s` is zero.
+pub fn webcrypto_to_asn1(
+    alg: &'static EcdsaVerificationAlgorithm,
+    signature: &[u8],
+) -> Result<Signature, error::Unspecified> {
+    ec::signature::fixed_to_asn1(alg.id, signature)
+}
+
+/// Converts an ASN.1 DER encoded `ECDSA-Sig-Value` (RFC 3279) into a WebCrypto ECDSA
+/// signature, `r || s` with each integer zero-padded to the byte length of the group order of
+/// `alg`'s curve. See [`webcrypto_to_asn1`].
+///
+/// # Errors
+/// `error::Unspecified` if `signature` is not a valid DER encoding, `r` or `s` is zero, or
+/// either integer is too long for the curve.
+pub fn asn1_to_webcrypto(
+    alg: &'static EcdsaVerificationAlgorithm,
+    signature: &[u8],
+) -> Result<Signature, error::Unspecified> {
+    ec::signature::asn1_to_fixed(alg.id, signature)
+}
+
+// DER encodings of X.509 `AlgorithmIdentifier`s and the verification algorithm each one maps
+// to. As is common practice for certificate path validation, identifiers are matched exactly
+// rather than parsed, so that only the canonical encoding of the RSASSA-PSS parameters is
+// accepted.
+static ALGORITHM_IDENTIFIERS: [(&[u8], &'static dyn VerificationAlgorithm); 11] = [
+    // ecdsa-with-SHA256
+    (
+        &[
+            0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02,
+        ],
+        &ECDSA_P256_SHA256_ASN1,
+    ),
+    // ecdsa-with-SHA384
+    (
+        &[
+            0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x03,
+        ],
+        &ECDSA_P384_SHA384_ASN1,
+    ),
+    // ecdsa-with-SHA512
+    (
+        &[
+            0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x04,
+        ],
+        &ECDSA_P521_SHA512_ASN1,
+    ),
+    // id-Ed25519
+    (&[0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70], &ED25519),
+    // sha1WithRSAEncryption
+    (
+        &[
+            0x30, 0x0d, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x05, 0x05,
+            0x00,
+        ],
+        &RSA_PKCS1_2048_8192_SHA1_FOR_LEGACY_USE_ONLY,
+    ),
+    // sha256WithRSAEncryption
+    (
+        &[
+            0x30, 0x0d, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0b, 0x05,
+            0x00,
+        ],
+        &RSA_PKCS1_2048_8192_SHA256,
+    ),
+    // sha384WithRSAEncryption
+    (
+        &[
+            0x30, 0x0d, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0c, 0x05,
+            0x00,
+        ],
+        &RSA_PKCS1_2048_8192_SHA384,
+    ),
+    // sha512WithRSAEncryption
+    (
+        &[
+            0x30, 0x0d, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0d, 0x05,
+            0x00,
+        ],
+        &RSA_PKCS1_2048_8192_SHA512,
+    ),
+    // id-RSASSA-PSS with SHA-256, MGF1(SHA-256) and a 32-byte salt
+    (
+        &[
+            0x30, 0x41, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0a, 0x30,
+            0x34, 0xa0, 0x0f, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04,
+            0x02, 0x01, 0x05, 0x00, 0xa1, 0x1c, 0x30, 0x1a, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86,
+            0xf7, 0x0d, 0x01, 0x01, 0x08, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65,
+            0x03, 0x04, 0x02, 0x01, 0x05, 0x00, 0xa2, 0x03, 0x02, 0x01, 0x20,
+        ],
+        &RSA_PSS_2048_8192_SHA256,
+    ),
+    // id-RSASSA-PSS with SHA-384, MGF1(SHA-384) and a 48-byte salt
+    (
+        &[
+            0x30, 0x41, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0a, 0x30,
+            0x34, 0xa0, 0x0f, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04,
+            0x02, 0x02, 0x05, 0x00, 0xa1, 0x1c, 0x30, 0x1a, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86,
+            0xf7, 0x0d, 0x01, 0x01, 0x08, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65,
+            0x03, 0x04, 0x02, 0x02, 0x05, 0x00, 0xa2, 0x03, 0x02, 0x01, 0x30,
+        ],
+        &RSA_PSS_2048_8192_SHA384,
+    ),
+    // id-RSASSA-PSS with SHA-512, MGF1(SHA-512) and a 64-byte salt
+    (
+        &[
+            0x30, 0x41, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0a, 0x30,
+            0x34, 0xa0, 0x0f, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04,
+            0x02, 0x03, 0x05, 0x00, 0xa1, 0x1c, 0x30, 0x1a, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86,
+            0xf7, 0x0d, 0x01, 0x01, 0x08, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65,
+            0x03, 0x04, 0x02, 0x03, 0x05, 0x00, 0xa2, 0x03, 0x02, 0x01, 0x40,
+        ],
+        &RSA_PSS_2048_8192_SHA512,
+    ),
+];
+
+/// Returns the verification algorithm identified by the DER-encoded X.509
+/// `AlgorithmIdentifier` in `der`, e.g. the `signatureAlgorithm` field of a certificate.
+///
+/// The following identifiers are recognized:
+/// * `ecdsa-with-SHA256`, `ecdsa-with-SHA384` and `ecdsa-with-SHA512` (RFC 5758). These
+///   identifiers do not name a curve, so the algorithm for the curve conventionally paired
+///   with the digest is returned: P-256, P-384 and P-521 respectively. Use
+///   [`compatible_algorithms`] to find the algorithms usable with a particular key.
+/// * `id-Ed25519` (RFC 8410).
+/// * `sha1WithRSAEncryption`, `sha256WithRSAEncryption`, `sha384WithRSAEncryption` and
+///   `sha512WithRSAEncryption` (RFC 8017), with the required NULL parameters.
+/// * `id-RSASSA-PSS` (RFC 4055) with SHA-256, SHA-384 or SHA-512, where the MGF1 digest
+///   matches the message digest, the salt length equals the digest length and the trailer
+///   field is omitted.
+///
+/// `None` is returned for any other identifier or encoding.
+#[must_use]
+pub fn algorithm_from_der_oid(der: &[u8]) -> Option<&'static dyn VerificationAlgorithm> {
+    ALGORITHM_IDENTIFIERS
+        .iter()
+        .find(|(encoding, _)| *encoding == der)
+        .map(|&(_, alg)| alg)
+}
+
 #[cfg(test)]
 mod tests {
     use regex::Regex;
@@ -814,4 +1279,261 @@ mod tests {
 
         assert!(pubkey_re.is_match(&unparsed_pubkey_debug));
     }
+
+    #[test]
+    fn test_unparsed_public_key_verify_context() {
+        use crate::signature::{
+            EcdsaKeyPair, Ed25519KeyPair, KeyPair, RsaKeyPair, ECDSA_P384_SHA384_FIXED,
+            ECDSA_P384_SHA384_FIXED_SIGNING, RSA_PSS_2048_8192_SHA256, RSA_PSS_SHA256,
+        };
+
+        let rng = SystemRandom::new();
+        let message = b"a message that is verified in chunks";
+
+        let ecdsa = EcdsaKeyPair::generate(&ECDSA_P384_SHA384_FIXED_SIGNING).unwrap();
+        let ecdsa_sig = ecdsa.sign(&rng, message).unwrap();
+        let ecdsa_empty_sig = ecdsa.sign(&rng, b"").unwrap();
+        let ecdsa_public_key =
+            UnparsedPublicKey::new(&ECDSA_P384_SHA384_FIXED, ecdsa.public_key().as_ref());
+
+        let rsa =
+            RsaKeyPair::from_pkcs8(include_bytes!("../tests/data/rsa_test_private_key_2048.p8"))
+                .unwrap();
+        let mut rsa_sig = vec![0u8; rsa.public_modulus_len()];
+        rsa.sign(&RSA_PSS_SHA256, &rng, message, &mut rsa_sig)
+            .unwrap();
+        let mut rsa_empty_sig = vec![0u8; rsa.public_modulus_len()];
+        rsa.sign(&RSA_PSS_SHA256, &rng, b"", &mut rsa_empty_sig)
+            .unwrap();
+        let rsa_public_key =
+            UnparsedPublicKey::new(&RSA_PSS_2048_8192_SHA256, rsa.public_key().as_ref());
+
+        for (public_key, sig, empty_sig) in [
+            (
+                ecdsa_public_key,
+                ecdsa_sig.as_ref(),
+                ecdsa_empty_sig.as_ref(),
+            ),
+            (rsa_public_key, rsa_sig.as_slice(), rsa_empty_sig.as_slice()),
+        ] {
+            public_key.verify(message, sig).unwrap();
+
+            let mut context = public_key.verify_context().unwrap();
+            for chunk in message.chunks(7) {
+                context.update(chunk).unwrap();
+            }
+            context.verify(sig).unwrap();
+
+            let mut context = public_key.verify_context().unwrap();
+            context.update(&message[1..]).unwrap();
+            assert!(context.verify(sig).is_err());
+
+            // Without any updates, the context verifies the empty message.
+            public_key.verify(b"", empty_sig).unwrap();
+            public_key
+                .verify_context()
+                .unwrap()
+                .verify(empty_sig)
+                .unwrap();
+            assert!(public_key.verify_context().unwrap().verify(sig).is_err());
+        }
+
+        let ed25519 = Ed25519KeyPair::generate().unwrap();
+        assert!(
+            UnparsedPublicKey::new(&ED25519, ed25519.public_key().as_ref())
+                .verify_context()
+                .is_err()
+        );
+        assert!(
+            UnparsedPublicKey::new(&ECDSA_P384_SHA384_FIXED, rsa.public_key().as_ref())
+                .verify_context()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_max_asn1_signature_len() {
+        use crate::signature::{
+            max_asn1_signature_len, EcdsaKeyPair, ECDSA_P256K1_SHA256_ASN1_SIGNING,
+            ECDSA_P256_SHA256_ASN1, ECDSA_P256_SHA256_ASN1_SIGNING, ECDSA_P384_SHA384_ASN1_SIGNING,
+            ECDSA_P521_SHA512_ASN1_SIGNING,
+        };
+
+        assert_eq!(72, max_asn1_signature_len(&ECDSA_P256_SHA256_ASN1));
+        for (alg, expected) in [
+            (&ECDSA_P256_SHA256_ASN1_SIGNING, 72),
+            (&ECDSA_P384_SHA384_ASN1_SIGNING, 104),
+            (&ECDSA_P521_SHA512_ASN1_SIGNING, 141),
+            (&ECDSA_P256K1_SHA256_ASN1_SIGNING, 72),
+        ] {
+            let max_len = max_asn1_signature_len(alg);
+            assert_eq!(expected, max_len);
+
+            let key_pair = EcdsaKeyPair::generate(alg).unwrap();
+            let rng = SystemRandom::new();
+            for i in 0u8..=255 {
+                let sig = key_pair.sign(&rng, &[i]).unwrap();
+                assert!(sig.as_ref().len() <= max_len);
+            }
+        }
+    }
+
+    #[test]
+    fn test_algorithm_from_der_oid() {
+        use crate::signature::{
+            algorithm_from_der_oid, ECDSA_P256_SHA256_ASN1, RSA_PKCS1_2048_8192_SHA256,
+            RSA_PSS_2048_8192_SHA384,
+        };
+        use crate::test::from_hex;
+
+        fn debug_string(der_hex: &str) -> Option<String> {
+            algorithm_from_der_oid(&from_hex(der_hex).unwrap()).map(|alg| format!("{alg:?}"))
+        }
+
+        // Generated by `openssl asn1parse -genconf`.
+        assert_eq!(
+            Some(format!("{:?}", &ECDSA_P256_SHA256_ASN1)),
+            debug_string("300a06082a8648ce3d040302")
+        );
+        assert_eq!(
+            Some(format!("{:?}", &RSA_PKCS1_2048_8192_SHA256)),
+            debug_string("300d06092a864886f70d01010b0500")
+        );
+        assert_eq!(
+            Some(format!("{:?}", &ED25519)),
+            debug_string("300506032b6570")
+        );
+        assert_eq!(
+            Some(format!("{:?}", &RSA_PSS_2048_8192_SHA384)),
+            debug_string(
+                "304106092a864886f70d01010a3034a00f300d06096086480165030402020500a11c301a06092a\
+                 864886f70d010108300d06096086480165030402020500a203020130"
+            )
+        );
+
+        // sha256WithRSAEncryption without the NULL parameters.
+        assert_eq!(None, debug_string("300b06092a864886f70d01010b"));
+        // id-RSASSA-PSS with SHA-384 but a 32-byte salt.
+        assert_eq!(
+            None,
+            debug_string(
+                "304106092a864886f70d01010a3034a00f300d06096086480165030402020500a11c301a06092a\
+                 864886f70d010108300d06096086480165030402020500a203020120"
+            )
+        );
+        // id-Ed448
+        assert_eq!(None, debug_string("300506032b6571"));
+        assert_eq!(None, debug_string(""));
+    }
+
+    #[test]
+    fn test_compatible_algorithms() {
+        use crate::signature::{
+            compatible_algorithms, EcdsaKeyPair, Ed25519KeyPair, KeyPair, VerificationAlgorithm,
+            ECDSA_P384_SHA256_ASN1, ECDSA_P384_SHA384_ASN1, ECDSA_P384_SHA384_ASN1_SIGNING,
+            ECDSA_P384_SHA384_FIXED, ECDSA_P384_SHA3_384_ASN1, ECDSA_P384_SHA3_384_FIXED,
+            RSA_PKCS1_1024_8192_SHA1_FOR_LEGACY_USE_ONLY,
+            RSA_PKCS1_1024_8192_SHA256_FOR_LEGACY_USE_ONLY,
+            RSA_PKCS1_1024_8192_SHA512_FOR_LEGACY_USE_ONLY,
+            RSA_PKCS1_2048_8192_SHA1_FOR_LEGACY_USE_ONLY, RSA_PKCS1_2048_8192_SHA256,
+            RSA_PKCS1_2048_8192_SHA384, RSA_PKCS1_2048_8192_SHA512, RSA_PSS_2048_8192_SHA256,
+            RSA_PSS_2048_8192_SHA256_AUTO_SALT, RSA_PSS_2048_8192_SHA256_MAX_SALT,
+            RSA_PSS_2048_8192_SHA384, RSA_PSS_2048_8192_SHA384_AUTO_SALT,
+            RSA_PSS_2048_8192_SHA384_MAX_SALT, RSA_PSS_2048_8192_SHA512,
+            RSA_PSS_2048_8192_SHA512_AUTO_SALT, RSA_PSS_2048_8192_SHA512_MAX_SALT,
+        };
+
+        fn debug_strings(algs: &[&'static dyn VerificationAlgorithm]) -> Vec<String> {
+            algs.iter().map(|alg| format!("{alg:?}")).collect()
+        }
+
+        let rsa_public_key = include_bytes!("../tests/data/rsa_test_public_key_2048.der");
+        let expected: [&'static dyn VerificationAlgorithm; 16] = [
+            &RSA_PKCS1_1024_8192_SHA1_FOR_LEGACY_USE_ONLY,
+            &RSA_PKCS1_1024_8192_SHA256_FOR_LEGACY_USE_ONLY,
+            &RSA_PKCS1_1024_8192_SHA512_FOR_LEGACY_USE_ONLY,
+            &RSA_PKCS1_2048_8192_SHA1_FOR_LEGACY_USE_ONLY,
+            &RSA_PKCS1_2048_8192_SHA256,
+            &RSA_PKCS1_2048_8192_SHA384,
+            &RSA_PKCS1_2048_8192_SHA512,
+            &RSA_PSS_2048_8192_SHA256,
+            &RSA_PSS_2048_8192_SHA384,
+            &RSA_PSS_2048_8192_SHA512,
+            &RSA_PSS_2048_8192_SHA256_MAX_SALT,
+            &RSA_PSS_2048_8192_SHA384_MAX_SALT,
+            &RSA_PSS_2048_8192_SHA512_MAX_SALT,
+            &RSA_PSS_2048_8192_SHA256_AUTO_SALT,
+            &RSA_PSS_2048_8192_SHA384_AUTO_SALT,
+            &RSA_PSS_2048_8192_SHA512_AUTO_SALT,
+        ];
+        assert_eq!(
+            debug_strings(&expected),
+            debug_strings(&compatible_algorithms(rsa_public_key))
+        );
+
+        let ec_key_pair = EcdsaKeyPair::generate(&ECDSA_P384_SHA384_ASN1_SIGNING).unwrap();
+        let expected: [&'static dyn VerificationAlgorithm; 5] = [
+            &ECDSA_P384_SHA384_FIXED,
+            &ECDSA_P384_SHA3_384_FIXED,
+            &ECDSA_P384_SHA256_ASN1,
+            &ECDSA_P384_SHA384_ASN1,
+            &ECDSA_P384_SHA3_384_ASN1,
+        ];
+        assert_eq!(
+            debug_strings(&expected),
+            debug_strings(&compatible_algorithms(ec_key_pair.public_key().as_ref()))
+        );
+
+        let ed25519_key_pair = Ed25519KeyPair::generate().unwrap();
+        assert_eq!(
+            debug_strings(&[&ED25519]),
+            debug_strings(&compatible_algorithms(
+                ed25519_key_pair.public_key().as_ref()
+            ))
+        );
+
+        assert!(compatible_algorithms(&[]).is_empty());
+        assert!(compatible_algorithms(&[0u8; 64]).is_empty());
+    }
+
+    #[test]
+    fn test_key_pair_verification_algorithm() {
+        use crate::signature::{
+            EcdsaKeyPair, Ed25519KeyPair, KeyPair, RsaKeyPair, ECDSA_P256K1_SHA256_FIXED_SIGNING,
+            ECDSA_P384_SHA384_ASN1_SIGNING,
+        };
+
+        fn algorithm_debug<K: KeyPair>(key_pair: &K) -> Option<String> {
+            key_pair
+                .verification_algorithm()
+                .map(|alg| format!("{alg:?}"))
+        }
+
+        let ecdsa = EcdsaKeyPair::generate(&ECDSA_P384_SHA384_ASN1_SIGNING).unwrap();
+        assert_eq!(
+            Some(format!("{:?}", *ECDSA_P384_SHA384_ASN1_SIGNING)),
+            algorithm_debug(&ecdsa)
+        );
+        let ecdsa = EcdsaKeyPair::generate(&ECDSA_P256K1_SHA256_FIXED_SIGNING).unwrap();
+        assert_eq!(
+            Some(format!("{:?}", *ECDSA_P256K1_SHA256_FIXED_SIGNING)),
+            algorithm_debug(&ecdsa)
+        );
+
+        let ed25519 = Ed25519KeyPair::generate().unwrap();
+        assert_eq!(Some(format!("{ED25519:?}")), algorithm_debug(&ed25519));
+
+        let rsa =
+            RsaKeyPair::from_pkcs8(include_bytes!("../tests/data/rsa_test_private_key_2048.p8"))
+                .unwrap();
+        assert_eq!(None, algorithm_debug(&rsa));
+
+        #[cfg(all(feature = "unstable", not(feature = "fips")))]
+        {
+            use crate::unstable::signature::{PqdsaKeyPair, MLDSA_44, MLDSA_44_SIGNING};
+
+            let pqdsa = PqdsaKeyPair::generate(&MLDSA_44_SIGNING).unwrap();
+            assert_eq!(Some(format!("{MLDSA_44:?}")), algorithm_debug(&pqdsa));
+        }
+    }
 }
@@ -0,0 +1,400 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use crate::error::Unspecified;
+use crate::signature::{
+    self, sign_tbs, EcdsaVerificationAlgorithm, KeyPair, RsaParameters, RsaSignatureEncoding,
+    TbsSigningKey, UnparsedPublicKey, VerificationAlgorithm,
+};
+use core::fmt::{self, Debug, Formatter};
+
+const ED25519_ID: u8 = 1;
+
+// The algorithm identifiers are part of the encoding of a `SignatureBundle` and must never
+// change. Only the algorithms that `SignatureBundle::create` produces signatures for have an
+// identifier; verification-only algorithms, such as the `*_FOR_LEGACY_USE_ONLY` ones, are
+// deliberately absent so that a bundle cannot select them.
+fn ecdsa_bundle_algorithm(id: u8) -> Option<&'static EcdsaVerificationAlgorithm> {
+    Some(match id {
+        2 => &signature::ECDSA_P256_SHA256_FIXED,
+        3 => &signature::ECDSA_P384_SHA384_FIXED,
+        4 => &signature::ECDSA_P384_SHA3_384_FIXED,
+        6 => &signature::ECDSA_P521_SHA224_FIXED,
+        7 => &signature::ECDSA_P521_SHA256_FIXED,
+        8 => &signature::ECDSA_P521_SHA384_FIXED,
+        9 => &signature::ECDSA_P521_SHA512_FIXED,
+        10 => &signature::ECDSA_P521_SHA3_512_FIXED,
+        11 => &signature::ECDSA_P256K1_SHA256_FIXED,
+        12 => &signature::ECDSA_P256K1_SHA3_256_FIXED,
+        13 => &signature::ECDSA_P256_SHA256_ASN1,
+        16 => &signature::ECDSA_P384_SHA384_ASN1,
+        17 => &signature::ECDSA_P384_SHA3_384_ASN1,
+        19 => &signature::ECDSA_P521_SHA224_ASN1,
+        20 => &signature::ECDSA_P521_SHA256_ASN1,
+        21 => &signature::ECDSA_P521_SHA384_ASN1,
+        22 => &signature::ECDSA_P521_SHA512_ASN1,
+        23 => &signature::ECDSA_P521_SHA3_512_ASN1,
+        24 => &signature::ECDSA_P256K1_SHA256_ASN1,
+        25 => &signature::ECDSA_P256K1_SHA3_256_ASN1,
+        _ => return None,
+    })
+}
+
+// Signatures produced with an RSA padding algorithm are identified by the `RSA_*_2048_8192_*`
+// verification algorithm with the same digest and padding.
+fn rsa_bundle_algorithm(id: u8) -> Option<&'static RsaParameters> {
+    Some(match id {
+        132 => &signature::RSA_PKCS1_2048_8192_SHA256,
+        133 => &signature::RSA_PKCS1_2048_8192_SHA384,
+        134 => &signature::RSA_PKCS1_2048_8192_SHA512,
+        136 => &signature::RSA_PSS_2048_8192_SHA256,
+        137 => &signature::RSA_PSS_2048_8192_SHA384,
+        138 => &signature::RSA_PSS_2048_8192_SHA512,
+        139 => &signature::RSA_PSS_2048_8192_SHA256_MAX_SALT,
+        140 => &signature::RSA_PSS_2048_8192_SHA384_MAX_SALT,
+        141 => &signature::RSA_PSS_2048_8192_SHA512_MAX_SALT,
+        _ => return None,
+    })
+}
+
+fn ecdsa_bundle_id(algorithm: &EcdsaVerificationAlgorithm) -> Option<u8> {
+    (0..=u8::MAX).find(|&id| ecdsa_bundle_algorithm(id) == Some(algorithm))
+}
+
+fn rsa_bundle_id(encoding: &RsaSignatureEncoding) -> Option<u8> {
+    (0..=u8::MAX).find(|&id| {
+        rsa_bundle_algorithm(id).map_or(false, |candidate| {
+            candidate.digest_algorithm() == encoding.digest_algorithm()
+                && candidate.padding() == encoding.padding()
+        })
+    })
+}
+
+fn bundle_algorithm(id: u8) -> Result<&'static dyn VerificationAlgorithm, Unspecified> {
+    if id == ED25519_ID {
+        return Ok(&signature::ED25519);
+    }
+    if let Some(algorithm) = ecdsa_bundle_algorithm(id) {
+        return Ok(algorithm);
+    }
+    match rsa_bundle_algorithm(id) {
+        Some(algorithm) => Ok(algorithm),
+        None => Err(Unspecified),
+    }
+}
+
+/// A self-describing signature: a signature together with the public key and the identifier
+/// of the algorithm needed to verify it.
+///
+/// The encoding returned by [`SignatureBundle::as_ref`] is:
+///
+/// * a one-byte algorithm identifier,
+/// * the length of the public key as a two-byte big-endian integer,
+/// * the public key, in the encoding returned by the key pair's `public_key` function, and
+/// * the signature, which extends to the end of the encoding.
+///
+/// The algorithm identifiers are specific to this format. A bundle only identifies the key
+/// that produced the signature; verifying a bundle does not establish that the key is trusted,
+/// which callers must check separately, e.g. by comparing [`SignatureBundle::public_key`] to a
+/// known key.
+#[derive(Clone)]
+pub struct SignatureBundle {
+    algorithm: &'static dyn VerificationAlgorithm,
+    public_key_len: usize,
+    bytes: Vec<u8>,
+}
+
+impl SignatureBundle {
+    const HEADER_LEN: usize = 3;
+
+    /// Signs `msg` with `key` and bundles the signature with the key's public key and
+    /// algorithm identifier.
+    ///
+    /// RSA signatures are identified by the `RSA_*_2048_8192_*` verification algorithm
+    /// corresponding to the padding algorithm.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if signing fails.
+    pub fn create(key: TbsSigningKey<'_>, msg: &[u8]) -> Result<Self, Unspecified> {
+        let (id, public_key): (_, &[u8]) = match key {
+            TbsSigningKey::Ecdsa(key_pair) => (
+                ecdsa_bundle_id(key_pair.signing_algorithm().0),
+                key_pair.public_key().as_ref(),
+            ),
+            TbsSigningKey::Ed25519(key_pair) => (Some(ED25519_ID), key_pair.public_key().as_ref()),
+            TbsSigningKey::Rsa(key_pair, padding_alg) => (
+                rsa_bundle_id(padding_alg.encoding()),
+                key_pair.public_key().as_ref(),
+            ),
+        };
+        let id = id.ok_or(Unspecified)?;
+        let algorithm = bundle_algorithm(id)?;
+        let public_key_len = u16::try_from(public_key.len())?;
+        let signature = sign_tbs(key, msg)?;
+
+        let mut bytes = Vec::with_capacity(Self::HEADER_LEN + public_key.len() + signature.len());
+        bytes.push(id);
+        bytes.extend_from_slice(&public_key_len.to_be_bytes());
+        bytes.extend_from_slice(public_key);
+        bytes.extend_from_slice(&signature);
+
+        Ok(Self {
+            algorithm,
+            public_key_len: public_key.len(),
+            bytes,
+        })
+    }
+
+    /// Parses an encoded bundle, e.g. one previously returned by [`SignatureBundle::as_ref`].
+    ///
+    /// The signature is not verified; use [`SignatureBundle::verify`].
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the algorithm identifier is unknown or the encoding is
+    /// truncated.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Unspecified> {
+        if bytes.len() < Self::HEADER_LEN {
+            return Err(Unspecified);
+        }
+        let algorithm = bundle_algorithm(bytes[0])?;
+        let public_key_len = usize::from(u16::from_be_bytes([bytes[1], bytes[2]]));
+        if bytes.len() - Self::HEADER_LEN < public_key_len {
+            return Err(Unspecified);
+        }
+        Ok(Self {
+            algorithm,
+            public_key_len,
+            bytes: bytes.to_vec(),
+        })
+    }
+
+    /// The algorithm that verifies the signature.
+    #[must_use]
+    pub fn algorithm(&self) -> &'static dyn VerificationAlgorithm {
+        self.algorithm
+    }
+
+    /// The public key of the key pair that produced the signature.
+    #[must_use]
+    pub fn public_key(&self) -> &[u8] {
+        &self.bytes[Self::HEADER_LEN..Self::HEADER_LEN + self.public_key_len]
+    }
+
+    /// The signature.
+    #[must_use]
+    pub fn signature(&self) -> &[u8] {
+        &self.bytes[Self::HEADER_LEN + self.public_key_len..]
+    }
+
+    /// Verifies that the bundle's signature of `msg` is valid for the bundle's public key.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the signature is not valid.
+    pub fn verify(&self, msg: &[u8]) -> Result<(), Unspecified> {
+        UnparsedPublicKey::new(self.algorithm, self.public_key()).verify(msg, self.signature())
+    }
+}
+
+impl AsRef<[u8]> for SignatureBundle {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl Debug for SignatureBundle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SignatureBundle")
+            .field("algorithm", &self.algorithm)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::signature::{
+        EcdsaKeyPair, Ed25519KeyPair, KeyPair, RsaKeyPair, SignatureBundle, TbsSigningKey,
+        ECDSA_P256K1_SHA256_ASN1_SIGNING, ECDSA_P256K1_SHA256_FIXED_SIGNING,
+        ECDSA_P256K1_SHA3_256_ASN1_SIGNING, ECDSA_P256K1_SHA3_256_FIXED_SIGNING,
+        ECDSA_P256_SHA256_ASN1_SIGNING, ECDSA_P256_SHA256_FIXED_SIGNING,
+        ECDSA_P384_SHA384_ASN1_SIGNING, ECDSA_P384_SHA384_FIXED_SIGNING,
+        ECDSA_P384_SHA3_384_ASN1_SIGNING, ECDSA_P384_SHA3_384_FIXED_SIGNING,
+        ECDSA_P521_SHA224_ASN1_SIGNING, ECDSA_P521_SHA224_FIXED_SIGNING,
+        ECDSA_P521_SHA256_ASN1_SIGNING, ECDSA_P521_SHA256_FIXED_SIGNING,
+        ECDSA_P521_SHA384_ASN1_SIGNING, ECDSA_P521_SHA384_FIXED_SIGNING,
+        ECDSA_P521_SHA3_512_ASN1_SIGNING, ECDSA_P521_SHA3_512_FIXED_SIGNING,
+        ECDSA_P521_SHA512_ASN1_SIGNING, ECDSA_P521_SHA512_FIXED_SIGNING, RSA_PKCS1_SHA256,
+        RSA_PKCS1_SHA384, RSA_PKCS1_SHA512, RSA_PSS_SHA256, RSA_PSS_SHA256_MAX_SALT,
+        RSA_PSS_SHA384, RSA_PSS_SHA384_MAX_SALT, RSA_PSS_SHA512, RSA_PSS_SHA512_MAX_SALT,
+    };
+
+    const MSG: &[u8] = b"hello, world";
+
+    #[test]
+    fn test_signature_bundle_round_trip() {
+        let ecdsa = EcdsaKeyPair::generate(&ECDSA_P256_SHA256_ASN1_SIGNING).unwrap();
+        let ecdsa_fixed = EcdsaKeyPair::generate(&ECDSA_P384_SHA384_FIXED_SIGNING).unwrap();
+        let ed25519 = Ed25519KeyPair::generate().unwrap();
+        let rsa = RsaKeyPair::from_pkcs8(include_bytes!(
+            "../../tests/data/rsa_test_private_key_2048.p8"
+        ))
+        .unwrap();
+
+        for (key, public_key) in [
+            (TbsSigningKey::Ecdsa(&ecdsa), ecdsa.public_key().as_ref()),
+            (
+                TbsSigningKey::Ecdsa(&ecdsa_fixed),
+                ecdsa_fixed.public_key().as_ref(),
+            ),
+            (
+                TbsSigningKey::Ed25519(&ed25519),
+                ed25519.public_key().as_ref(),
+            ),
+            (
+                TbsSigningKey::Rsa(&rsa, &RSA_PSS_SHA256),
+                rsa.public_key().as_ref(),
+            ),
+        ] {
+            let bundle = SignatureBundle::create(key, MSG).unwrap();
+            assert_eq!(public_key, bundle.public_key());
+            bundle.verify(MSG).unwrap();
+            assert!(bundle.verify(&MSG[1..]).is_err());
+
+            let parsed = SignatureBundle::from_bytes(bundle.as_ref()).unwrap();
+            assert_eq!(bundle.as_ref(), parsed.as_ref());
+            assert_eq!(public_key, parsed.public_key());
+            assert_eq!(bundle.signature(), parsed.signature());
+            parsed.verify(MSG).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_signature_bundle_algorithm_ids() {
+        let ecdsa = EcdsaKeyPair::generate(&ECDSA_P256_SHA256_ASN1_SIGNING).unwrap();
+        let ecdsa_fixed = EcdsaKeyPair::generate(&ECDSA_P384_SHA384_FIXED_SIGNING).unwrap();
+        let ed25519 = Ed25519KeyPair::generate().unwrap();
+        let rsa = RsaKeyPair::from_pkcs8(include_bytes!(
+            "../../tests/data/rsa_test_private_key_2048.p8"
+        ))
+        .unwrap();
+
+        // The identifiers are part of the encoding and must never change.
+        for (key, id) in [
+            (TbsSigningKey::Ed25519(&ed25519), 1),
+            (TbsSigningKey::Ecdsa(&ecdsa_fixed), 3),
+            (TbsSigningKey::Ecdsa(&ecdsa), 13),
+            (TbsSigningKey::Rsa(&rsa, &RSA_PKCS1_SHA256), 132),
+            (TbsSigningKey::Rsa(&rsa, &RSA_PSS_SHA256), 136),
+            (TbsSigningKey::Rsa(&rsa, &RSA_PSS_SHA512_MAX_SALT), 141),
+        ] {
+            let bundle = SignatureBundle::create(key, MSG).unwrap();
+            assert_eq!(id, bundle.as_ref()[0]);
+            bundle.verify(MSG).unwrap();
+        }
+
+        for id in [0, 26, 127, 145, 255] {
+            assert!(super::bundle_algorithm(id).is_err());
+        }
+    }
+
+    #[test]
+    fn test_signature_bundle_all_signing_algorithms() {
+        for alg in [
+            &ECDSA_P256_SHA256_FIXED_SIGNING,
+            &ECDSA_P384_SHA384_FIXED_SIGNING,
+            &ECDSA_P384_SHA3_384_FIXED_SIGNING,
+            &ECDSA_P521_SHA224_FIXED_SIGNING,
+            &ECDSA_P521_SHA256_FIXED_SIGNING,
+            &ECDSA_P521_SHA384_FIXED_SIGNING,
+            &ECDSA_P521_SHA512_FIXED_SIGNING,
+            &ECDSA_P521_SHA3_512_FIXED_SIGNING,
+            &ECDSA_P256K1_SHA256_FIXED_SIGNING,
+            &ECDSA_P256K1_SHA3_256_FIXED_SIGNING,
+            &ECDSA_P256_SHA256_ASN1_SIGNING,
+            &ECDSA_P384_SHA384_ASN1_SIGNING,
+            &ECDSA_P384_SHA3_384_ASN1_SIGNING,
+            &ECDSA_P521_SHA224_ASN1_SIGNING,
+            &ECDSA_P521_SHA256_ASN1_SIGNING,
+            &ECDSA_P521_SHA384_ASN1_SIGNING,
+            &ECDSA_P521_SHA512_ASN1_SIGNING,
+            &ECDSA_P521_SHA3_512_ASN1_SIGNING,
+            &ECDSA_P256K1_SHA256_ASN1_SIGNING,
+            &ECDSA_P256K1_SHA3_256_ASN1_SIGNING,
+        ] {
+            let key_pair = EcdsaKeyPair::generate(alg).unwrap();
+            let bundle = SignatureBundle::create(TbsSigningKey::Ecdsa(&key_pair), MSG).unwrap();
+            SignatureBundle::from_bytes(bundle.as_ref())
+                .unwrap()
+                .verify(MSG)
+                .unwrap();
+        }
+
+        let rsa = RsaKeyPair::from_pkcs8(include_bytes!(
+            "../../tests/data/rsa_test_private_key_2048.p8"
+        ))
+        .unwrap();
+        for padding_alg in [
+            &RSA_PKCS1_SHA256,
+            &RSA_PKCS1_SHA384,
+            &RSA_PKCS1_SHA512,
+            &RSA_PSS_SHA256,
+            &RSA_PSS_SHA384,
+            &RSA_PSS_SHA512,
+            &RSA_PSS_SHA256_MAX_SALT,
+            &RSA_PSS_SHA384_MAX_SALT,
+            &RSA_PSS_SHA512_MAX_SALT,
+        ] {
+            let bundle =
+                SignatureBundle::create(TbsSigningKey::Rsa(&rsa, padding_alg), MSG).unwrap();
+            SignatureBundle::from_bytes(bundle.as_ref())
+                .unwrap()
+                .verify(MSG)
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_signature_bundle_rejects_verification_only_ids() {
+        // Identifiers of verification algorithms that `create` never produces signatures for,
+        // e.g. `RSA_PKCS1_1024_8192_SHA1_FOR_LEGACY_USE_ONLY` (128), the `ECDSA_P521_SHA1_*`
+        // algorithms (5 and 18) and the `RSA_PSS_*_AUTO_SALT` algorithms (142 to 144).
+        let ecdsa = EcdsaKeyPair::generate(&ECDSA_P256_SHA256_ASN1_SIGNING).unwrap();
+        let bundle = SignatureBundle::create(TbsSigningKey::Ecdsa(&ecdsa), MSG).unwrap();
+        for id in [5, 14, 15, 18, 128, 129, 130, 131, 135, 142, 143, 144] {
+            assert!(super::bundle_algorithm(id).is_err());
+
+            let mut bytes = bundle.as_ref().to_vec();
+            bytes[0] = id;
+            assert!(SignatureBundle::from_bytes(&bytes).is_err());
+        }
+    }
+
+    #[test]
+    fn test_signature_bundle_tampered() {
+        let key_pair = EcdsaKeyPair::generate(&ECDSA_P256_SHA256_ASN1_SIGNING).unwrap();
+        let bundle = SignatureBundle::create(TbsSigningKey::Ecdsa(&key_pair), MSG).unwrap();
+        let bytes = bundle.as_ref();
+
+        // Flipping any bit changes the algorithm, the public key or the signature.
+        for i in 0..bytes.len() {
+            let mut tampered = bytes.to_vec();
+            tampered[i] ^= 0x01;
+            if let Ok(tampered) = SignatureBundle::from_bytes(&tampered) {
+                assert!(tampered.verify(MSG).is_err());
+            }
+        }
+
+        // An unknown algorithm identifier is rejected.
+        let mut tampered = bytes.to_vec();
+        tampered[0] = 0;
+        assert!(SignatureBundle::from_bytes(&tampered).is_err());
+
+        // Truncated bundles are rejected.
+        assert!(SignatureBundle::from_bytes(&bytes[..2]).is_err());
+        let public_key_end = 3 + key_pair.public_key().as_ref().len();
+        assert!(SignatureBundle::from_bytes(&bytes[..public_key_end - 1]).is_err());
+        assert!(SignatureBundle::from_bytes(&bytes[..public_key_end])
+            .unwrap()
+            .verify(MSG)
+            .is_err());
+    }
+}
@@ -0,0 +1,201 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use crate::error::Unspecified;
+use crate::rand::SecureRandom;
+use crate::signature::{EcdsaKeyPair, Ed25519KeyPair, KeyPair, RsaEncoding, RsaKeyPair, Signature};
+
+/// A key pair that refuses to produce more than a fixed number of signatures.
+///
+/// Each successful `sign` call counts towards the limit. Once `max_signatures` signatures have
+/// been produced every further call fails, signalling that the key must be rotated. Failed
+/// signing operations are not counted.
+///
+/// `sign` is offered for `EcdsaKeyPair`, `Ed25519KeyPair` and `RsaKeyPair`, with the same
+/// parameters as the key pair's own `sign` function.
+///
+/// # Example
+///
+/// ```
+/// use aws_lc_rs::signature::{CountedSigner, Ed25519KeyPair};
+///
+/// let mut signer = CountedSigner::new(Ed25519KeyPair::generate()?, 2);
+/// signer.sign(b"first")?;
+/// signer.sign(b"second")?;
+/// assert!(signer.is_exhausted());
+/// assert!(signer.sign(b"third").is_err());
+///
+/// # Ok::<(), aws_lc_rs::error::Unspecified>(())
+/// ```
+#[derive(Debug)]
+pub struct CountedSigner<K> {
+    key_pair: K,
+    max_signatures: u64,
+    signatures: u64,
+}
+
+impl<K> CountedSigner<K> {
+    /// Wraps `key_pair` so that it produces at most `max_signatures` signatures.
+    #[must_use]
+    pub fn new(key_pair: K, max_signatures: u64) -> Self {
+        Self {
+            key_pair,
+            max_signatures,
+            signatures: 0,
+        }
+    }
+
+    /// The number of signatures produced so far.
+    #[must_use]
+    pub fn signatures(&self) -> u64 {
+        self.signatures
+    }
+
+    /// The number of signatures that can still be produced.
+    #[must_use]
+    pub fn remaining(&self) -> u64 {
+        self.max_signatures - self.signatures
+    }
+
+    /// Returns `true` if the limit has been reached and the key pair should be rotated.
+    #[must_use]
+    pub fn is_exhausted(&self) -> bool {
+        self.signatures >= self.max_signatures
+    }
+
+    /// Unwraps the key pair. Signatures made with it are no longer counted.
+    #[must_use]
+    pub fn into_inner(self) -> K {
+        self.key_pair
+    }
+
+    fn counted<R>(
+        &mut self,
+        sign: impl FnOnce(&K) -> Result<R, Unspecified>,
+    ) -> Result<R, Unspecified> {
+        if self.is_exhausted() {
+            return Err(Unspecified);
+        }
+        let result = sign(&self.key_pair)?;
+        self.signatures += 1;
+        Ok(result)
+    }
+}
+
+impl<K: KeyPair> CountedSigner<K> {
+    /// The public key of the wrapped key pair.
+    #[must_use]
+    pub fn public_key(&self) -> &K::PublicKey {
+        self.key_pair.public_key()
+    }
+}
+
+impl CountedSigner<EcdsaKeyPair> {
+    /// Signs `message` as `EcdsaKeyPair::sign` does.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the signature limit has been reached or signing fails.
+    pub fn sign(
+        &mut self,
+        rng: &dyn SecureRandom,
+        message: &[u8],
+    ) -> Result<Signature, Unspecified> {
+        self.counted(|key_pair| key_pair.sign(rng, message))
+    }
+}
+
+impl CountedSigner<Ed25519KeyPair> {
+    /// Signs `message` as `Ed25519KeyPair::sign` does.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the signature limit has been reached or signing fails.
+    pub fn sign(&mut self, message: &[u8]) -> Result<Signature, Unspecified> {
+        self.counted(|key_pair| Ok(key_pair.sign(message)))
+    }
+}
+
+impl CountedSigner<RsaKeyPair> {
+    /// Signs `message` into `signature` as `RsaKeyPair::sign` does.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the signature limit has been reached or signing fails.
+    pub fn sign(
+        &mut self,
+        padding_alg: &'static dyn RsaEncoding,
+        rng: &dyn SecureRandom,
+        message: &[u8],
+        signature: &mut [u8],
+    ) -> Result<(), Unspecified> {
+        self.counted(|key_pair| key_pair.sign(padding_alg, rng, message, signature))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Unspecified;
+    use crate::rand::SystemRandom;
+    use crate::signature::{
+        CountedSigner, EcdsaKeyPair, Ed25519KeyPair, RsaKeyPair, UnparsedPublicKey,
+        ECDSA_P256_SHA256_ASN1, ECDSA_P256_SHA256_ASN1_SIGNING, RSA_PKCS1_SHA256,
+    };
+
+    const N: u64 = 3;
+
+    #[test]
+    fn test_counted_signer_limit() {
+        let rng = SystemRandom::new();
+
+        let mut ecdsa = CountedSigner::new(
+            EcdsaKeyPair::generate(&ECDSA_P256_SHA256_ASN1_SIGNING).unwrap(),
+            N,
+        );
+        for i in 0..N {
+            assert_eq!(N - i, ecdsa.remaining());
+            let signature = ecdsa.sign(&rng, b"message").unwrap();
+            UnparsedPublicKey::new(&ECDSA_P256_SHA256_ASN1, ecdsa.public_key())
+                .verify(b"message", signature.as_ref())
+                .unwrap();
+        }
+        assert!(ecdsa.is_exhausted());
+        assert!(ecdsa.sign(&rng, b"message").is_err());
+        assert_eq!(N, ecdsa.signatures());
+
+        let mut ed25519 = CountedSigner::new(Ed25519KeyPair::generate().unwrap(), N);
+        for _ in 0..N {
+            ed25519.sign(b"message").unwrap();
+        }
+        assert!(ed25519.sign(b"message").is_err());
+        assert_eq!(0, ed25519.remaining());
+
+        let key_pair = RsaKeyPair::generate(crate::rsa::KeySize::Rsa2048).unwrap();
+        let mut signature = vec![0u8; key_pair.public_modulus_len()];
+        let mut rsa = CountedSigner::new(key_pair, N);
+        for _ in 0..N {
+            rsa.sign(&RSA_PKCS1_SHA256, &rng, b"message", &mut signature)
+                .unwrap();
+        }
+        assert!(rsa
+            .sign(&RSA_PKCS1_SHA256, &rng, b"message", &mut signature)
+            .is_err());
+
+        // Taking the key pair back out is the only way to sign without the limit.
+        let key_pair = rsa.into_inner();
+        key_pair
+            .sign(&RSA_PKCS1_SHA256, &rng, b"message", &mut signature)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_counted_signer_failures_not_counted() {
+        let mut ed25519 = CountedSigner::new(Ed25519KeyPair::generate().unwrap(), 1);
+        assert!(ed25519.counted(|_| Err::<(), _>(Unspecified)).is_err());
+        assert_eq!(0, ed25519.signatures());
+
+        ed25519.sign(b"message").unwrap();
+        assert!(ed25519.is_exhausted());
+
+        let mut none = CountedSigner::new(Ed25519KeyPair::generate().unwrap(), 0);
+        assert!(none.is_exhausted());
+        assert!(none.sign(b"message").is_err());
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use crate::aws_lc::{EVP_DigestVerifyFinal, EVP_DigestVerifyUpdate, EVP_PKEY};
+use crate::digest::digest_ctx::DigestContext;
+use crate::ec::signature::{with_asn1_signature, AlgorithmID};
+use crate::error::Unspecified;
+use crate::fips::indicator_check;
+use crate::ptr::LcPtr;
+use crate::signature::{EcdsaVerificationAlgorithm, RsaParameters, VerificationAlgorithm};
+use core::fmt;
+use core::fmt::{Debug, Formatter};
+use std::io::{ErrorKind, Read};
+
+/// The encoding of signatures accepted by a `VerificationContext`.
+pub(crate) enum StreamingSignatureFormat {
+    /// Signatures are passed to AWS-LC as-is (RSA, ASN.1 DER encoded ECDSA).
+    Encoded,
+    /// Fixed-length ECDSA signatures, which are converted to ASN.1 DER before verification.
+    EcdsaFixed(&'static AlgorithmID),
+}
+
+/// A context for verifying a signature over a message that is provided incrementally.
+///
+/// This allows a large message, such as a file, to be verified without holding all of it in
+/// memory. A context is created by `RsaParameters::verification_context` or
+/// `EcdsaVerificationAlgorithm::verification_context`.
+///
+/// # Example
+/// ```
+/// use aws_lc_rs::rand::SystemRandom;
+/// use aws_lc_rs::signature::{self, EcdsaKeyPair, KeyPair};
+///
+/// let key_pair = EcdsaKeyPair::generate(&signature::ECDSA_P256_SHA256_ASN1_SIGNING)?;
+/// let sig = key_pair.sign(&SystemRandom::new(), b"hello, world")?;
+///
+/// let mut context = signature::ECDSA_P256_SHA256_ASN1
+///     .verification_context(key_pair.public_key().as_ref())?;
+/// context.update(b"hello, ")?;
+/// context.update(b"world")?;
+/// context.verify(sig.as_ref())?;
+/// # Ok::<(), aws_lc_rs::error::Unspecified>(())
+/// ```
+pub struct VerificationContext {
+    // The digest context holds a reference to the key, which is also kept here so that it
+    // outlives the context.
+    _evp_pkey: LcPtr<EVP_PKEY>,
+    md_ctx: DigestContext,
+    signature_format: StreamingSignatureFormat,
+}
+
+impl VerificationContext {
+    pub(crate) fn new(
+        evp_pkey: LcPtr<EVP_PKEY>,
+        md_ctx: DigestContext,
+        signature_format: StreamingSignatureFormat,
+    ) -> Self {
+        Self {
+            _evp_pkey: evp_pkey,
+            md_ctx,
+            signature_format,
+        }
+    }
+
+    /// Updates the context with the next chunk of the message.
+    ///
+    /// # Errors
+    /// `error::Unspecified` on internal error.
+    pub fn update(&mut self, data: &[u8]) -> Result<(), Unspecified> {
+        if 1 != unsafe {
+            EVP_DigestVerifyUpdate(self.md_ctx.as_mut_ptr(), data.as_ptr().cast(), data.len())
+        } {
+            return Err(Unspecified);
+        }
+        Ok(())
+    }
+
+    /// Verifies `signature` over all of the data provided to `update`.
+    ///
+    // # FIPS
+    // The conditions of the algorithm used to create the context must be met.
+    //
+    /// # Errors
+    /// `error::Unspecified` if the signature is not valid.
+    pub fn verify(mut self, signature: &[u8]) -> Result<(), Unspecified> {
+        match self.signature_format {
+            StreamingSignatureFormat::Encoded => verify_final(&mut self.md_ctx, signature),
+            StreamingSignatureFormat::EcdsaFixed(alg) => {
+                let md_ctx = &mut self.md_ctx;
+                with_asn1_signature(alg, signature, |signature| verify_final(md_ctx, signature))
+            }
+        }
+    }
+}
+
+fn verify_final(md_ctx: &mut DigestContext, signature: &[u8]) -> Result<(), Unspecified> {
+    if 1 != indicator_check!(unsafe {
+        EVP_DigestVerifyFinal(md_ctx.as_mut_ptr(), signature.as_ptr(), signature.len())
+    }) {
+        return Err(Unspecified);
+    }
+    Ok(())
+}
+
+impl Debug for VerificationContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("VerificationContext { .. }")
+    }
+}
+
+/// A `VerificationAlgorithm` that can verify a signature over a message that is provided
+/// incrementally.
+///
+/// Implemented by the RSA and ECDSA verification algorithms. Ed25519 signatures cannot be
+/// verified incrementally because the whole message is hashed twice.
+pub trait StreamingVerificationAlgorithm: VerificationAlgorithm {
+    /// Creates a `VerificationContext` for this algorithm and `public_key`.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the public key cannot be parsed for this algorithm.
+    fn verification_context(
+        &'static self,
+        public_key: &[u8],
+    ) -> Result<VerificationContext, Unspecified>;
+}
+
+impl StreamingVerificationAlgorithm for RsaParameters {
+    fn verification_context(
+        &'static self,
+        public_key: &[u8],
+    ) -> Result<VerificationContext, Unspecified> {
+        RsaParameters::verification_context(self, public_key)
+    }
+}
+
+impl StreamingVerificationAlgorithm for EcdsaVerificationAlgorithm {
+    fn verification_context(
+        &'static self,
+        public_key: &[u8],
+    ) -> Result<VerificationContext, Unspecified> {
+        EcdsaVerificationAlgorithm::verification_context(self, public_key)
+    }
+}
+
+/// Verifies `signature` over the message read from `reader` until end of file, using `alg`
+/// and `public_key`.
+///
+/// The message is read in fixed-size chunks, so it never needs to be held in memory in full.
+/// The result is the same as verifying the buffered message with `UnparsedPublicKey::verify`.
+///
+/// # Example
+/// ```
+/// use aws_lc_rs::rand::SystemRandom;
+/// use aws_lc_rs::signature::{self, EcdsaKeyPair, KeyPair};
+///
+/// let key_pair = EcdsaKeyPair::generate(&signature::ECDSA_P256_SHA256_ASN1_SIGNING)?;
+/// let message = vec![0x5a; 100_000];
+/// let sig = key_pair.sign(&SystemRandom::new(), &message)?;
+///
+/// signature::verify_reader(
+///     &signature::ECDSA_P256_SHA256_ASN1,
+///     key_pair.public_key().as_ref(),
+///     message.as_slice(),
+///     sig.as_ref(),
+/// )?;
+/// # Ok::<(), aws_lc_rs::error::Unspecified>(())
+/// ```
+///
+// # FIPS
+// The conditions of `alg` must be met.
+//
+/// # Errors
+/// `error::Unspecified` if reading from `reader` fails, the public key cannot be parsed, or
+/// the signature is not valid.
+pub fn verify_reader<R: Read>(
+    alg: &'static dyn StreamingVerificationAlgorithm,
+    public_key: &[u8],
+    mut reader: R,
+    signature: &[u8],
+) -> Result<(), Unspecified> {
+    const CHUNK_LEN: usize = 8192;
+
+    let mut context = alg.verification_context(public_key)?;
+    let mut chunk = [0u8; CHUNK_LEN];
+    loop {
+        match reader.read(&mut chunk) {
+            Ok(0) => break,
+            Ok(len) => context.update(&chunk[..len])?,
+            Err(e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(_) => return Err(Unspecified),
+        }
+    }
+    context.verify(signature)
+}
@@ -0,0 +1,140 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use crate::error::Unspecified;
+use crate::rand::SystemRandom;
+use crate::signature::{
+    EcdsaKeyPair, Ed25519KeyPair, RsaEncoding, RsaKeyPair, UnparsedPublicKey, VerificationAlgorithm,
+};
+use core::fmt::{self, Debug, Formatter};
+
+/// A key pair, and for RSA the padding algorithm, used by [`sign_tbs`].
+#[non_exhaustive]
+#[derive(Clone, Copy)]
+pub enum TbsSigningKey<'a> {
+    /// An ECDSA key pair. Signatures use the key pair's signing algorithm.
+    Ecdsa(&'a EcdsaKeyPair),
+
+    /// An Ed25519 key pair.
+    Ed25519(&'a Ed25519KeyPair),
+
+    /// An RSA key pair and the padding algorithm to sign with.
+    Rsa(&'a RsaKeyPair, &'static dyn RsaEncoding),
+}
+
+impl Debug for TbsSigningKey<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ecdsa(key_pair) => f.debug_tuple("Ecdsa").field(key_pair).finish(),
+            Self::Ed25519(key_pair) => f.debug_tuple("Ed25519").field(key_pair).finish(),
+            Self::Rsa(key_pair, padding_alg) => f
+                .debug_tuple("Rsa")
+                .field(key_pair)
+                .field(padding_alg)
+                .finish(),
+        }
+    }
+}
+
+/// Signs a DER-encoded "to be signed" structure, such as the `CertificationRequestInfo` of a
+/// PKCS#10 certificate signing request or the `TBSCertificate` of an X.509 certificate.
+///
+/// The signature is computed over the complete DER encoding of `tbs_der`, including its
+/// outer tag and length, as required by RFC 2986 and RFC 5280. The returned bytes are the
+/// contents of the `signature` BIT STRING: an ASN.1 DER encoded ECDSA signature when the key
+/// pair uses an ASN.1 signing algorithm, a 64-byte Ed25519 signature, or an RSA signature of
+/// the modulus length.
+///
+/// This is equivalent to calling the key pair's `sign` function on `tbs_der`.
+///
+/// # Errors
+/// `error::Unspecified` if signing fails.
+pub fn sign_tbs(key: TbsSigningKey<'_>, tbs_der: &[u8]) -> Result<Vec<u8>, Unspecified> {
+    let rng = SystemRandom::new();
+    match key {
+        TbsSigningKey::Ecdsa(key_pair) => Ok(key_pair.sign(&rng, tbs_der)?.as_ref().to_vec()),
+        TbsSigningKey::Ed25519(key_pair) => Ok(key_pair.sign(tbs_der).as_ref().to_vec()),
+        TbsSigningKey::Rsa(key_pair, padding_alg) => {
+            let mut signature = vec![0u8; key_pair.public_modulus_len()];
+            key_pair.sign(padding_alg, &rng, tbs_der, &mut signature)?;
+            Ok(signature)
+        }
+    }
+}
+
+/// Verifies a `signature` over a DER-encoded "to be signed" structure produced by
+/// [`sign_tbs`] or by another implementation.
+///
+/// `public_key` is in the encoding expected by `alg`; see [`UnparsedPublicKey`].
+///
+/// # Errors
+/// `error::Unspecified` if the signature is not valid.
+pub fn verify_tbs(
+    alg: &'static dyn VerificationAlgorithm,
+    public_key: &[u8],
+    tbs_der: &[u8],
+    signature: &[u8],
+) -> Result<(), Unspecified> {
+    UnparsedPublicKey::new(alg, public_key).verify(tbs_der, signature)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rand::SystemRandom;
+    use crate::signature::{
+        sign_tbs, verify_tbs, EcdsaKeyPair, Ed25519KeyPair, KeyPair, RsaKeyPair, TbsSigningKey,
+        ECDSA_P256_SHA256_ASN1, ECDSA_P256_SHA256_ASN1_SIGNING, ED25519,
+        RSA_PKCS1_2048_8192_SHA256, RSA_PKCS1_SHA256,
+    };
+
+    // A dummy `CertificationRequestInfo`: SEQUENCE { INTEGER 0, SEQUENCE {}, SEQUENCE {},
+    // [0] {} }.
+    const TBS_DER: &[u8] = &[
+        0x30, 0x09, 0x02, 0x01, 0x00, 0x30, 0x00, 0x30, 0x00, 0xa0, 0x00,
+    ];
+
+    #[test]
+    fn test_sign_and_verify_tbs() {
+        let rng = SystemRandom::new();
+
+        let ecdsa = EcdsaKeyPair::generate(&ECDSA_P256_SHA256_ASN1_SIGNING).unwrap();
+        let signature = sign_tbs(TbsSigningKey::Ecdsa(&ecdsa), TBS_DER).unwrap();
+        verify_tbs(
+            &ECDSA_P256_SHA256_ASN1,
+            ecdsa.public_key().as_ref(),
+            TBS_DER,
+            &signature,
+        )
+        .unwrap();
+        assert!(verify_tbs(
+            &ECDSA_P256_SHA256_ASN1,
+            ecdsa.public_key().as_ref(),
+            &TBS_DER[1..],
+            &signature,
+        )
+        .is_err());
+
+        let ed25519 = Ed25519KeyPair::generate().unwrap();
+        let signature = sign_tbs(TbsSigningKey::Ed25519(&ed25519), TBS_DER).unwrap();
+        assert_eq!(ed25519.sign(TBS_DER).as_ref(), signature.as_slice());
+        verify_tbs(&ED25519, ed25519.public_key().as_ref(), TBS_DER, &signature).unwrap();
+
+        let rsa = RsaKeyPair::from_pkcs8(include_bytes!(
+            "../../tests/data/rsa_test_private_key_2048.p8"
+        ))
+        .unwrap();
+        let signature = sign_tbs(TbsSigningKey::Rsa(&rsa, &RSA_PKCS1_SHA256), TBS_DER).unwrap();
+        let mut expected = vec![0u8; rsa.public_modulus_len()];
+        rsa.sign(&RSA_PKCS1_SHA256, &rng, TBS_DER, &mut expected)
+            .unwrap();
+        // PKCS#1 v1.5 signatures are deterministic.
+        assert_eq!(expected, signature);
+        verify_tbs(
+            &RSA_PKCS1_2048_8192_SHA256,
+            rsa.public_key().as_ref(),
+            TBS_DER,
+            &signature,
+        )
+        .unwrap();
+    }
+}
@@ -122,6 +122,44 @@ pub use crate::hex::{
     encode_upper as to_hex_upper,
 };
 
+/// Deterministically derives an ECDSA key pair for `alg` from `label`.
+///
+/// The private scalar is expanded from `label` with HKDF-SHA256 (see
+/// [`rand::HkdfRandom`]), so the same label always yields the same key pair. This lets tests
+/// use stable fixtures without committing key files. The resulting keys are public knowledge
+/// and must never be used outside of tests.
+///
+/// # Panics
+/// Panics if the key pair cannot be constructed.
+#[must_use]
+pub fn deterministic_ecdsa_key(
+    alg: &'static crate::signature::EcdsaSigningAlgorithm,
+    label: &str,
+) -> crate::signature::EcdsaKeyPair {
+    let rng = rand::HkdfRandom::new(label.as_bytes());
+    generate_matching_ecdsa_key(alg, &rng, |_| true).unwrap()
+}
+
+/// Generates ECDSA key pairs using `rng` until one has a public key for which `predicate`
+/// returns `true`. `predicate` is given the public key as returned by `public_key().as_ref()`,
+/// i.e. an uncompressed point beginning with `0x04`.
+///
+/// This is intended only for producing reproducible test fixtures, e.g. "vanity" keys whose
+/// public key starts with specific bytes, using a deterministic `rng`. It is slow: each
+/// attempt is a full key generation, so the expected number of attempts doubles with every
+/// constrained bit, and it never returns if `rng` cannot produce a matching key. Do not use it
+/// to generate production keys.
+///
+/// # Errors
+/// `error::Unspecified` if `rng` fails, or on internal error.
+pub fn generate_matching_ecdsa_key(
+    alg: &'static crate::signature::EcdsaSigningAlgorithm,
+    rng: &dyn crate::rand::SecureRandom,
+    predicate: impl Fn(&[u8]) -> bool,
+) -> Result<crate::signature::EcdsaKeyPair, error::Unspecified> {
+    crate::signature::EcdsaKeyPair::generate_matching(alg, rng, predicate)
+}
+
 /// `compile_time_assert_clone::<T>();` fails to compile if `T` doesn't
 /// implement `Clone`.
 #[allow(clippy::extra_unused_type_parameters)]
@@ -486,6 +524,47 @@ pub mod rand {
         }
     }
 
+    /// An implementation of `SecureRandom` whose output is expanded with HKDF-SHA256 from a
+    /// fixed seed. Each call to `fill()` produces fresh output, and the sequence of outputs
+    /// is the same for every instance created with the same seed. *Not thread-safe.*
+    #[derive(Debug)]
+    pub struct HkdfRandom {
+        prk: crate::hkdf::Prk,
+        counter: core::cell::Cell<u32>,
+    }
+
+    impl HkdfRandom {
+        const SALT: &'static [u8] = b"aws-lc-rs test::rand::HkdfRandom";
+
+        #[must_use]
+        pub fn new(seed: &[u8]) -> Self {
+            let salt = crate::hkdf::Salt::new(crate::hkdf::HKDF_SHA256, Self::SALT);
+            Self {
+                prk: salt.extract(seed),
+                counter: core::cell::Cell::new(0),
+            }
+        }
+    }
+
+    impl crate::rand::sealed::SecureRandom for HkdfRandom {
+        fn fill_impl(&self, dest: &mut [u8]) -> Result<(), error::Unspecified> {
+            struct Len(usize);
+            impl crate::hkdf::KeyType for Len {
+                fn len(&self) -> usize {
+                    self.0
+                }
+            }
+
+            let counter = self.counter.get();
+            self.counter
+                .set(counter.checked_add(1).ok_or(error::Unspecified)?);
+            let info = counter.to_be_bytes();
+            let info = [&info[..]];
+            let okm = self.prk.expand(&info, Len(dest.len()))?;
+            okm.fill(dest)
+        }
+    }
+
     impl Drop for FixedSliceSequenceRandom<'_> {
         fn drop(&mut self) {
             // Ensure that `fill()` was called exactly the right number of
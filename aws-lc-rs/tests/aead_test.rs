@@ -754,3 +754,80 @@ fn prepare_nonce() {
     ok.open_in_place(Aad::empty(), &mut message)
         .expect_err("sequence limit reached");
 }
+
+#[test]
+fn test_safe_message_limit() {
+    for alg in [
+        &aead::AES_128_GCM,
+        &aead::AES_192_GCM,
+        &aead::AES_256_GCM,
+        &aead::AES_128_GCM_SIV,
+        &aead::AES_256_GCM_SIV,
+        &aead::CHACHA20_POLY1305,
+    ] {
+        // 96-bit random nonces collide with probability at most 2^-32 after 2^32 messages.
+        assert_eq!(96, alg.nonce_len() * 8);
+        assert_eq!(1u64 << 32, alg.safe_message_limit());
+    }
+}
+
+#[test]
+fn test_gcm_siv_nonce_reuse() {
+    const MESSAGE_1: &[u8] = b"first message under a reused nonce";
+    const MESSAGE_2: &[u8] = b"other message under a reused nonce";
+
+    for alg in [&aead::AES_128_GCM_SIV, &aead::AES_256_GCM_SIV] {
+        assert_eq!(NONCE_LEN, alg.nonce_len());
+        assert_eq!(16, alg.tag_len());
+        let key_bytes = vec![0x42u8; alg.key_len()];
+        let key = make_less_safe_key(alg, &key_bytes);
+        let nonce = [0x24u8; NONCE_LEN];
+
+        let mut ciphertext_1 = MESSAGE_1.to_vec();
+        key.seal_in_place_append_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::empty(),
+            &mut ciphertext_1,
+        )
+        .unwrap();
+        let mut ciphertext_2 = MESSAGE_2.to_vec();
+        key.seal_in_place_append_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::empty(),
+            &mut ciphertext_2,
+        )
+        .unwrap();
+
+        // With GCM, XORing two ciphertexts under the same nonce yields the XOR of the
+        // plaintexts. GCM-SIV derives the keystream from the message, so it does not.
+        let ciphertext_xor: Vec<u8> = ciphertext_1
+            .iter()
+            .zip(&ciphertext_2)
+            .map(|(a, b)| a ^ b)
+            .take(MESSAGE_1.len())
+            .collect();
+        let plaintext_xor: Vec<u8> = MESSAGE_1
+            .iter()
+            .zip(MESSAGE_2)
+            .map(|(a, b)| a ^ b)
+            .collect();
+        assert_ne!(plaintext_xor, ciphertext_xor);
+
+        let plaintext = key
+            .open_in_place(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::empty(),
+                &mut ciphertext_1,
+            )
+            .unwrap();
+        assert_eq!(MESSAGE_1, plaintext);
+        let plaintext = key
+            .open_in_place(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::empty(),
+                &mut ciphertext_2,
+            )
+            .unwrap();
+        assert_eq!(MESSAGE_2, plaintext);
+    }
+}
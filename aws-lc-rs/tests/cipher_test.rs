@@ -593,6 +593,32 @@ cipher_kat!(
     "b5098e5e788de6ac2f2098eb2fc6f8"
 );
 
+cipher_kat!(
+    test_kat_aes_128_ctr_sp800_38a_f_5_1,
+    &AES_128,
+    OperatingMode::CTR,
+    ctr,
+    "2b7e151628aed2a6abf7158809cf4f3c",
+    "f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff",
+    "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51\
+     30c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710",
+    "874d6191b620e3261bef6864990db6ce9806f66b7970fdff8617187bb9fffdff\
+     5ae4df3edbd5d35e5b4f09020db03eab1e031dda2fbe03d1792170a0f3009cee"
+);
+
+cipher_kat!(
+    test_kat_aes_256_ctr_sp800_38a_f_5_5,
+    &AES_256,
+    OperatingMode::CTR,
+    ctr,
+    "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4",
+    "f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff",
+    "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51\
+     30c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710",
+    "601ec313775789a5b7a7f504bbf3d228f443e3ca4d62b59aca84e990cacaf5c5\
+     2b0930daa23de94ce87017ba2d84988ddfc9c58db67aada613c2dd08457941a6"
+);
+
 padded_cipher_kat!(
     test_kat_aes_128_cbc_15_bytes,
     &AES_128,
@@ -664,6 +690,18 @@ padded_cipher_rt!(
     "00112233445566778899aabbccddee"
 );
 
+cipher_rt!(
+    test_rt_aes_128_ctr_100_bytes,
+    &AES_128,
+    OperatingMode::CTR,
+    ctr,
+    "000102030405060708090a0b0c0d0e0f",
+    "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f\
+     202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f\
+     404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f\
+     60616263"
+);
+
 cipher_rt!(
     test_rt_aes_128_ctr_16_bytes,
     &AES_128,
@@ -385,8 +385,16 @@ test_large_digest!(
     ]
 );
 
-// TODO: test_large_digest!(digest_test_large_digest_sha512_256,
-//                            digest::SHA512_256, 256 / 8, [ ... ]);
+test_large_digest!(
+    digest_test_large_digest_sha512_256,
+    digest::SHA512_256,
+    256 / 8,
+    [
+        0x32, 0xCB, 0xB8, 0x5A, 0x5E, 0x66, 0x05, 0xA3, 0xB9, 0x4C, 0x99, 0x07, 0xB6, 0xE4, 0xFC,
+        0xC6, 0x00, 0x1B, 0x05, 0x91, 0x1C, 0xAE, 0xB0, 0x7F, 0xB0, 0xD3, 0x0E, 0xA9, 0x81, 0x7C,
+        0xDC, 0x62
+    ]
+);
 
 #[test]
 fn test_fmt_algorithm() {
@@ -435,3 +443,236 @@ fn digest_test_fmt() {
         &format!("{:?}", digest::digest(&digest::SHA512_256, b"hello, world"))
     );
 }
+
+#[test]
+fn digest_test_hex() {
+    let digest = digest::digest(&digest::SHA256, b"hello, world");
+    assert_eq!(
+        "09ca7e4eaa6e8ae9c7d261167129184883644d07dfba7cbfbc4c8a2e08360d5b",
+        format!("{digest:x}")
+    );
+    assert_eq!(
+        "09CA7E4EAA6E8AE9C7D261167129184883644D07DFBA7CBFBC4C8A2E08360D5B",
+        format!("{digest:X}")
+    );
+}
+
+#[test]
+fn digest_algorithm_test() {
+    assert_eq!(
+        &digest::SHA256,
+        digest::digest(&digest::SHA256, b"hello, world").algorithm()
+    );
+
+    let mut ctx = digest::Context::new(&digest::SHA384);
+    ctx.update(b"hello, world");
+    assert_eq!(&digest::SHA384, ctx.finish().algorithm());
+}
+
+#[test]
+fn digest_many_test() {
+    let inputs: [&[u8]; 4] = [b"", b"a", b"hello, world", &[0u8; 200]];
+    for alg in [
+        &digest::SHA1_FOR_LEGACY_USE_ONLY,
+        &digest::SHA224,
+        &digest::SHA256,
+        &digest::SHA384,
+        &digest::SHA512,
+        &digest::SHA512_256,
+        &digest::SHA3_256,
+        &digest::SHA3_384,
+        &digest::SHA3_512,
+    ] {
+        let digests = digest::digest_many(alg, &inputs);
+        assert_eq!(inputs.len(), digests.len());
+        for (input, actual) in inputs.iter().zip(digests.iter()) {
+            let expected = digest::digest(alg, input);
+            assert_eq!(alg, actual.algorithm());
+            assert_eq!(expected.as_ref(), actual.as_ref());
+        }
+    }
+
+    assert!(digest::digest_many(&digest::SHA256, &[]).is_empty());
+}
+
+#[test]
+fn keyed_sha3_256_test() {
+    const MESSAGE: &[u8] = b"hello, world";
+
+    let tag = digest::keyed_sha3_256(b"key", MESSAGE);
+    assert_eq!(&digest::SHA3_256, tag.algorithm());
+    assert_eq!(
+        tag.as_ref(),
+        digest::keyed_sha3_256(b"key", MESSAGE).as_ref()
+    );
+    assert_ne!(
+        tag.as_ref(),
+        digest::digest(&digest::SHA3_256, MESSAGE).as_ref()
+    );
+    assert_ne!(
+        tag.as_ref(),
+        digest::keyed_sha3_256(b"other key", MESSAGE).as_ref()
+    );
+    // The key length is encoded, so moving bytes between key and message changes the output.
+    assert_ne!(
+        digest::keyed_sha3_256(b"ke", b"yhello, world").as_ref(),
+        tag.as_ref()
+    );
+
+    let mut expected_input = 3u64.to_be_bytes().to_vec();
+    expected_input.extend_from_slice(b"key");
+    expected_input.extend_from_slice(MESSAGE);
+    assert_eq!(
+        tag.as_ref(),
+        digest::digest(&digest::SHA3_256, &expected_input).as_ref()
+    );
+
+    digest::verify_keyed_sha3_256(b"key", MESSAGE, tag.as_ref()).unwrap();
+    assert!(digest::verify_keyed_sha3_256(b"other key", MESSAGE, tag.as_ref()).is_err());
+    let mut flipped = tag.as_ref().to_vec();
+    flipped[0] ^= 0x01;
+    assert!(digest::verify_keyed_sha3_256(b"key", MESSAGE, &flipped).is_err());
+    assert!(digest::verify_keyed_sha3_256(b"key", MESSAGE, &tag.as_ref()[..31]).is_err());
+}
+
+#[test]
+fn tagged_hash_test() {
+    for (tag, message, expected) in [
+        // The hash of the all-zero auxiliary random data used in BIP340 test vector 0.
+        (
+            "BIP0340/aux",
+            &[0u8; 32][..],
+            "54f169cfc9e2e5727480441f90ba25c488f461c70b5ea5dcaaf7af69270aa514",
+        ),
+        (
+            "BIP0340/challenge",
+            b"",
+            "c216d352f5818b7b4beacd4ae0a26fe888080823d2a598856661bcd54f1b3713",
+        ),
+        (
+            "TapLeaf",
+            b"abc",
+            "83a56308a9c56f467e8df293da5ae5fdbc85b871952a83c4bf0575ee948ec230",
+        ),
+    ] {
+        let hash = digest::tagged_hash(tag, message);
+        assert_eq!(&digest::SHA256, hash.algorithm());
+        assert_eq!(test::from_hex(expected).unwrap(), hash.as_ref());
+
+        let tag_hash = digest::digest(&digest::SHA256, tag.as_bytes());
+        let mut input = tag_hash.as_ref().to_vec();
+        input.extend_from_slice(tag_hash.as_ref());
+        input.extend_from_slice(message);
+        assert_eq!(
+            digest::digest(&digest::SHA256, &input).as_ref(),
+            hash.as_ref()
+        );
+    }
+
+    assert_ne!(
+        digest::tagged_hash("BIP0340/nonce", b"message").as_ref(),
+        digest::tagged_hash("BIP0340/aux", b"message").as_ref()
+    );
+}
+
+#[test]
+fn shake_test() {
+    // FIPS 202 known answers for the empty message and "abc".
+    let shake128_empty =
+        test::from_dirty_hex("7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26");
+    let shake256_empty = test::from_dirty_hex(
+        "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762f\
+         d75dc4ddd8c0f200cb05019d67b592f6fc821c49479ab48640292eacb3b7c4be",
+    );
+    let shake128_abc =
+        test::from_dirty_hex("5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc8");
+    let shake256_abc = test::from_dirty_hex(
+        "483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739\
+         d5a15bef186a5386c75744c0527e1faa9f8726e462a12a4feb06bd8801e751e4",
+    );
+
+    assert_eq!(shake128_empty, digest::shake128(b"", 32).unwrap());
+    assert_eq!(shake256_empty, digest::shake256(b"", 64).unwrap());
+    assert_eq!(shake128_abc, digest::shake128(b"abc", 32).unwrap());
+    assert_eq!(shake256_abc, digest::shake256(b"abc", 64).unwrap());
+
+    // Output lengths beyond the rate, and shorter outputs as prefixes of longer ones.
+    let long = digest::shake128(b"abc", 200).unwrap();
+    assert_eq!(
+        test::from_dirty_hex("6aa01b3f5af057805f973ff8ecb8b226ac32ada6f01c1fcd4818cb006aa5b4cd"),
+        &long[168..]
+    );
+    assert_eq!(shake128_abc, &long[..32]);
+    assert_eq!(&shake256_abc[..17], digest::shake256(b"abc", 17).unwrap());
+    assert!(digest::shake256(b"abc", 0).unwrap().is_empty());
+}
+
+#[test]
+fn digest_context_clone_and_reset_test() {
+    for alg in [&digest::SHA256, &digest::SHA384, &digest::SHA3_256] {
+        let mut prefix = digest::Context::new(alg);
+        prefix.update(b"foo");
+
+        let mut bar = prefix.clone();
+        let mut baz = prefix.clone();
+        bar.update(b"bar");
+        let bar = bar.finish();
+        baz.update(b"baz");
+        let baz = baz.finish();
+
+        assert_eq!(digest::digest(alg, b"foobar").as_ref(), bar.as_ref());
+        assert_eq!(digest::digest(alg, b"foobaz").as_ref(), baz.as_ref());
+        // Finishing the clones leaves the original untouched.
+        assert_eq!(
+            digest::digest(alg, b"foo").as_ref(),
+            prefix.clone().finish().as_ref()
+        );
+
+        prefix.reset();
+        assert_eq!(alg, prefix.algorithm());
+        prefix.update(b"qux");
+        assert_eq!(
+            digest::digest(alg, b"qux").as_ref(),
+            prefix.finish().as_ref()
+        );
+    }
+}
+
+#[test]
+fn digest_sha512_256_test() {
+    // FIPS 180-4 examples: the one- and two-block messages.
+    const ONE_BLOCK: &[u8] = b"abc";
+    const TWO_BLOCK: &[u8] = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmn\
+        hijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu";
+    let expected_one_block =
+        test::from_dirty_hex("53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e0e2f13107e7af23");
+    let expected_two_block =
+        test::from_dirty_hex("3928e184fb8690f840da3988121d31be65cb9d3ef83ee6146feac861e19b563a");
+
+    for (msg, expected) in [
+        (ONE_BLOCK, expected_one_block),
+        (TWO_BLOCK, expected_two_block),
+    ] {
+        let one_shot = digest::digest(&digest::SHA512_256, msg);
+        assert_eq!(expected, one_shot.as_ref());
+
+        let mut ctx = digest::Context::new(&digest::SHA512_256);
+        for byte in msg.chunks(1) {
+            ctx.update(byte);
+        }
+        assert_eq!(expected, ctx.finish().as_ref());
+
+        // SHA-512/256 uses its own initial hash value, so it is not a truncated SHA-512.
+        let sha512 = digest::digest(&digest::SHA512, msg);
+        assert_ne!(
+            &sha512.as_ref()[..digest::SHA512_256_OUTPUT_LEN],
+            one_shot.as_ref()
+        );
+    }
+
+    assert_eq!(
+        digest::SHA512_256_OUTPUT_LEN,
+        digest::SHA512_256.output_len()
+    );
+    assert_eq!(128, digest::SHA512_256.block_len());
+}
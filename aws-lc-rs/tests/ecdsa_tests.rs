@@ -3,10 +3,13 @@
 // Modifications copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
-use aws_lc_rs::encoding::{AsBigEndian, AsDer, EcPrivateKeyRfc5915Der};
+use aws_lc_rs::encoding::{
+    AsBigEndian, AsDer, EcPrivateKeyBin, EcPrivateKeyRfc5915Der, EcPublicKeyCompressedBin,
+    EcPublicKeyUncompressedBin, PublicKeyX509Der,
+};
 use aws_lc_rs::rand::SystemRandom;
 use aws_lc_rs::signature::{self, EcdsaKeyPair, KeyPair, Signature, UnparsedPublicKey};
-use aws_lc_rs::{test, test_file};
+use aws_lc_rs::{agreement, digest, test, test_file};
 
 #[test]
 fn ecdsa_traits() {
@@ -514,3 +517,1002 @@ fn test_private_key() {
         }
     }
 }
+
+#[test]
+fn test_sign_digest() {
+    const MESSAGE: &[u8] = b"message to be signed";
+
+    for (signing_alg, digest_alg) in [
+        (
+            &signature::ECDSA_P521_SHA3_512_ASN1_SIGNING,
+            &digest::SHA3_512,
+        ),
+        (&signature::ECDSA_P521_SHA512_FIXED_SIGNING, &digest::SHA512),
+        (
+            &signature::ECDSA_P384_SHA3_384_FIXED_SIGNING,
+            &digest::SHA3_384,
+        ),
+        (&signature::ECDSA_P384_SHA384_ASN1_SIGNING, &digest::SHA384),
+        (&signature::ECDSA_P256_SHA256_ASN1_SIGNING, &digest::SHA256),
+        (&signature::ECDSA_P256_SHA256_FIXED_SIGNING, &digest::SHA256),
+        (
+            &signature::ECDSA_P256K1_SHA256_FIXED_SIGNING,
+            &digest::SHA256,
+        ),
+    ] {
+        let rng = SystemRandom::new();
+        let key_pair = EcdsaKeyPair::generate(signing_alg).unwrap();
+        let public_key = UnparsedPublicKey::new(&**signing_alg, key_pair.public_key().as_ref());
+
+        let msg_digest = digest::digest(digest_alg, MESSAGE);
+        let digest_signature = key_pair.sign_digest(&rng, msg_digest.as_ref()).unwrap();
+        let message_signature = key_pair.sign(&rng, MESSAGE).unwrap();
+
+        // ECDSA nonces are random, so both signatures must verify against the message.
+        public_key
+            .verify(MESSAGE, digest_signature.as_ref())
+            .unwrap();
+        public_key
+            .verify(MESSAGE, message_signature.as_ref())
+            .unwrap();
+
+        // The digest length must match the algorithm's digest.
+        assert!(key_pair
+            .sign_digest(&rng, &msg_digest.as_ref()[1..])
+            .is_err());
+        assert!(key_pair.sign_digest(&rng, MESSAGE).is_err());
+    }
+}
+
+#[test]
+fn test_public_key_sizes() {
+    assert_eq!(
+        (33, 65),
+        signature::ECDSA_P256_SHA256_ASN1.public_key_sizes()
+    );
+    assert_eq!(
+        (33, 65),
+        signature::ECDSA_P256K1_SHA256_FIXED.public_key_sizes()
+    );
+    assert_eq!(
+        (49, 97),
+        signature::ECDSA_P384_SHA384_FIXED.public_key_sizes()
+    );
+    assert_eq!(
+        (67, 133),
+        signature::ECDSA_P521_SHA512_ASN1.public_key_sizes()
+    );
+    // Signing algorithms dereference to their verification algorithm.
+    assert_eq!(
+        (33, 65),
+        signature::ECDSA_P256_SHA256_FIXED_SIGNING.public_key_sizes()
+    );
+
+    for signing_alg in [
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+        &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+        &signature::ECDSA_P256K1_SHA256_ASN1_SIGNING,
+    ] {
+        let key_pair = EcdsaKeyPair::generate(signing_alg).unwrap();
+        let public_key = key_pair.public_key();
+        let compressed = AsBigEndian::<EcPublicKeyCompressedBin>::as_be_bytes(public_key).unwrap();
+        let (compressed_len, uncompressed_len) = signing_alg.public_key_sizes();
+        assert_eq!(compressed_len, compressed.as_ref().len());
+        assert_eq!(uncompressed_len, public_key.as_ref().len());
+    }
+}
+
+#[test]
+fn test_verify_public_key_consistency() {
+    for signing_alg in [
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+        &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+        &signature::ECDSA_P256K1_SHA256_FIXED_SIGNING,
+    ] {
+        let key_pair = EcdsaKeyPair::generate(signing_alg).unwrap();
+        assert_eq!(Ok(()), key_pair.verify_public_key_consistency());
+
+        let pkcs8 = key_pair.to_pkcs8v1().unwrap();
+        let key_pair = EcdsaKeyPair::from_pkcs8(signing_alg, pkcs8.as_ref()).unwrap();
+        assert_eq!(Ok(()), key_pair.verify_public_key_consistency());
+    }
+}
+
+#[test]
+fn test_verification_context_streaming() {
+    use std::io::Read;
+
+    const FILE_PATH: &str = "tests/data/ecdsa_test_private_key_p256.p8";
+
+    let rng = SystemRandom::new();
+    let message = std::fs::read(FILE_PATH).unwrap();
+
+    for (signing_alg, verification_alg) in [
+        (
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256_SHA256_ASN1,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+            &signature::ECDSA_P384_SHA384_FIXED,
+        ),
+        (
+            &signature::ECDSA_P521_SHA512_FIXED_SIGNING,
+            &signature::ECDSA_P521_SHA512_FIXED,
+        ),
+    ] {
+        let key_pair = EcdsaKeyPair::generate(signing_alg).unwrap();
+        let public_key = key_pair.public_key().as_ref();
+        let sig = key_pair.sign(&rng, &message).unwrap();
+
+        let mut file = std::fs::File::open(FILE_PATH).unwrap();
+        let mut context = verification_alg.verification_context(public_key).unwrap();
+        let mut chunk = [0u8; 16];
+        loop {
+            let len = file.read(&mut chunk).unwrap();
+            if len == 0 {
+                break;
+            }
+            context.update(&chunk[..len]).unwrap();
+        }
+        context.verify(sig.as_ref()).unwrap();
+
+        let mut context = verification_alg.verification_context(public_key).unwrap();
+        context.update(&message).unwrap();
+        context.update(b"trailing data").unwrap();
+        assert!(context.verify(sig.as_ref()).is_err());
+    }
+
+    // Keys for a different curve are rejected.
+    let key_pair = EcdsaKeyPair::generate(&signature::ECDSA_P256_SHA256_ASN1_SIGNING).unwrap();
+    assert!(signature::ECDSA_P384_SHA384_ASN1
+        .verification_context(key_pair.public_key().as_ref())
+        .is_err());
+}
+
+#[test]
+fn test_deterministic_ecdsa_key() {
+    for &(signing_alg, verification_alg) in &[
+        (
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256_SHA256_ASN1,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+            &signature::ECDSA_P384_SHA384_ASN1,
+        ),
+        (
+            &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+            &signature::ECDSA_P521_SHA512_ASN1,
+        ),
+        (
+            &signature::ECDSA_P256K1_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256K1_SHA256_ASN1,
+        ),
+    ] {
+        let key_pair = test::deterministic_ecdsa_key(signing_alg, "test_deterministic_ecdsa_key");
+        let same_label = test::deterministic_ecdsa_key(signing_alg, "test_deterministic_ecdsa_key");
+        let other_label = test::deterministic_ecdsa_key(signing_alg, "another label");
+
+        assert_eq!(
+            key_pair.public_key().as_ref(),
+            same_label.public_key().as_ref()
+        );
+        assert_ne!(
+            key_pair.public_key().as_ref(),
+            other_label.public_key().as_ref()
+        );
+
+        let message = b"deterministic";
+        let sig = key_pair.sign(&SystemRandom::new(), message).unwrap();
+        UnparsedPublicKey::new(verification_alg, same_label.public_key())
+            .verify(message, sig.as_ref())
+            .unwrap();
+    }
+}
+
+#[test]
+fn test_verify_reader() {
+    const FILE_PATH: &str = "tests/data/ecdsa_sign_asn1_tests.txt";
+
+    let message = std::fs::read(FILE_PATH).unwrap();
+    let rng = SystemRandom::new();
+
+    for (signing_alg, verification_alg) in [
+        (
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256_SHA256_ASN1,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+            &signature::ECDSA_P384_SHA384_FIXED,
+        ),
+    ] {
+        let key_pair = EcdsaKeyPair::generate(signing_alg).unwrap();
+        let public_key = key_pair.public_key().as_ref();
+        let sig = key_pair.sign(&rng, &message).unwrap();
+
+        let buffered =
+            UnparsedPublicKey::new(verification_alg, public_key).verify(&message, sig.as_ref());
+        let streamed = signature::verify_reader(
+            verification_alg,
+            public_key,
+            std::fs::File::open(FILE_PATH).unwrap(),
+            sig.as_ref(),
+        );
+        assert!(buffered.is_ok());
+        assert_eq!(buffered, streamed);
+
+        let truncated = &message[..message.len() - 1];
+        let buffered =
+            UnparsedPublicKey::new(verification_alg, public_key).verify(truncated, sig.as_ref());
+        let streamed =
+            signature::verify_reader(verification_alg, public_key, truncated, sig.as_ref());
+        assert!(buffered.is_err());
+        assert_eq!(buffered, streamed);
+    }
+}
+
+#[test]
+fn test_public_key_from_raw_coordinates() {
+    const MESSAGE: &[u8] = b"legacy key format";
+
+    let key_pair = EcdsaKeyPair::generate(&signature::ECDSA_P256_SHA256_ASN1_SIGNING).unwrap();
+    let sig = key_pair.sign(&SystemRandom::new(), MESSAGE).unwrap();
+    let public_key = key_pair.public_key().as_ref();
+    assert_eq!(0x04, public_key[0]);
+    let xy = &public_key[1..];
+    assert_eq!(64, xy.len());
+
+    let parsed = signature::ECDSA_P256_SHA256_ASN1
+        .public_key_from_raw_coordinates(xy)
+        .unwrap();
+    assert_eq!(public_key, parsed.as_slice());
+    UnparsedPublicKey::new(&signature::ECDSA_P256_SHA256_ASN1, &parsed)
+        .verify(MESSAGE, sig.as_ref())
+        .unwrap();
+
+    // Wrong length for the curve.
+    assert!(signature::ECDSA_P384_SHA384_ASN1
+        .public_key_from_raw_coordinates(xy)
+        .is_err());
+    assert!(signature::ECDSA_P256_SHA256_ASN1
+        .public_key_from_raw_coordinates(public_key)
+        .is_err());
+
+    // Not a point on the curve.
+    let mut off_curve = xy.to_vec();
+    off_curve[63] ^= 0x01;
+    assert!(signature::ECDSA_P256_SHA256_ASN1
+        .public_key_from_raw_coordinates(&off_curve)
+        .is_err());
+}
+
+#[test]
+fn test_zero_signature_components_rejected() {
+    const MESSAGE: &[u8] = b"zero signature";
+
+    let key_pair = EcdsaKeyPair::generate(&signature::ECDSA_P256_SHA256_FIXED_SIGNING).unwrap();
+    let public_key = key_pair.public_key().as_ref();
+    let sig = key_pair.sign(&SystemRandom::new(), MESSAGE).unwrap();
+    let fixed = UnparsedPublicKey::new(&signature::ECDSA_P256_SHA256_FIXED, public_key);
+    fixed.verify(MESSAGE, sig.as_ref()).unwrap();
+
+    let (r, s) = sig.as_ref().split_at(32);
+    for crafted in [[&[0u8; 32][..], s].concat(), [r, &[0u8; 32][..]].concat()] {
+        assert!(fixed.verify(MESSAGE, &crafted).is_err());
+    }
+
+    // SEQUENCE { INTEGER r, INTEGER s } with one component zero.
+    let asn1 = UnparsedPublicKey::new(&signature::ECDSA_P256_SHA256_ASN1, public_key);
+    for crafted in [
+        [0x30, 0x06, 0x02, 0x01, 0x00, 0x02, 0x01, 0x01],
+        [0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x00],
+        [0x30, 0x06, 0x02, 0x01, 0x00, 0x02, 0x01, 0x00],
+    ] {
+        assert!(asn1.verify(MESSAGE, &crafted).is_err());
+    }
+}
+
+#[test]
+fn test_p521_public_key_coordinate_padding() {
+    // A P-521 key whose X coordinate has three leading zero bytes when encoded in 66 bytes.
+    const PKCS8: &str = "3081ee020100301006072a8648ce3d020106052b810400230481d63081d302010104420062d88ad6d72e46dc90dcddec987acbaa09a7ee2ca04b6c595b44d3233d43e36d7b3cfb777e5186176f7be8fbf46401164505a862536d5922ebc8d638d12db7b671a1818903818600040000000758377019d8c41c379aec81ad2a748488f39d1e8f17d6a2ffa92fa8c51ce78a63f3e26c241e9c0354345c6a762981ac4eba951ad4e2954508a7993e948bc5004278ebd1e11fbc01b846401a1f2e01875bec4a7d50b01f568bf0dbbecae1c8c0209bb2fc334aec549cd3c19140d86cc5ce3fa19c7e48c52d9634868298ff5b84ce";
+    const PRIVATE_KEY: &str = "0062d88ad6d72e46dc90dcddec987acbaa09a7ee2ca04b6c595b44d3233d43e36d7b3cfb777e5186176f7be8fbf46401164505a862536d5922ebc8d638d12db7b671";
+    const PUBLIC_KEY: &str = "040000000758377019d8c41c379aec81ad2a748488f39d1e8f17d6a2ffa92fa8c51ce78a63f3e26c241e9c0354345c6a762981ac4eba951ad4e2954508a7993e948bc5004278ebd1e11fbc01b846401a1f2e01875bec4a7d50b01f568bf0dbbecae1c8c0209bb2fc334aec549cd3c19140d86cc5ce3fa19c7e48c52d9634868298ff5b84ce";
+
+    let expected = test::from_hex(PUBLIC_KEY).unwrap();
+    assert_eq!(1 + 2 * 66, expected.len());
+    assert_eq!([0x04, 0x00, 0x00, 0x00], expected[..4]);
+
+    let key_pair = EcdsaKeyPair::from_pkcs8(
+        &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+        &test::from_hex(PKCS8).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(expected, key_pair.public_key().as_ref());
+    let uncompressed: EcPublicKeyUncompressedBin = key_pair.public_key().as_be_bytes().unwrap();
+    assert_eq!(expected, uncompressed.as_ref());
+
+    let (_, uncompressed_len) = signature::ECDSA_P521_SHA512_ASN1.public_key_sizes();
+    assert_eq!(expected.len(), uncompressed_len);
+    assert_eq!(
+        expected,
+        signature::ECDSA_P521_SHA512_ASN1
+            .public_key_from_raw_coordinates(&expected[1..])
+            .unwrap()
+    );
+
+    let private_key = agreement::PrivateKey::from_private_key(
+        &agreement::ECDH_P521,
+        &test::from_hex(PRIVATE_KEY).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(expected, private_key.compute_public_key().unwrap().as_ref());
+}
+
+#[test]
+fn test_from_private_key_and_public_key_mismatch() {
+    let alg = &signature::ECDSA_P256_SHA256_ASN1_SIGNING;
+    let key_pair = EcdsaKeyPair::generate(alg).unwrap();
+    let private_key = key_pair.private_key().as_be_bytes().unwrap();
+    let public_key = key_pair.public_key().as_ref();
+
+    let key_pair_copy =
+        EcdsaKeyPair::from_private_key_and_public_key(alg, private_key.as_ref(), public_key)
+            .unwrap();
+    assert_eq!(public_key, key_pair_copy.public_key().as_ref());
+
+    // A valid public key for the curve that belongs to a different private key.
+    let other_key_pair = EcdsaKeyPair::generate(alg).unwrap();
+    assert!(EcdsaKeyPair::from_private_key_and_public_key(
+        alg,
+        private_key.as_ref(),
+        other_key_pair.public_key().as_ref(),
+    )
+    .is_err());
+
+    // A point that is not on the curve.
+    let mut off_curve = public_key.to_vec();
+    off_curve[64] ^= 0x01;
+    assert!(
+        EcdsaKeyPair::from_private_key_and_public_key(alg, private_key.as_ref(), &off_curve)
+            .is_err()
+    );
+
+    // A public key for a different curve.
+    let p384_key_pair = EcdsaKeyPair::generate(&signature::ECDSA_P384_SHA384_ASN1_SIGNING).unwrap();
+    assert!(EcdsaKeyPair::from_private_key_and_public_key(
+        alg,
+        private_key.as_ref(),
+        p384_key_pair.public_key().as_ref(),
+    )
+    .is_err());
+}
+
+#[test]
+fn test_secp256k1_sign_and_public_key_encodings() {
+    let rng = SystemRandom::new();
+    for (signing_alg, verification_alg) in [
+        (
+            &signature::ECDSA_P256K1_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256K1_SHA256_ASN1,
+        ),
+        (
+            &signature::ECDSA_P256K1_SHA256_FIXED_SIGNING,
+            &signature::ECDSA_P256K1_SHA256_FIXED,
+        ),
+    ] {
+        let key_pair = EcdsaKeyPair::generate(signing_alg).unwrap();
+        let sig = key_pair.sign(&rng, b"").unwrap();
+
+        let raw = key_pair.public_key().as_ref().to_vec();
+        let compressed: EcPublicKeyCompressedBin = key_pair.public_key().as_be_bytes().unwrap();
+        let x509: PublicKeyX509Der = key_pair.public_key().as_der().unwrap();
+        assert_eq!(65, raw.len());
+        assert_eq!(33, compressed.as_ref().len());
+        for public_key in [raw.as_slice(), compressed.as_ref(), x509.as_ref()] {
+            UnparsedPublicKey::new(verification_alg, public_key)
+                .verify(b"", sig.as_ref())
+                .unwrap();
+        }
+
+        // A P-256 key is not accepted where a secp256k1 key is expected.
+        let p256_key_pair =
+            EcdsaKeyPair::generate(&signature::ECDSA_P256_SHA256_ASN1_SIGNING).unwrap();
+        let p256_sig = p256_key_pair.sign(&rng, b"").unwrap();
+        assert!(UnparsedPublicKey::new(
+            &signature::ECDSA_P256K1_SHA256_ASN1,
+            p256_key_pair.public_key().as_ref()
+        )
+        .verify(b"", p256_sig.as_ref())
+        .is_err());
+        let p256_pkcs8 =
+            EcdsaKeyPair::generate_pkcs8(&signature::ECDSA_P256_SHA256_ASN1_SIGNING, &rng).unwrap();
+        assert!(EcdsaKeyPair::from_pkcs8(signing_alg, p256_pkcs8.as_ref()).is_err());
+    }
+}
+
+#[test]
+fn test_recover_ecdsa_public_key() {
+    let rng = SystemRandom::new();
+    for (signing_alg, verification_alg, digest_alg) in [
+        (
+            &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+            &signature::ECDSA_P256_SHA256_FIXED,
+            &digest::SHA256,
+        ),
+        (
+            &signature::ECDSA_P256K1_SHA256_FIXED_SIGNING,
+            &signature::ECDSA_P256K1_SHA256_FIXED,
+            &digest::SHA256,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+            &signature::ECDSA_P384_SHA384_FIXED,
+            &digest::SHA384,
+        ),
+        (
+            &signature::ECDSA_P521_SHA512_FIXED_SIGNING,
+            &signature::ECDSA_P521_SHA512_FIXED,
+            &digest::SHA512,
+        ),
+    ] {
+        let key_pair = EcdsaKeyPair::generate(signing_alg).unwrap();
+        let message = b"recover the signer";
+        let sig = key_pair.sign(&rng, message).unwrap();
+        let digest = digest::digest(digest_alg, message);
+
+        let recovered: Vec<_> = (0..4)
+            .filter_map(|recovery_id| {
+                signature::recover_ecdsa_public_key(
+                    verification_alg,
+                    digest.as_ref(),
+                    sig.as_ref(),
+                    recovery_id,
+                )
+                .ok()
+            })
+            .filter(|public_key| public_key.as_ref() == key_pair.public_key().as_ref())
+            .collect();
+        assert_eq!(1, recovered.len());
+
+        // A recovered key for another message is a different key.
+        let other_digest = digest::digest(digest_alg, b"another message");
+        for recovery_id in 0..4 {
+            if let Ok(public_key) = signature::recover_ecdsa_public_key(
+                verification_alg,
+                other_digest.as_ref(),
+                sig.as_ref(),
+                recovery_id,
+            ) {
+                assert_ne!(key_pair.public_key().as_ref(), public_key.as_ref());
+            }
+        }
+
+        assert!(signature::recover_ecdsa_public_key(
+            verification_alg,
+            digest.as_ref(),
+            sig.as_ref(),
+            4
+        )
+        .is_err());
+        assert!(signature::recover_ecdsa_public_key(
+            verification_alg,
+            &digest.as_ref()[1..],
+            sig.as_ref(),
+            0
+        )
+        .is_err());
+        assert!(signature::recover_ecdsa_public_key(
+            verification_alg,
+            digest.as_ref(),
+            &sig.as_ref()[1..],
+            0
+        )
+        .is_err());
+    }
+
+    // `r` and `s` must be in the range [1, n - 1].
+    let digest = digest::digest(&digest::SHA256, b"");
+    let mut sig = [0x01u8; 64];
+    sig[..32].fill(0);
+    assert!(signature::recover_ecdsa_public_key(
+        &signature::ECDSA_P256_SHA256_FIXED,
+        digest.as_ref(),
+        &sig,
+        0
+    )
+    .is_err());
+    sig[..32].fill(0xff);
+    assert!(signature::recover_ecdsa_public_key(
+        &signature::ECDSA_P256K1_SHA256_FIXED,
+        digest.as_ref(),
+        &sig,
+        0
+    )
+    .is_err());
+}
+
+#[test]
+fn test_ecdsa_curve_from_name() {
+    let p256 = signature::ecdsa_curve_from_name("P-256").unwrap();
+    assert_eq!("P-256", p256.name());
+    assert_eq!(
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        p256.signing_algorithm()
+    );
+    for alias in ["p-256", "P256", "secp256r1", "prime256v1", "PRIME256V1"] {
+        assert_eq!(Some(p256), signature::ecdsa_curve_from_name(alias));
+    }
+
+    let p384 = signature::ecdsa_curve_from_name("secp384r1").unwrap();
+    assert_eq!(Some(p384), signature::ecdsa_curve_from_name("P-384"));
+    assert_ne!(p256, p384);
+    let p521 = signature::ecdsa_curve_from_name("secp521r1").unwrap();
+    assert_eq!(Some(p521), signature::ecdsa_curve_from_name("p521"));
+    let secp256k1 = signature::ecdsa_curve_from_name("secp256k1").unwrap();
+    assert_eq!(Some(secp256k1), signature::ecdsa_curve_from_name("P-256K"));
+    assert_ne!(p256, secp256k1);
+
+    for unknown in ["", "P-224", "secp256", "P-256 ", "curve25519"] {
+        assert_eq!(None, signature::ecdsa_curve_from_name(unknown));
+    }
+}
+
+#[test]
+fn test_ecdsa_generate_named() {
+    let rng = SystemRandom::new();
+    for (name, verification_alg) in [
+        ("prime256v1", &signature::ECDSA_P256_SHA256_ASN1),
+        ("P-384", &signature::ECDSA_P384_SHA384_ASN1),
+        ("secp521r1", &signature::ECDSA_P521_SHA512_ASN1),
+        ("secp256k1", &signature::ECDSA_P256K1_SHA256_ASN1),
+    ] {
+        let key_pair = EcdsaKeyPair::generate_named(name).unwrap();
+        let sig = key_pair.sign(&rng, b"named curve").unwrap();
+        UnparsedPublicKey::new(verification_alg, key_pair.public_key())
+            .verify(b"named curve", sig.as_ref())
+            .unwrap();
+    }
+    assert!(EcdsaKeyPair::generate_named("P-192").is_err());
+}
+
+#[test]
+fn test_validate_pkcs8() {
+    let p256_pkcs8 = include_bytes!("data/ecdsa_test_private_key_p256.p8");
+    let ed25519_pkcs8 = include_bytes!("data/ed25519_test_private_key.p8");
+
+    signature::validate_pkcs8(&signature::ECDSA_P256_SHA256_ASN1_SIGNING, p256_pkcs8).unwrap();
+    signature::validate_pkcs8(&signature::ECDSA_P256_SHA256_FIXED_SIGNING, p256_pkcs8).unwrap();
+    signature::validate_pkcs8(&signature::ED25519, ed25519_pkcs8).unwrap();
+
+    // A key for a different curve or algorithm.
+    assert_eq!(
+        "WrongAlgorithm",
+        signature::validate_pkcs8(&signature::ECDSA_P384_SHA384_ASN1_SIGNING, p256_pkcs8)
+            .unwrap_err()
+            .description_()
+    );
+    assert!(signature::validate_pkcs8(&signature::ED25519, p256_pkcs8).is_err());
+    assert!(
+        signature::validate_pkcs8(&signature::ECDSA_P256_SHA256_ASN1_SIGNING, ed25519_pkcs8)
+            .is_err()
+    );
+
+    // A truncated document.
+    assert!(signature::validate_pkcs8(
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        &p256_pkcs8[..p256_pkcs8.len() - 1]
+    )
+    .is_err());
+    assert!(signature::validate_pkcs8(&signature::ED25519, &ed25519_pkcs8[..16]).is_err());
+    assert!(signature::validate_pkcs8(&signature::ECDSA_P256_SHA256_ASN1_SIGNING, &[]).is_err());
+}
+
+#[test]
+fn test_ecdsa_curve_parameters() {
+    let p256 = signature::ECDSA_P256_SHA256_ASN1.curve();
+    assert_eq!("P-256", p256.name());
+    assert_eq!(32, p256.field_size_bytes());
+    assert_eq!(1, p256.cofactor().unwrap());
+    assert_eq!(
+        test::from_dirty_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"),
+        p256.order_bytes().unwrap()
+    );
+
+    for (alg, name, field_size_bytes) in [
+        (&signature::ECDSA_P256_SHA256_FIXED, "P-256", 32),
+        (&signature::ECDSA_P384_SHA256_ASN1, "P-384", 48),
+        (&signature::ECDSA_P521_SHA512_FIXED, "P-521", 66),
+        (&signature::ECDSA_P256K1_SHA256_ASN1, "secp256k1", 32),
+    ] {
+        let curve = alg.curve();
+        assert_eq!(name, curve.name());
+        assert_eq!(Some(curve), signature::ecdsa_curve_from_name(name));
+        assert_eq!(field_size_bytes, curve.field_size_bytes());
+        assert_eq!(field_size_bytes, curve.order_bytes().unwrap().len());
+        assert_eq!(1, curve.cofactor().unwrap());
+        assert_eq!(
+            (1 + field_size_bytes, 1 + 2 * field_size_bytes),
+            alg.public_key_sizes()
+        );
+    }
+}
+
+#[test]
+fn test_private_key_be_bytes_is_zeroizing_buffer() {
+    let key_pair = EcdsaKeyPair::generate(&signature::ECDSA_P384_SHA384_ASN1_SIGNING).unwrap();
+    let scalar: EcPrivateKeyBin = key_pair.private_key().as_be_bytes().unwrap();
+    assert_eq!(48, scalar.as_ref().len());
+    let round_trip = EcdsaKeyPair::from_private_key_and_public_key(
+        &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+        scalar.as_ref(),
+        key_pair.public_key().as_ref(),
+    )
+    .unwrap();
+    assert_eq!(
+        key_pair.public_key().as_ref(),
+        round_trip.public_key().as_ref()
+    );
+
+    // The buffer has drop glue, which zeroizes it, so it cannot be `Copy`.
+    assert!(core::mem::needs_drop::<EcPrivateKeyBin<'static>>());
+    // Debug output does not reveal the scalar.
+    assert_eq!("EcPrivateKeyBin", format!("{scalar:?}"));
+}
+
+#[test]
+fn test_ecdsa_from_private_scalar() {
+    for alg in [
+        &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+        &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+        &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+        &signature::ECDSA_P256K1_SHA256_ASN1_SIGNING,
+    ] {
+        let key_pair = EcdsaKeyPair::generate(alg).unwrap();
+        let scalar: EcPrivateKeyBin = key_pair.private_key().as_be_bytes().unwrap();
+        let imported = EcdsaKeyPair::from_private_scalar(alg, scalar.as_ref()).unwrap();
+        assert_eq!(
+            key_pair.public_key().as_ref(),
+            imported.public_key().as_ref()
+        );
+        let reexported: EcPrivateKeyBin = imported.private_key().as_be_bytes().unwrap();
+        assert_eq!(scalar.as_ref(), reexported.as_ref());
+
+        // The scalar must be exactly the curve's private key length.
+        let mut padded = vec![0u8];
+        padded.extend_from_slice(scalar.as_ref());
+        assert!(EcdsaKeyPair::from_private_scalar(alg, &padded).is_err());
+        assert!(EcdsaKeyPair::from_private_scalar(alg, &scalar.as_ref()[1..]).is_err());
+
+        // The scalar must be in the range [1, n - 1].
+        let field_size_bytes = alg.curve().field_size_bytes();
+        let order = alg.curve().order_bytes().unwrap();
+        assert!(EcdsaKeyPair::from_private_scalar(alg, &vec![0u8; field_size_bytes]).is_err());
+        assert!(EcdsaKeyPair::from_private_scalar(alg, &order).is_err());
+        assert!(EcdsaKeyPair::from_private_scalar(alg, &vec![0xffu8; field_size_bytes]).is_err());
+        let mut order_minus_one = order.clone();
+        *order_minus_one.last_mut().unwrap() -= 1;
+        EcdsaKeyPair::from_private_scalar(alg, &order_minus_one).unwrap();
+        let mut one = vec![0u8; field_size_bytes];
+        *one.last_mut().unwrap() = 1;
+        EcdsaKeyPair::from_private_scalar(alg, &one).unwrap();
+    }
+}
+
+#[test]
+fn test_ecdsa_curve_point_compression() {
+    let curve = signature::ECDSA_P256_SHA256_ASN1.curve();
+    let key_pair = EcdsaKeyPair::generate(&signature::ECDSA_P256_SHA256_ASN1_SIGNING).unwrap();
+    let uncompressed = key_pair.public_key().as_ref();
+    let expected_compressed: EcPublicKeyCompressedBin =
+        key_pair.public_key().as_be_bytes().unwrap();
+
+    let compressed = curve.compress_point(uncompressed).unwrap();
+    assert_eq!(expected_compressed.as_ref(), compressed.as_slice());
+    assert_eq!(
+        uncompressed,
+        curve.decompress_point(&compressed).unwrap().as_slice()
+    );
+
+    // Each function only accepts its own input form.
+    assert!(curve.compress_point(&compressed).is_err());
+    assert!(curve.decompress_point(uncompressed).is_err());
+    let mut wrong_prefix = compressed.clone();
+    wrong_prefix[0] = 0x04;
+    assert!(curve.decompress_point(&wrong_prefix).is_err());
+    let mut wrong_prefix = uncompressed.to_vec();
+    wrong_prefix[0] = 0x02;
+    assert!(curve.compress_point(&wrong_prefix).is_err());
+
+    // Points on another curve have the wrong length.
+    let p384 = signature::ECDSA_P384_SHA384_ASN1.curve();
+    assert!(p384.compress_point(uncompressed).is_err());
+    assert!(p384.decompress_point(&compressed).is_err());
+
+    // Points that are not on the curve are rejected.
+    let mut off_curve = uncompressed.to_vec();
+    *off_curve.last_mut().unwrap() ^= 1;
+    assert!(curve.compress_point(&off_curve).is_err());
+    assert!((0u8..32).any(|b| {
+        let mut candidate = compressed.clone();
+        *candidate.last_mut().unwrap() = b;
+        curve.decompress_point(&candidate).is_err()
+    }));
+}
+
+#[test]
+fn test_ecdsa_curve_clear_cofactor() {
+    let curve = signature::ECDSA_P256_SHA256_ASN1.curve();
+    assert_eq!(1, curve.cofactor().unwrap());
+    let key_pair = EcdsaKeyPair::generate(&signature::ECDSA_P256_SHA256_ASN1_SIGNING).unwrap();
+    let uncompressed = key_pair.public_key().as_ref();
+    let compressed = curve.compress_point(uncompressed).unwrap();
+
+    // With a cofactor of 1 the point is returned unchanged, in the form it was given.
+    assert_eq!(
+        uncompressed,
+        curve.clear_cofactor(uncompressed).unwrap().as_slice()
+    );
+    assert_eq!(compressed, curve.clear_cofactor(&compressed).unwrap());
+
+    let mut off_curve = uncompressed.to_vec();
+    *off_curve.last_mut().unwrap() ^= 1;
+    assert!(curve.clear_cofactor(&off_curve).is_err());
+    assert!(curve.clear_cofactor(&[0x00]).is_err());
+    assert!(curve.clear_cofactor(&[]).is_err());
+}
+
+#[test]
+fn test_ecdsa_private_key_equals() {
+    let alg = &signature::ECDSA_P256_SHA256_FIXED_SIGNING;
+    let key_pair = EcdsaKeyPair::generate(alg).unwrap();
+    let scalar: EcPrivateKeyBin = key_pair.private_key().as_be_bytes().unwrap();
+
+    assert!(key_pair.private_key_equals(&key_pair));
+    let same = EcdsaKeyPair::from_private_scalar(alg, scalar.as_ref()).unwrap();
+    assert!(key_pair.private_key_equals(&same));
+    assert!(same.private_key_equals(&key_pair));
+
+    // The signing algorithm does not affect the private key.
+    let same_asn1 = EcdsaKeyPair::from_private_scalar(
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        scalar.as_ref(),
+    )
+    .unwrap();
+    assert!(key_pair.private_key_equals(&same_asn1));
+
+    let other = EcdsaKeyPair::generate(alg).unwrap();
+    assert!(!key_pair.private_key_equals(&other));
+
+    // The same scalar on a curve with a longer encoding is not equal, even though both
+    // scalars are padded to the same length before comparison.
+    let mut padded = vec![0u8; 16];
+    padded.extend_from_slice(scalar.as_ref());
+    let p384 =
+        EcdsaKeyPair::from_private_scalar(&signature::ECDSA_P384_SHA384_FIXED_SIGNING, &padded)
+            .unwrap();
+    assert!(!key_pair.private_key_equals(&p384));
+    assert!(!p384.private_key_equals(&key_pair));
+
+    // The same scalar on another curve with the same encoding length is not equal.
+    let p256k1 = EcdsaKeyPair::from_private_scalar(
+        &signature::ECDSA_P256K1_SHA256_FIXED_SIGNING,
+        scalar.as_ref(),
+    );
+    if let Ok(p256k1) = p256k1 {
+        assert!(!key_pair.private_key_equals(&p256k1));
+    }
+}
+
+#[test]
+fn test_ecdsa_public_key_matches() {
+    let key_pair = EcdsaKeyPair::generate(&signature::ECDSA_P256_SHA256_ASN1_SIGNING).unwrap();
+    let public_key = key_pair.public_key();
+    let x509: PublicKeyX509Der = public_key.as_der().unwrap();
+    let compressed: EcPublicKeyCompressedBin = public_key.as_be_bytes().unwrap();
+
+    assert!(key_pair.public_key_matches(public_key.as_ref()));
+    assert!(key_pair.public_key_matches(x509.as_ref()));
+    assert!(key_pair.public_key_matches(compressed.as_ref()));
+
+    let other = EcdsaKeyPair::generate(&signature::ECDSA_P256_SHA256_ASN1_SIGNING).unwrap();
+    let other_x509: PublicKeyX509Der = other.public_key().as_der().unwrap();
+    assert!(!key_pair.public_key_matches(other.public_key().as_ref()));
+    assert!(!key_pair.public_key_matches(other_x509.as_ref()));
+
+    // A key on another curve never matches.
+    let p384 = EcdsaKeyPair::generate(&signature::ECDSA_P384_SHA384_ASN1_SIGNING).unwrap();
+    assert!(!key_pair.public_key_matches(p384.public_key().as_ref()));
+
+    assert!(!key_pair.public_key_matches(&[]));
+    assert!(!key_pair.public_key_matches(&public_key.as_ref()[1..]));
+}
+
+#[test]
+fn test_ecdsa_signing_context() {
+    let rng = SystemRandom::new();
+    let message = b"a message that is signed in three chunks";
+
+    for (signing_alg, verification_alg) in [
+        (
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256_SHA256_ASN1,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+            &signature::ECDSA_P384_SHA384_FIXED,
+        ),
+    ] {
+        let key_pair = EcdsaKeyPair::generate(signing_alg).unwrap();
+        let public_key = UnparsedPublicKey::new(verification_alg, key_pair.public_key());
+
+        let mut context = key_pair.signing_context().unwrap();
+        context.update(&message[..10]).unwrap();
+        context.update(&message[10..25]).unwrap();
+        context.update(&message[25..]).unwrap();
+        let chunked = context.finish().unwrap();
+
+        // ECDSA signatures are randomized, so the signatures are compared by verifying both
+        // against the whole message.
+        let one_shot = key_pair.sign(&rng, message).unwrap();
+        public_key.verify(message, one_shot.as_ref()).unwrap();
+        public_key.verify(message, chunked.as_ref()).unwrap();
+        assert!(public_key.verify(&message[1..], chunked.as_ref()).is_err());
+
+        // A context without any updates signs the empty message.
+        let empty = key_pair.signing_context().unwrap().finish().unwrap();
+        public_key.verify(b"", empty.as_ref()).unwrap();
+    }
+}
+
+#[test]
+fn test_webcrypto_signature_conversion() {
+    const MESSAGE: &[u8] = b"WebCrypto";
+    let rng = SystemRandom::new();
+
+    for (signing_alg, fixed_alg, asn1_alg, webcrypto_len) in [
+        (
+            &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+            &signature::ECDSA_P256_SHA256_FIXED,
+            &signature::ECDSA_P256_SHA256_ASN1,
+            64,
+        ),
+        (
+            &signature::ECDSA_P521_SHA512_FIXED_SIGNING,
+            &signature::ECDSA_P521_SHA512_FIXED,
+            &signature::ECDSA_P521_SHA512_ASN1,
+            132,
+        ),
+    ] {
+        let key_pair = EcdsaKeyPair::generate(signing_alg).unwrap();
+        let public_key = key_pair.public_key().as_ref();
+
+        // Keep signing until `r` has a leading zero byte, which the ASN.1 encoding drops and
+        // the WebCrypto encoding must restore.
+        let webcrypto = loop {
+            let sig = key_pair.sign(&rng, MESSAGE).unwrap();
+            assert_eq!(webcrypto_len, sig.as_ref().len());
+            if sig.as_ref()[0] == 0 {
+                break sig;
+            }
+        };
+
+        let asn1 = signature::webcrypto_to_asn1(fixed_alg, webcrypto.as_ref()).unwrap();
+        UnparsedPublicKey::new(asn1_alg, public_key)
+            .verify(MESSAGE, asn1.as_ref())
+            .unwrap();
+        for alg in [fixed_alg, asn1_alg] {
+            let round_trip = signature::asn1_to_webcrypto(alg, asn1.as_ref()).unwrap();
+            assert_eq!(webcrypto.as_ref(), round_trip.as_ref());
+        }
+
+        assert!(
+            signature::webcrypto_to_asn1(fixed_alg, &webcrypto.as_ref()[..webcrypto_len - 1])
+                .is_err()
+        );
+        assert!(signature::webcrypto_to_asn1(fixed_alg, &vec![0u8; webcrypto_len]).is_err());
+        assert!(signature::asn1_to_webcrypto(fixed_alg, webcrypto.as_ref()).is_err());
+    }
+
+    // An ASN.1 signature from a larger curve does not fit the P-256 encoding.
+    let p521_key_pair = EcdsaKeyPair::generate(&signature::ECDSA_P521_SHA512_ASN1_SIGNING).unwrap();
+    let p521_sig = loop {
+        let sig = p521_key_pair.sign(&rng, MESSAGE).unwrap();
+        let webcrypto =
+            signature::asn1_to_webcrypto(&signature::ECDSA_P521_SHA512_ASN1, sig.as_ref()).unwrap();
+        if webcrypto.as_ref()[0] == 1 {
+            break sig;
+        }
+    };
+    assert!(
+        signature::asn1_to_webcrypto(&signature::ECDSA_P256_SHA256_ASN1, p521_sig.as_ref())
+            .is_err()
+    );
+}
+
+#[test]
+fn test_ecdsa_fips_checked_key_parsing() {
+    let rng = SystemRandom::new();
+    for (alg, verification_alg) in [
+        (
+            &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+            &signature::ECDSA_P256_SHA256_FIXED,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+            &signature::ECDSA_P384_SHA384_FIXED,
+        ),
+        (
+            &signature::ECDSA_P521_SHA512_FIXED_SIGNING,
+            &signature::ECDSA_P521_SHA512_FIXED,
+        ),
+    ] {
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(alg, &rng).unwrap();
+
+        // A well-formed key passes both the regular and the FIPS key check.
+        let key_pair = EcdsaKeyPair::from_pkcs8(alg, pkcs8.as_ref()).unwrap();
+        let fips_key_pair = EcdsaKeyPair::from_pkcs8_fips_checked(alg, pkcs8.as_ref()).unwrap();
+        assert_eq!(
+            key_pair.public_key().as_ref(),
+            fips_key_pair.public_key().as_ref()
+        );
+
+        let der: EcPrivateKeyRfc5915Der = key_pair.private_key().as_der().unwrap();
+        let der_key_pair =
+            EcdsaKeyPair::from_private_key_der_fips_checked(alg, der.as_ref()).unwrap();
+        assert_eq!(
+            key_pair.public_key().as_ref(),
+            der_key_pair.public_key().as_ref()
+        );
+
+        let message = b"fips checked key";
+        let signature = fips_key_pair.sign(&rng, message).unwrap();
+        UnparsedPublicKey::new(verification_alg, key_pair.public_key().as_ref())
+            .verify(message, signature.as_ref())
+            .unwrap();
+    }
+
+    let pkcs8 =
+        EcdsaKeyPair::generate_pkcs8(&signature::ECDSA_P256_SHA256_FIXED_SIGNING, &rng).unwrap();
+    assert!(EcdsaKeyPair::from_pkcs8_fips_checked(
+        &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+        pkcs8.as_ref()
+    )
+    .is_err());
+    assert!(EcdsaKeyPair::from_pkcs8_fips_checked(
+        &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+        &pkcs8.as_ref()[1..]
+    )
+    .is_err());
+
+    // Keys whose embedded public key is not on the curve, or does not belong to the private
+    // key, are rejected by both the regular and the FIPS key check.
+    let alg = &signature::ECDSA_P256_SHA256_FIXED_SIGNING;
+    let public_key = EcdsaKeyPair::from_pkcs8(alg, pkcs8.as_ref())
+        .unwrap()
+        .public_key()
+        .as_ref()
+        .to_vec();
+    let public_key_start = pkcs8
+        .as_ref()
+        .windows(public_key.len())
+        .position(|window| window == public_key.as_slice())
+        .unwrap();
+    let public_key_range = public_key_start..public_key_start + public_key.len();
+
+    let mut off_curve = pkcs8.as_ref().to_vec();
+    off_curve[public_key_range.end - 1] ^= 0x01;
+
+    let other_key_pair = EcdsaKeyPair::generate(alg).unwrap();
+    let mut mismatched = pkcs8.as_ref().to_vec();
+    mismatched[public_key_range].copy_from_slice(other_key_pair.public_key().as_ref());
+
+    for malformed in [off_curve, mismatched] {
+        assert!(EcdsaKeyPair::from_pkcs8(alg, &malformed).is_err());
+        assert!(EcdsaKeyPair::from_pkcs8_fips_checked(alg, &malformed).is_err());
+    }
+}
@@ -3,7 +3,7 @@
 // Modifications copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
-use aws_lc_rs::encoding::{AsBigEndian, AsDer, Curve25519SeedBin};
+use aws_lc_rs::encoding::{AsBigEndian, AsDer, Curve25519SeedBin, PublicKeyX509Der};
 use aws_lc_rs::rand::SystemRandom;
 use aws_lc_rs::signature::{self, Ed25519KeyPair, KeyPair, VerificationAlgorithm, ED25519};
 use aws_lc_rs::{error, test, test_file};
@@ -259,3 +259,170 @@ fn test_seed() {
 
     assert_eq!(key_pair_doc.as_ref(), key_pair_seed_copy_doc.as_ref());
 }
+
+#[test]
+fn test_public_key_x509_der_and_pem() {
+    // Generated by `openssl genpkey -algorithm ed25519`; the DER and PEM encodings of the public
+    // key are from `openssl pkey -pubout`.
+    const SEED: &str = "a24d8841561c7576538026f4e539a7c5e63f1a1f951e6f6ca7eb197007f595cd";
+    const PUBLIC_KEY_DER: &str =
+        "302a300506032b6570032100c85acee15259f4ac28b95cf00c655ae19e13019ffab47ee7402ebf52545aa23f";
+    const PUBLIC_KEY_PEM: &str = "-----BEGIN PUBLIC KEY-----
+MCowBQYDK2VwAyEAyFrO4VJZ9KwouVzwDGVa4Z4TAZ/6tH7nQC6/UlRaoj8=
+-----END PUBLIC KEY-----
+";
+
+    let key_pair = Ed25519KeyPair::from_seed_unchecked(&test::from_hex(SEED).unwrap()).unwrap();
+    let der: PublicKeyX509Der = key_pair.public_key().as_der().unwrap();
+    assert_eq!(test::from_hex(PUBLIC_KEY_DER).unwrap(), der.as_ref());
+    assert_eq!(PUBLIC_KEY_PEM, der.to_pem().unwrap());
+}
+
+#[test]
+fn test_public_key_is_raw_bytes() {
+    const MESSAGE: &[u8] = b"raw public key";
+
+    let key_pair = Ed25519KeyPair::generate().unwrap();
+    let public_key = key_pair.public_key().as_ref();
+    assert_eq!(32, public_key.len());
+
+    // The raw key is the last 32 bytes of the SubjectPublicKeyInfo encoding.
+    let der: PublicKeyX509Der = key_pair.public_key().as_der().unwrap();
+    assert_eq!(public_key, &der.as_ref()[der.as_ref().len() - 32..]);
+
+    let sig = key_pair.sign(MESSAGE);
+    signature::UnparsedPublicKey::new(&ED25519, public_key)
+        .verify(MESSAGE, sig.as_ref())
+        .unwrap();
+}
+
+#[test]
+fn test_signature_hex() {
+    // RFC 8032 Section 7.1, TEST 1.
+    const SEED: &str = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+    const SIGNATURE: &str = "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b";
+
+    let key_pair = Ed25519KeyPair::from_seed_unchecked(&test::from_hex(SEED).unwrap()).unwrap();
+    let sig = key_pair.sign(b"");
+    assert_eq!(SIGNATURE, format!("{sig:x}"));
+    assert_eq!(SIGNATURE.to_uppercase(), format!("{sig:X}"));
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn test_verify_file_ed25519() {
+    const CONTENTS: &[u8] = b"a large signed artifact";
+
+    fn verify_file(
+        public_key: &[u8],
+        path: &std::path::Path,
+        signature: &[u8],
+    ) -> Result<(), aws_lc_rs::error::Unspecified> {
+        // SAFETY: The test's files are not modified while they are being verified.
+        unsafe { signature::verify_file_ed25519(public_key, path, signature) }
+    }
+
+    let key_pair = Ed25519KeyPair::generate().unwrap();
+    let public_key = key_pair.public_key().as_ref();
+    let dir = std::env::temp_dir();
+    let path = dir.join(format!("aws-lc-rs-verify-file-{}", std::process::id()));
+    let empty_path = dir.join(format!(
+        "aws-lc-rs-verify-file-empty-{}",
+        std::process::id()
+    ));
+    std::fs::write(&path, CONTENTS).unwrap();
+    std::fs::write(&empty_path, b"").unwrap();
+
+    let sig = key_pair.sign(CONTENTS);
+    verify_file(public_key, &path, sig.as_ref()).unwrap();
+    assert!(verify_file(public_key, &empty_path, sig.as_ref()).is_err());
+
+    let empty_sig = key_pair.sign(b"");
+    verify_file(public_key, &empty_path, empty_sig.as_ref()).unwrap();
+    assert!(verify_file(public_key, &path, empty_sig.as_ref()).is_err());
+
+    let other_key_pair = Ed25519KeyPair::generate().unwrap();
+    assert!(verify_file(other_key_pair.public_key().as_ref(), &path, sig.as_ref()).is_err());
+
+    std::fs::remove_file(&path).unwrap();
+    std::fs::remove_file(&empty_path).unwrap();
+    assert!(verify_file(public_key, &path, sig.as_ref()).is_err());
+}
+
+#[cfg(not(feature = "fips"))]
+#[test]
+fn test_ed25519_sigstore() {
+    // RFC 8032 Section 7.3, TEST abc (Ed25519ph).
+    const SEED: &str = "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42";
+    const PUBLIC_KEY: &str = "ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf";
+    const SIGNATURE: &str = "98a70222f0b8121aa9d30f813d683f809e462b469c7ff87639499bb94e6dae4131f85042463c2a355a2003d062adf5aaa10b8c61e636062aaad11c2a26083406";
+
+    let public_key = test::from_hex(PUBLIC_KEY).unwrap();
+    let key_pair =
+        Ed25519KeyPair::from_seed_and_public_key(&test::from_hex(SEED).unwrap(), &public_key)
+            .unwrap();
+    let sig = signature::ed25519_sign_sigstore(&key_pair, b"abc").unwrap();
+    assert_eq!(SIGNATURE, format!("{sig:x}"));
+    signature::ed25519_verify_sigstore(&public_key, b"abc", sig.as_ref()).unwrap();
+
+    // The public key may also be a SubjectPublicKeyInfo.
+    let x509: PublicKeyX509Der = key_pair.public_key().as_der().unwrap();
+    signature::ed25519_verify_sigstore(x509.as_ref(), b"abc", sig.as_ref()).unwrap();
+
+    // An Ed25519ph signature is not a plain Ed25519 signature, and vice versa.
+    assert!(signature::UnparsedPublicKey::new(&ED25519, &public_key)
+        .verify(b"abc", sig.as_ref())
+        .is_err());
+    let plain = key_pair.sign(b"abc");
+    assert!(signature::ed25519_verify_sigstore(&public_key, b"abc", plain.as_ref()).is_err());
+
+    assert!(signature::ed25519_verify_sigstore(&public_key, b"abd", sig.as_ref()).is_err());
+    assert!(signature::ed25519_verify_sigstore(&public_key, b"abc", &sig.as_ref()[..63]).is_err());
+
+    let key_pair = Ed25519KeyPair::generate().unwrap();
+    let payload = b"{\"critical\":{}}";
+    let sig = signature::ed25519_sign_sigstore(&key_pair, payload).unwrap();
+    signature::ed25519_verify_sigstore(key_pair.public_key().as_ref(), payload, sig.as_ref())
+        .unwrap();
+}
+
+#[test]
+fn test_ed25519_public_key_matches() {
+    let key_pair = Ed25519KeyPair::generate().unwrap();
+    let public_key = key_pair.public_key();
+    let x509: PublicKeyX509Der = public_key.as_der().unwrap();
+
+    assert!(key_pair.public_key_matches(public_key.as_ref()));
+    assert!(key_pair.public_key_matches(x509.as_ref()));
+
+    let other = Ed25519KeyPair::generate().unwrap();
+    let other_x509: PublicKeyX509Der = other.public_key().as_der().unwrap();
+    assert!(!key_pair.public_key_matches(other.public_key().as_ref()));
+    assert!(!key_pair.public_key_matches(other_x509.as_ref()));
+
+    assert!(!key_pair.public_key_matches(&[]));
+    assert!(!key_pair.public_key_matches(&public_key.as_ref()[1..]));
+}
+
+#[test]
+fn test_ed25519_public_from_pkcs8() {
+    let rng = SystemRandom::new();
+    let pkcs8v2 = Ed25519KeyPair::generate_pkcs8(&rng).unwrap();
+    let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8v2.as_ref()).unwrap();
+    let pkcs8v1 = key_pair.to_pkcs8v1().unwrap();
+
+    for pkcs8 in [pkcs8v1.as_ref(), pkcs8v2.as_ref()] {
+        let public_key = Ed25519KeyPair::public_from_pkcs8(pkcs8).unwrap();
+        assert_eq!(public_key.len(), 32);
+        assert_eq!(
+            public_key.as_slice(),
+            Ed25519KeyPair::from_pkcs8(pkcs8)
+                .unwrap()
+                .public_key()
+                .as_ref()
+        );
+    }
+
+    assert!(Ed25519KeyPair::public_from_pkcs8(&pkcs8v2.as_ref()[1..]).is_err());
+    assert!(Ed25519KeyPair::public_from_pkcs8(&[]).is_err());
+}
@@ -23,23 +23,88 @@ fn hkdf_tests() {
         let secret = test_case.consume_bytes("IKM");
         let salt = test_case.consume_bytes("salt");
         let info = test_case.consume_bytes("info");
-        test_case.consume_bytes("PRK");
+        let expected_prk = test_case.consume_bytes("PRK");
         let expected_out = test_case.consume_bytes("OKM");
 
         let salt = hkdf::Salt::new(alg, &salt);
 
-        // TODO: test multi-part info, especially with empty parts.
-        let My(out) = salt
-            .extract(&secret)
-            .expand(&[&info], My(expected_out.len()))
-            .unwrap()
-            .into();
+        let prk = salt.extract(&secret);
+        assert_eq!(prk.as_bytes(), expected_prk.as_slice());
+
+        let My(out) = prk.expand(&[&info], My(expected_out.len())).unwrap().into();
+        assert_eq!(out, expected_out);
+
+        // A PRK constructed from the exposed bytes derives the same output.
+        let prk = hkdf::Prk::new_less_safe(alg, prk.as_bytes());
+        assert_eq!(prk.as_bytes(), expected_prk.as_slice());
+        let My(out) = prk.expand(&[&info], My(expected_out.len())).unwrap().into();
         assert_eq!(out, expected_out);
 
         Ok(())
     });
 }
 
+#[test]
+fn hkdf_salt_len_tests() {
+    // Generated by Python's `cryptography` package, using IKM = 0x0b * 22,
+    // info = f0f1f2f3f4f5f6f7f8f9 and `salt[i] = i`.
+    const IKM: [u8; 22] = [0x0b; 22];
+    const INFO: &[u8] = &[0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9];
+    let vectors = [
+        (
+            hkdf::HKDF_SHA256,
+            1,
+            "19ef24a32c717b167f33a91d6f648bdf96596776afdb6377ac434c1c293ccb04",
+            "abbafb13f5c1bc489d4203135817956dd521b39e3bd61d1cc85cef884d1f8e2e2ca9c19f23df620dd394",
+        ),
+        (
+            hkdf::HKDF_SHA256,
+            100,
+            "c1f3ce088c4b6b26794dd0d9daba2ca6e39395072005567fcd625b993b2e82be",
+            "9f71042551054f69ae52ea3f15436b0b6c5dd1517265a558817b4e76c2dabe99bbde66a887fc8491fdd2",
+        ),
+        (
+            hkdf::HKDF_SHA256,
+            200,
+            "fe9245970863a41cb2db63140f0438d665c6fc0cf0b18bb40ed3c974d26f1c54",
+            "1979eb1c9898f6cd4f4c2dfccdf6c3baa62b4dfa0ac81bb575f9d595d285f0b331127c5e3be3998536f7",
+        ),
+        (
+            hkdf::HKDF_SHA384,
+            1,
+            "10e40cf072a4c5626e43dd22c1cf727d4bb140975c9ad0cbc8e45b40068f8f0ba57cdb598af9dfa6963a96899af047e5",
+            "6bdcdc9fdaf458c805ffd57fe975f67580c797164e598e32619b9333bb4a74c07f1c3d5331b70fbd812c",
+        ),
+        (
+            hkdf::HKDF_SHA384,
+            100,
+            "b73adc9e644b0b4d1ed22b48daebceccc09c989545ff0c1a804d06e1684ab8092944580de6384fc3c8069cc8b6925ace",
+            "84736c0aac923ed7c1a9ff056d47c93a7a834591f91abd7ba6574b0df5b633e4d185a9514eb6394d94d2",
+        ),
+        (
+            hkdf::HKDF_SHA512,
+            100,
+            "dff9ed1bf6579ea064aa1879c8ba20133cb29ae853ea70a9f15d15c4e25a6176c0a7934d411ca4fc89701f587b1388a59330c9f02975d04cf69f77fce41b1d16",
+            "2868e251ef7fa1b842105d4d771b34bd4e2495d998a5643d0078397d89e579998640065fe9d95db9931f",
+        ),
+        (
+            hkdf::HKDF_SHA512,
+            200,
+            "bd8da3d8b123c726de43f3c97f3c4be3ff76552e4ae0fbbe01e997879c06c97c3f8e04a02ba2844bf57d8eedb2f164db9e91265e1ec855819fd0259f1e8cfe9d",
+            "0d2fc35216a15b5bea02bfa0d87c198613ae687ef793070039c019fc2bc01f5a36b604cce4c574e27de5",
+        ),
+    ];
+
+    for (alg, salt_len, expected_prk, expected_okm) in vectors {
+        let salt: Vec<u8> = (0..=u8::MAX).take(salt_len).collect();
+        let prk = hkdf::Salt::new(alg, &salt).extract(&IKM);
+        assert_eq!(prk.as_bytes(), test::from_hex(expected_prk).unwrap());
+
+        let My(okm) = prk.expand(&[INFO], My(42)).unwrap().into();
+        assert_eq!(okm, test::from_hex(expected_okm).unwrap());
+    }
+}
+
 #[test]
 fn hkdf_output_len_tests() {
     for &alg in &[hkdf::HKDF_SHA256, hkdf::HKDF_SHA384, hkdf::HKDF_SHA512] {
@@ -110,6 +175,34 @@ fn hkdf_info_len_tests() {
     }
 }
 
+#[test]
+fn hkdf_segmented_info_tests() {
+    let long_info = vec![7u8; 400];
+    for &alg in &[hkdf::HKDF_SHA256, hkdf::HKDF_SHA384, hkdf::HKDF_SHA512] {
+        let salt = hkdf::Salt::new(alg, b"salt");
+        let prk = salt.extract(b"input key material");
+
+        for (segmented, concatenated) in [
+            (&[&b"a"[..], &b"bc"[..]][..], &b"abc"[..]),
+            (&[&b""[..], &b"abc"[..], &b""[..]][..], &b"abc"[..]),
+            (&[&long_info[..150], &long_info[150..]][..], &long_info[..]),
+        ] {
+            let mut expected = [0u8; 42];
+            prk.expand(&[concatenated], My(expected.len()))
+                .unwrap()
+                .fill(&mut expected)
+                .unwrap();
+
+            let mut actual = [0u8; 42];
+            prk.expand(segmented, My(actual.len()))
+                .unwrap()
+                .fill(&mut actual)
+                .unwrap();
+            assert_eq!(expected, actual);
+        }
+    }
+}
+
 #[test]
 /// Try creating various key types via HKDF.
 fn hkdf_key_types() {
@@ -175,6 +268,37 @@ fn hkdf_clone_tests() {
     }
 }
 
+#[test]
+fn hkdf_prk_clone_across_threads() {
+    use std::thread;
+
+    test::compile_time_assert_clone::<hkdf::Prk>();
+    test::compile_time_assert_send::<hkdf::Prk>();
+    test::compile_time_assert_sync::<hkdf::Prk>();
+
+    for &alg in &[hkdf::HKDF_SHA256, hkdf::HKDF_SHA384, hkdf::HKDF_SHA512] {
+        let prk = hkdf::Salt::new(alg, b"salt").extract(b"secret");
+
+        let expected_client: My<Vec<u8>> = prk.expand(&[b"client"], My(42)).unwrap().into();
+        let expected_server: My<Vec<u8>> = prk.expand(&[b"server"], My(42)).unwrap().into();
+        assert_ne!(expected_client, expected_server);
+
+        let client_prk = prk.clone();
+        let server_prk = prk.clone();
+        drop(prk);
+
+        let client = thread::spawn(move || -> My<Vec<u8>> {
+            client_prk.expand(&[b"client"], My(42)).unwrap().into()
+        });
+        let server = thread::spawn(move || -> My<Vec<u8>> {
+            server_prk.expand(&[b"server"], My(42)).unwrap().into()
+        });
+
+        assert_eq!(client.join().unwrap(), expected_client);
+        assert_eq!(server.join().unwrap(), expected_server);
+    }
+}
+
 #[test]
 fn hkdf_thread_safeness() {
     use std::thread;
@@ -128,3 +128,87 @@ fn hmac_thread_safeness() {
         assert_eq!(thread_signature.as_ref(), signature.as_ref());
     }
 }
+
+#[test]
+fn hotp_rfc4226_test_vectors() {
+    // RFC 4226, Appendix D.
+    const EXPECTED: [u32; 10] = [
+        755_224, 287_082, 359_152, 969_429, 338_314, 254_676, 287_922, 162_583, 399_871, 520_489,
+    ];
+    let key = hmac::Key::new(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, b"12345678901234567890");
+    for (counter, expected) in EXPECTED.iter().enumerate() {
+        assert_eq!(
+            *expected,
+            hmac::hotp(&key, counter as u64, 6).unwrap(),
+            "counter {counter}"
+        );
+    }
+
+    assert!(hmac::hotp(&key, 0, 5).is_err());
+    assert!(hmac::hotp(&key, 0, 10).is_err());
+}
+
+#[test]
+fn totp_rfc6238_test_vectors() {
+    // RFC 6238, Appendix B.
+    const VECTORS: [(u64, [u32; 3]); 6] = [
+        (59, [94_287_082, 46_119_246, 90_693_936]),
+        (1_111_111_109, [7_081_804, 68_084_774, 25_091_201]),
+        (1_111_111_111, [14_050_471, 67_062_674, 99_943_326]),
+        (1_234_567_890, [89_005_924, 91_819_424, 93_441_116]),
+        (2_000_000_000, [69_279_037, 90_698_825, 38_618_901]),
+        (20_000_000_000, [65_353_130, 77_737_706, 47_863_826]),
+    ];
+    let keys = [
+        hmac::Key::new(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, b"12345678901234567890"),
+        hmac::Key::new(hmac::HMAC_SHA256, b"12345678901234567890123456789012"),
+        hmac::Key::new(
+            hmac::HMAC_SHA512,
+            b"1234567890123456789012345678901234567890123456789012345678901234",
+        ),
+    ];
+    for (time, expected) in VECTORS {
+        for (key, expected) in keys.iter().zip(expected) {
+            assert_eq!(expected, hmac::totp(key, time, 30, 8).unwrap());
+        }
+    }
+
+    assert!(hmac::totp(&keys[0], 59, 0, 8).is_err());
+}
+
+#[test]
+fn hmac_sha512_256_test() {
+    // RFC 4231 test case 1 inputs.
+    let key = hmac::Key::new(hmac::HMAC_SHA512_256, &[0x0b; 20]);
+    let expected =
+        test::from_dirty_hex("9f9126c3d9c3c330d760425ca8a217e31feae31bfe70196ff81642b868402eab");
+
+    let tag = hmac::sign(&key, b"Hi There");
+    assert_eq!(expected, tag.as_ref());
+    hmac::verify(&key, b"Hi There", &expected).unwrap();
+    assert!(hmac::verify(&key, b"Hi there", &expected).is_err());
+}
+
+#[test]
+fn hmac_verify_tag_mismatch() {
+    for algorithm in [hmac::HMAC_SHA256, hmac::HMAC_SHA384, hmac::HMAC_SHA512] {
+        let key = hmac::Key::new(algorithm, b"hmac verify key");
+        let tag = hmac::sign(&key, b"message");
+        let tag = tag.as_ref();
+        hmac::verify(&key, b"message", tag).unwrap();
+
+        for i in 0..tag.len() {
+            let mut flipped = tag.to_vec();
+            flipped[i] ^= 0x80;
+            assert!(hmac::verify(&key, b"message", &flipped).is_err());
+        }
+
+        for len in 0..tag.len() {
+            assert!(hmac::verify(&key, b"message", &tag[..len]).is_err());
+        }
+
+        let mut extended = tag.to_vec();
+        extended.push(0);
+        assert!(hmac::verify(&key, b"message", &extended).is_err());
+    }
+}
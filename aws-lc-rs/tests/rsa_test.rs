@@ -1069,3 +1069,520 @@ fn rsa2048_pkcs1_openssl_kat() {
 
     assert_eq!(EXPECTED_MESSAGE, plaintext);
 }
+
+// Generated with Java 17 (`java.security.Signature.getInstance("RSASSA-PSS")`) by signing
+// "Java KAT" with rsa_test_private_key_2048.p8 using
+// `new PSSParameterSpec("SHA-256", "MGF1", MGF1ParameterSpec.SHA256, 32, 1)`.
+#[test]
+fn rsa2048_pss_sha256_java_kat() {
+    const PRIVATE_KEY: &[u8] = include_bytes!("data/rsa_test_private_key_2048.p8");
+
+    const MESSAGE: &[u8] = b"Java KAT";
+    const SIGNATURE: &[u8] = &[
+        0x0b, 0x7f, 0xb6, 0x96, 0xe4, 0x52, 0x12, 0x82, 0x96, 0x2a, 0x95, 0x02, 0xd7, 0x58, 0xe7,
+        0x22, 0x31, 0x2f, 0xdc, 0xc2, 0x1d, 0x68, 0x45, 0x29, 0x38, 0x38, 0xe5, 0x8d, 0xb6, 0x2a,
+        0xcd, 0x47, 0xb4, 0x2d, 0xca, 0xfd, 0xaf, 0x24, 0x35, 0x5c, 0xf8, 0xbb, 0x3c, 0x68, 0x1f,
+        0xc4, 0x8d, 0x3b, 0xa3, 0xff, 0xba, 0xb9, 0x92, 0xbf, 0x3b, 0xb1, 0x52, 0x23, 0x7a, 0xb5,
+        0x1e, 0xd6, 0xd3, 0x07, 0x3d, 0xa3, 0xc9, 0x3a, 0xcd, 0xcd, 0xd8, 0xc4, 0x36, 0x2e, 0xea,
+        0x9a, 0x61, 0x31, 0x72, 0x51, 0xb5, 0x86, 0x65, 0xcc, 0x18, 0xe2, 0x08, 0x62, 0x64, 0x18,
+        0x59, 0x5f, 0xbe, 0x44, 0x8e, 0x13, 0x0a, 0x1a, 0x29, 0x4c, 0x77, 0xa2, 0xbc, 0x2e, 0x5a,
+        0x20, 0x9c, 0x5a, 0xfd, 0x52, 0x94, 0x01, 0x42, 0xf6, 0x4c, 0x79, 0xe2, 0x21, 0x5e, 0xe8,
+        0x9f, 0x65, 0xf1, 0xcc, 0xa9, 0x6f, 0xe4, 0x0f, 0xee, 0xb1, 0xf1, 0xda, 0x1d, 0x6a, 0xc1,
+        0xdc, 0xd7, 0x4a, 0x52, 0xaa, 0xb3, 0x7e, 0x0b, 0xd9, 0x84, 0xd3, 0xd9, 0xdd, 0x2a, 0xe8,
+        0x8c, 0xbc, 0xb6, 0x33, 0x0b, 0x9d, 0x5b, 0xd5, 0xb4, 0x5a, 0x53, 0xf9, 0x7c, 0xa3, 0x57,
+        0x26, 0x06, 0x74, 0xa1, 0x7e, 0x86, 0xb9, 0xfe, 0x9d, 0x59, 0xb0, 0x05, 0x79, 0x77, 0x29,
+        0x05, 0x4d, 0x67, 0xcb, 0x80, 0x72, 0xb6, 0x7b, 0x06, 0x39, 0xa8, 0x53, 0xd8, 0x91, 0x19,
+        0x96, 0xea, 0x7a, 0x51, 0xc0, 0x5b, 0x6b, 0x19, 0x0b, 0x8f, 0x33, 0x96, 0x46, 0x78, 0xb2,
+        0x7c, 0xbd, 0x84, 0x10, 0x04, 0x61, 0x74, 0x31, 0x05, 0xfa, 0xeb, 0x30, 0xa8, 0x47, 0x94,
+        0xde, 0xbb, 0x2e, 0xd9, 0xcc, 0x31, 0x30, 0xa5, 0xb1, 0x25, 0xb5, 0x32, 0xfe, 0xc6, 0x8f,
+        0xfc, 0xd4, 0x5e, 0x68, 0x56, 0x64, 0x4b, 0x2b, 0xbf, 0xa6, 0x79, 0xcc, 0xb9, 0xcc, 0x78,
+        0x16,
+    ];
+
+    let key_pair = RsaKeyPair::from_pkcs8(PRIVATE_KEY).unwrap();
+    let public_key = key_pair.public_key().as_ref();
+
+    signature::UnparsedPublicKey::new(&signature::RSA_PSS_2048_8192_SHA256, public_key)
+        .verify(MESSAGE, SIGNATURE)
+        .unwrap();
+    assert!(signature::UnparsedPublicKey::new(
+        &signature::RSA_PSS_2048_8192_SHA256_MAX_SALT,
+        public_key
+    )
+    .verify(MESSAGE, SIGNATURE)
+    .is_err());
+}
+
+// Generated with Java 17 (`java.security.Signature.getInstance("RSASSA-PSS")`) by signing
+// "Java KAT" with rsa_test_private_key_2048.p8 using
+// `new PSSParameterSpec("SHA-256", "MGF1", MGF1ParameterSpec.SHA256, 222, 1)`.
+#[test]
+fn rsa2048_pss_sha256_max_salt_java_kat() {
+    const PRIVATE_KEY: &[u8] = include_bytes!("data/rsa_test_private_key_2048.p8");
+
+    const MESSAGE: &[u8] = b"Java KAT";
+    const SIGNATURE: &[u8] = &[
+        0x40, 0x95, 0xd1, 0x17, 0x34, 0x7b, 0x09, 0x34, 0x29, 0x91, 0x71, 0x51, 0xde, 0x59, 0xb3,
+        0x51, 0x4a, 0x73, 0xd1, 0xcd, 0x38, 0xb6, 0xbe, 0xb6, 0xb7, 0x3d, 0x54, 0x4b, 0x88, 0x5c,
+        0xfd, 0xba, 0x68, 0x78, 0x10, 0x08, 0x6f, 0xbc, 0x02, 0x78, 0x2b, 0xcd, 0xdd, 0x73, 0x79,
+        0xab, 0x9c, 0xf4, 0xb0, 0x8f, 0x3b, 0xc7, 0x07, 0x0b, 0x8b, 0x3d, 0xa6, 0xcf, 0x34, 0x63,
+        0x0e, 0xd8, 0xb7, 0xf0, 0x33, 0x6a, 0x4a, 0x61, 0x59, 0x45, 0x9e, 0xcc, 0x25, 0xdf, 0x9b,
+        0xb2, 0x3c, 0xa4, 0xd5, 0x1d, 0xc2, 0x9a, 0x66, 0x11, 0xd8, 0xce, 0x36, 0xd0, 0x8f, 0xcc,
+        0xd6, 0xbe, 0x79, 0x48, 0x68, 0x40, 0x84, 0x14, 0x4c, 0x62, 0xcb, 0x1b, 0x08, 0x27, 0x27,
+        0x32, 0x1f, 0xff, 0xbc, 0xa4, 0x74, 0xce, 0x32, 0x3a, 0x27, 0xa7, 0x5f, 0x87, 0xc2, 0xeb,
+        0xb8, 0x39, 0x81, 0x53, 0xd2, 0xfc, 0xf2, 0x1f, 0xf7, 0x88, 0x2b, 0xd4, 0x8a, 0x22, 0x19,
+        0x62, 0xa4, 0x5c, 0x7d, 0xa5, 0x62, 0x80, 0x2e, 0xc3, 0xf6, 0x60, 0x23, 0x6a, 0x5b, 0x48,
+        0x24, 0x8f, 0x1a, 0x8f, 0xe1, 0x29, 0xe4, 0xa3, 0x84, 0xd3, 0xa4, 0x4a, 0x13, 0x9f, 0xd6,
+        0xd7, 0xfd, 0x2e, 0xba, 0x5e, 0xbf, 0xba, 0x9c, 0x14, 0xaa, 0xf7, 0x77, 0xdc, 0x94, 0x2b,
+        0xcd, 0x54, 0xe3, 0x81, 0xf6, 0x8d, 0x15, 0x40, 0x09, 0x85, 0x35, 0x3b, 0xad, 0x85, 0xb1,
+        0x0d, 0xae, 0xe9, 0xc8, 0xd4, 0x5e, 0x82, 0x93, 0xd0, 0x7b, 0x32, 0x7c, 0xdb, 0x19, 0x93,
+        0x80, 0xca, 0xe8, 0x8b, 0xd4, 0xc5, 0xb8, 0x4a, 0x41, 0x5e, 0x87, 0xf0, 0xaf, 0x7d, 0xa7,
+        0x63, 0xed, 0x32, 0x3d, 0x35, 0x7b, 0x07, 0xc6, 0xc3, 0x5c, 0x8c, 0x1f, 0x1f, 0x76, 0x44,
+        0x49, 0xd6, 0x58, 0x7b, 0xfd, 0xa8, 0xf7, 0x28, 0xa7, 0x03, 0x82, 0xc7, 0x09, 0x34, 0x0c,
+        0x79,
+    ];
+
+    let key_pair = RsaKeyPair::from_pkcs8(PRIVATE_KEY).unwrap();
+    let public_key = key_pair.public_key().as_ref();
+
+    signature::UnparsedPublicKey::new(&signature::RSA_PSS_2048_8192_SHA256_MAX_SALT, public_key)
+        .verify(MESSAGE, SIGNATURE)
+        .unwrap();
+    assert!(
+        signature::UnparsedPublicKey::new(&signature::RSA_PSS_2048_8192_SHA256, public_key)
+            .verify(MESSAGE, SIGNATURE)
+            .is_err()
+    );
+}
+
+#[test]
+fn rsa_pss_salt_length_round_trip() {
+    const PRIVATE_KEY: &[u8] = include_bytes!("data/rsa_test_private_key_2048.p8");
+    const MESSAGE: &[u8] = b"hello, world";
+
+    let key_pair = RsaKeyPair::from_pkcs8(PRIVATE_KEY).unwrap();
+    let public_key = key_pair.public_key().as_ref();
+    let rng = rand::SystemRandom::new();
+
+    for (signing_alg, verification_alg, other_alg) in [
+        (
+            &signature::RSA_PSS_SHA256,
+            &signature::RSA_PSS_2048_8192_SHA256,
+            &signature::RSA_PSS_2048_8192_SHA256_MAX_SALT,
+        ),
+        (
+            &signature::RSA_PSS_SHA384,
+            &signature::RSA_PSS_2048_8192_SHA384,
+            &signature::RSA_PSS_2048_8192_SHA384_MAX_SALT,
+        ),
+        (
+            &signature::RSA_PSS_SHA512,
+            &signature::RSA_PSS_2048_8192_SHA512,
+            &signature::RSA_PSS_2048_8192_SHA512_MAX_SALT,
+        ),
+        (
+            &signature::RSA_PSS_SHA256_MAX_SALT,
+            &signature::RSA_PSS_2048_8192_SHA256_MAX_SALT,
+            &signature::RSA_PSS_2048_8192_SHA256,
+        ),
+        (
+            &signature::RSA_PSS_SHA384_MAX_SALT,
+            &signature::RSA_PSS_2048_8192_SHA384_MAX_SALT,
+            &signature::RSA_PSS_2048_8192_SHA384,
+        ),
+        (
+            &signature::RSA_PSS_SHA512_MAX_SALT,
+            &signature::RSA_PSS_2048_8192_SHA512_MAX_SALT,
+            &signature::RSA_PSS_2048_8192_SHA512,
+        ),
+    ] {
+        let mut sig = vec![0u8; key_pair.public_modulus_len()];
+        key_pair.sign(signing_alg, &rng, MESSAGE, &mut sig).unwrap();
+
+        signature::UnparsedPublicKey::new(verification_alg, public_key)
+            .verify(MESSAGE, &sig)
+            .unwrap();
+        assert!(signature::UnparsedPublicKey::new(other_alg, public_key)
+            .verify(MESSAGE, &sig)
+            .is_err());
+    }
+}
+
+#[test]
+fn rsa_pss_auto_salt_length_kat() {
+    const PRIVATE_KEY: &[u8] = include_bytes!("data/rsa_test_private_key_2048.p8");
+
+    // Generated with Python's `cryptography` package using SHA-256 and MGF1-SHA-256, with salt
+    // lengths of 0, 32 (the digest length) and 222 (the maximum for this modulus).
+    const MESSAGE: &[u8] = b"PSS auto salt length KAT";
+    const SALT_0: &[u8] = &[
+        0x60, 0x0d, 0x3a, 0xaa, 0xb0, 0x24, 0x5b, 0xe7, 0x0b, 0x29, 0x7e, 0x13, 0x9e, 0x83, 0xe0,
+        0xbd, 0xda, 0x13, 0x97, 0xd1, 0x77, 0x38, 0x67, 0x83, 0xdf, 0xe3, 0xda, 0x79, 0xd9, 0x5e,
+        0xe6, 0x00, 0xae, 0x7b, 0x16, 0xa2, 0xad, 0xa2, 0xcd, 0xce, 0xc7, 0x5a, 0x89, 0x15, 0xbb,
+        0x6d, 0xdd, 0x3b, 0x32, 0x6b, 0xe4, 0x81, 0x44, 0x34, 0x1c, 0xbd, 0x81, 0x30, 0x65, 0xca,
+        0x72, 0xba, 0x7b, 0xa2, 0x5a, 0xb1, 0x27, 0x1a, 0x7c, 0x19, 0x5f, 0xdf, 0x7b, 0xf9, 0x01,
+        0xe4, 0xd1, 0x6f, 0x4f, 0xe9, 0x62, 0x0d, 0x81, 0x3d, 0xa3, 0x2f, 0x79, 0xb5, 0x82, 0xac,
+        0x5c, 0x81, 0x2a, 0x07, 0xc6, 0x47, 0xbb, 0xe2, 0x27, 0xb5, 0x7a, 0x1b, 0xc9, 0x6e, 0x6a,
+        0x82, 0xd2, 0x55, 0x57, 0x96, 0x8c, 0x5b, 0x06, 0xb2, 0x6b, 0x8f, 0xe7, 0x8c, 0x89, 0xe6,
+        0x0e, 0xcf, 0xea, 0xf8, 0xc3, 0xa9, 0x4a, 0xd1, 0x65, 0x5e, 0x97, 0x4b, 0xf1, 0x20, 0xfc,
+        0xd9, 0xd5, 0x07, 0x24, 0x7c, 0x68, 0x95, 0x1e, 0x47, 0xc2, 0x85, 0x5e, 0x35, 0x0e, 0x14,
+        0x45, 0xcf, 0x9d, 0x44, 0xc3, 0xc4, 0x4e, 0xee, 0x53, 0x0a, 0xfc, 0x22, 0x48, 0x68, 0xa3,
+        0x9e, 0xf5, 0x85, 0x17, 0x09, 0x23, 0x8d, 0x4a, 0x9a, 0x8e, 0xf9, 0x14, 0xdf, 0x2d, 0x0b,
+        0xe9, 0x96, 0xf6, 0x92, 0x09, 0x06, 0xea, 0xf8, 0x90, 0x0c, 0xf1, 0xe7, 0x2b, 0xe4, 0x6f,
+        0xf5, 0x01, 0xc1, 0xcf, 0x51, 0x5b, 0x08, 0xc9, 0x43, 0xd3, 0x1c, 0x43, 0x69, 0x57, 0xa2,
+        0x98, 0xa3, 0x80, 0x22, 0xd2, 0x40, 0xc0, 0x5f, 0x52, 0xeb, 0xf4, 0xbe, 0xfb, 0x1b, 0x9a,
+        0xe0, 0x73, 0xb8, 0x58, 0xe2, 0x36, 0x8d, 0x46, 0xcf, 0x7b, 0xc0, 0x0e, 0x3b, 0x71, 0x75,
+        0xef, 0xf4, 0x32, 0xda, 0xd3, 0xb4, 0x34, 0x37, 0x39, 0x72, 0xd1, 0x66, 0x3d, 0xf7, 0x47,
+        0x6d,
+    ];
+    const SALT_DIGEST: &[u8] = &[
+        0x54, 0x46, 0x55, 0xcb, 0x83, 0x50, 0xf0, 0xfb, 0xe4, 0x6b, 0x7f, 0x2e, 0x3e, 0x33, 0x5c,
+        0xcd, 0x9b, 0xa9, 0x40, 0x55, 0xcb, 0x1d, 0xc3, 0x74, 0xb9, 0x5a, 0x89, 0xe7, 0x31, 0xc3,
+        0xc1, 0x5b, 0xc2, 0xdf, 0x6b, 0x78, 0x29, 0xc6, 0x5c, 0x4a, 0x7e, 0x3d, 0xb9, 0x72, 0x5e,
+        0xa0, 0x54, 0x10, 0x7d, 0xbf, 0x8b, 0xdf, 0x34, 0x40, 0xab, 0xe7, 0xd7, 0x0a, 0x86, 0xe6,
+        0xdd, 0xbb, 0x33, 0x45, 0x7c, 0x0a, 0xda, 0xaf, 0x1b, 0xea, 0xb3, 0x6a, 0x01, 0x88, 0xdd,
+        0x67, 0x9e, 0x90, 0x49, 0x32, 0xb7, 0x99, 0x9c, 0x1f, 0xd4, 0xcd, 0x70, 0x03, 0x8d, 0x17,
+        0x90, 0x68, 0xd2, 0xe4, 0xa2, 0x21, 0x72, 0x76, 0x89, 0x4c, 0xe3, 0xa3, 0xb7, 0x51, 0xa8,
+        0x31, 0xf8, 0x96, 0x50, 0x96, 0x54, 0x79, 0xee, 0xe6, 0x62, 0x86, 0x4d, 0xb8, 0xaf, 0x98,
+        0xf7, 0x0e, 0x84, 0x84, 0x7f, 0x4f, 0xc3, 0x1a, 0x77, 0x50, 0xf5, 0xbf, 0x96, 0x41, 0xc6,
+        0xa8, 0xd7, 0xbf, 0x68, 0x64, 0x1e, 0xee, 0xfc, 0x99, 0xe8, 0x4c, 0x75, 0x4d, 0xa0, 0x75,
+        0x93, 0xee, 0x1a, 0xf8, 0x3c, 0xf9, 0x42, 0x29, 0x30, 0xbb, 0x9d, 0x3a, 0x54, 0xed, 0x7f,
+        0x54, 0x2f, 0xf3, 0x3a, 0xd0, 0x1a, 0xea, 0xc5, 0x7d, 0x26, 0xd5, 0x1a, 0x77, 0x1c, 0x04,
+        0x01, 0xb3, 0x8b, 0xf7, 0x8f, 0x22, 0x8a, 0x15, 0xb6, 0xa6, 0xcc, 0xde, 0x6b, 0x5d, 0x87,
+        0x96, 0x86, 0x5d, 0x2a, 0xb1, 0xb4, 0x6a, 0x86, 0xfc, 0xcb, 0xd2, 0x68, 0xd4, 0x43, 0xfa,
+        0x4a, 0x22, 0x81, 0xec, 0xba, 0xa9, 0xd8, 0x63, 0x38, 0xda, 0x8d, 0x9e, 0xce, 0x27, 0x94,
+        0xbb, 0x0b, 0x9e, 0x3b, 0x99, 0x47, 0xa9, 0xc7, 0x5f, 0x88, 0xf4, 0x64, 0x88, 0xe6, 0x91,
+        0xd9, 0x1e, 0xcb, 0x58, 0xbc, 0xd4, 0x04, 0xda, 0xfa, 0x41, 0x90, 0x63, 0x40, 0xae, 0xdc,
+        0x55,
+    ];
+    const SALT_MAX: &[u8] = &[
+        0x09, 0x06, 0xe2, 0xc3, 0xba, 0x8c, 0xb9, 0xf3, 0xe9, 0xa2, 0xe3, 0xd4, 0xa9, 0x04, 0x28,
+        0x28, 0x62, 0xb5, 0xc5, 0xa9, 0xa7, 0x5c, 0xe4, 0xf2, 0x56, 0x1c, 0x86, 0xce, 0xf4, 0xbc,
+        0xc9, 0x75, 0x17, 0x04, 0xf1, 0xc8, 0x62, 0x3d, 0xd0, 0x63, 0xae, 0x38, 0x01, 0x58, 0xe6,
+        0xaf, 0xa2, 0x15, 0x74, 0xc0, 0x30, 0x88, 0x52, 0x9f, 0x0a, 0x56, 0x4a, 0x76, 0xa8, 0x05,
+        0xb3, 0x22, 0xf6, 0x80, 0x1d, 0x69, 0xd2, 0x30, 0x41, 0xfb, 0xe4, 0x0d, 0xff, 0xfa, 0x4f,
+        0x2f, 0x2d, 0x42, 0xb2, 0xd6, 0xf0, 0xe3, 0x12, 0x49, 0xed, 0x31, 0x8a, 0xf5, 0xb0, 0xa1,
+        0x8a, 0x34, 0xc2, 0x44, 0xfc, 0x89, 0x7a, 0xa1, 0xea, 0x11, 0x08, 0x79, 0xe6, 0x56, 0xdd,
+        0x4d, 0xeb, 0x95, 0x6b, 0x01, 0x00, 0x0e, 0x4e, 0x78, 0x97, 0xcc, 0xee, 0xb7, 0x58, 0xae,
+        0xce, 0xdf, 0xe1, 0x73, 0xb4, 0xdf, 0xc7, 0xa2, 0x89, 0xab, 0x25, 0x3f, 0x25, 0x79, 0x98,
+        0x88, 0x63, 0x04, 0x39, 0x75, 0xc0, 0x57, 0x44, 0x58, 0x93, 0xbe, 0xd7, 0xd6, 0x6f, 0xa0,
+        0x6f, 0xb9, 0x62, 0x46, 0x22, 0x52, 0x0f, 0xd1, 0x41, 0x0d, 0xc6, 0xff, 0x2d, 0x48, 0xfc,
+        0x6a, 0x6e, 0x84, 0x48, 0xe6, 0x5b, 0x2a, 0xe4, 0x01, 0x13, 0xe3, 0xc0, 0x36, 0x62, 0x99,
+        0x35, 0xbc, 0x3c, 0x85, 0x0f, 0x89, 0x90, 0x95, 0xb5, 0x4d, 0x28, 0xa0, 0xdc, 0x98, 0xed,
+        0x7e, 0x90, 0x73, 0xa9, 0x35, 0x1c, 0x29, 0x88, 0x18, 0xa9, 0x4e, 0x95, 0xe6, 0xa5, 0x11,
+        0xed, 0xa5, 0xda, 0x2e, 0x6c, 0xad, 0xc7, 0xce, 0xab, 0x51, 0x56, 0x8b, 0x09, 0x74, 0x60,
+        0xce, 0x93, 0x9f, 0xe8, 0xbb, 0xb5, 0xd6, 0x9e, 0x9e, 0xb2, 0xbe, 0xb7, 0x97, 0xa4, 0x4e,
+        0xae, 0x8f, 0x1b, 0x0a, 0x2c, 0xb2, 0xed, 0xe5, 0x10, 0xae, 0x87, 0x87, 0xc1, 0x66, 0xde,
+        0x3e,
+    ];
+
+    let key_pair = RsaKeyPair::from_pkcs8(PRIVATE_KEY).unwrap();
+    let public_key = key_pair.public_key().as_ref();
+    let auto = signature::UnparsedPublicKey::new(
+        &signature::RSA_PSS_2048_8192_SHA256_AUTO_SALT,
+        public_key,
+    );
+
+    for sig in [SALT_0, SALT_DIGEST, SALT_MAX] {
+        auto.verify(MESSAGE, sig).unwrap();
+        assert!(auto.verify(b"PSS auto salt length KAT!", sig).is_err());
+    }
+
+    let strict =
+        signature::UnparsedPublicKey::new(&signature::RSA_PSS_2048_8192_SHA256, public_key);
+    strict.verify(MESSAGE, SALT_DIGEST).unwrap();
+    assert!(strict.verify(MESSAGE, SALT_0).is_err());
+    assert!(strict.verify(MESSAGE, SALT_MAX).is_err());
+}
+
+#[test]
+fn rsa_pss_auto_salt_length_round_trip() {
+    const PRIVATE_KEY: &[u8] = include_bytes!("data/rsa_test_private_key_2048.p8");
+    const MESSAGE: &[u8] = b"hello, world";
+
+    let key_pair = RsaKeyPair::from_pkcs8(PRIVATE_KEY).unwrap();
+    let public_key = key_pair.public_key().as_ref();
+    let rng = rand::SystemRandom::new();
+
+    for (signing_alg, verification_alg) in [
+        (
+            &signature::RSA_PSS_SHA256,
+            &signature::RSA_PSS_2048_8192_SHA256_AUTO_SALT,
+        ),
+        (
+            &signature::RSA_PSS_SHA384,
+            &signature::RSA_PSS_2048_8192_SHA384_AUTO_SALT,
+        ),
+        (
+            &signature::RSA_PSS_SHA512,
+            &signature::RSA_PSS_2048_8192_SHA512_AUTO_SALT,
+        ),
+        (
+            &signature::RSA_PSS_SHA256_MAX_SALT,
+            &signature::RSA_PSS_2048_8192_SHA256_AUTO_SALT,
+        ),
+        (
+            &signature::RSA_PSS_SHA384_MAX_SALT,
+            &signature::RSA_PSS_2048_8192_SHA384_AUTO_SALT,
+        ),
+        (
+            &signature::RSA_PSS_SHA512_MAX_SALT,
+            &signature::RSA_PSS_2048_8192_SHA512_AUTO_SALT,
+        ),
+    ] {
+        let mut sig = vec![0u8; key_pair.public_modulus_len()];
+        key_pair.sign(signing_alg, &rng, MESSAGE, &mut sig).unwrap();
+
+        let public_key = signature::UnparsedPublicKey::new(verification_alg, public_key);
+        public_key.verify(MESSAGE, &sig).unwrap();
+        sig[0] ^= 1;
+        assert!(public_key.verify(MESSAGE, &sig).is_err());
+    }
+}
+
+#[test]
+fn rsa_verification_context_streaming() {
+    use std::io::Read;
+
+    const PRIVATE_KEY: &[u8] = include_bytes!("data/rsa_test_private_key_2048.p8");
+    const FILE_PATH: &str = "tests/data/rsa_pkcs1_verify_tests.txt";
+
+    let key_pair = RsaKeyPair::from_pkcs8(PRIVATE_KEY).unwrap();
+    let public_key = key_pair.public_key().as_ref();
+    let rng = rand::SystemRandom::new();
+    let message = std::fs::read(FILE_PATH).unwrap();
+
+    for (signing_alg, verification_alg) in [
+        (
+            &signature::RSA_PKCS1_SHA256,
+            &signature::RSA_PKCS1_2048_8192_SHA256,
+        ),
+        (
+            &signature::RSA_PSS_SHA384,
+            &signature::RSA_PSS_2048_8192_SHA384,
+        ),
+        (
+            &signature::RSA_PSS_SHA512_MAX_SALT,
+            &signature::RSA_PSS_2048_8192_SHA512_MAX_SALT,
+        ),
+    ] {
+        let mut sig = vec![0u8; key_pair.public_modulus_len()];
+        key_pair
+            .sign(signing_alg, &rng, &message, &mut sig)
+            .unwrap();
+
+        let mut file = std::fs::File::open(FILE_PATH).unwrap();
+        let mut context = verification_alg.verification_context(public_key).unwrap();
+        let mut chunk = [0u8; 4096];
+        loop {
+            let len = file.read(&mut chunk).unwrap();
+            if len == 0 {
+                break;
+            }
+            context.update(&chunk[..len]).unwrap();
+        }
+        context.verify(&sig).unwrap();
+
+        // A truncated message does not verify.
+        let mut context = verification_alg.verification_context(public_key).unwrap();
+        context.update(&message[..message.len() - 1]).unwrap();
+        assert!(context.verify(&sig).is_err());
+    }
+
+    // The modulus size is checked when the context is created.
+    assert!(signature::RSA_PKCS1_3072_8192_SHA384
+        .verification_context(public_key)
+        .is_err());
+}
+
+// PKCS#1 v1.5 SHA-256 signatures over "Lenient DigestInfo KAT" made with
+// rsa_test_private_key_2048.p8, one with the standard `NULL` DigestInfo parameters and one
+// with the parameters omitted.
+#[test]
+fn rsa_pkcs1_lenient_digest_info_kat() {
+    const PUBLIC_KEY: &[u8] = include_bytes!("data/rsa_test_public_key_2048.der");
+    const MESSAGE: &[u8] = b"Lenient DigestInfo KAT";
+    const SIG_WITH_NULL: &[u8] = &[
+        0x88, 0xe2, 0x46, 0x17, 0x88, 0x39, 0x43, 0x9a, 0xff, 0x4f, 0x8a, 0xdc, 0x16, 0x03, 0x07,
+        0x73, 0x94, 0x1d, 0xc2, 0x14, 0x23, 0xde, 0x19, 0x95, 0x54, 0x78, 0x84, 0x5f, 0xf5, 0xdd,
+        0x64, 0xa4, 0xf9, 0xf4, 0xd4, 0xe7, 0xaf, 0xea, 0xdc, 0x61, 0xed, 0x05, 0xfe, 0x23, 0xb7,
+        0xbe, 0x54, 0x5e, 0xe9, 0x80, 0xf1, 0x24, 0x0c, 0x99, 0x21, 0x4f, 0x5f, 0x1a, 0x5f, 0x87,
+        0x7c, 0x52, 0x00, 0x41, 0x5f, 0x6d, 0xed, 0x85, 0x5d, 0x95, 0xc1, 0x12, 0x49, 0xf7, 0xed,
+        0x9a, 0xd3, 0x25, 0x91, 0xc5, 0x09, 0x81, 0x3c, 0xc9, 0x15, 0x3e, 0x00, 0x46, 0xaf, 0x51,
+        0x82, 0x2d, 0x70, 0xd2, 0x4c, 0x3d, 0x2f, 0xe7, 0x05, 0x23, 0x04, 0xc8, 0xa7, 0xb4, 0x87,
+        0x05, 0x5c, 0x14, 0x0f, 0x70, 0xa1, 0xfe, 0xab, 0x2a, 0x8f, 0x9d, 0x0e, 0x38, 0x2f, 0xa2,
+        0x1f, 0x1d, 0xbf, 0xa8, 0x0c, 0xbe, 0x7e, 0x50, 0x73, 0xdb, 0x9c, 0x5e, 0x97, 0x7a, 0x31,
+        0xdc, 0xb1, 0xde, 0x4d, 0xf3, 0x0e, 0xa3, 0xff, 0x69, 0xba, 0x34, 0xf0, 0x4f, 0xdd, 0x30,
+        0x2c, 0xb5, 0xfb, 0x8f, 0xea, 0xa2, 0xc5, 0x55, 0xfa, 0x2e, 0x8c, 0x37, 0x3c, 0x2e, 0xc9,
+        0xd5, 0xd3, 0x91, 0x89, 0xd8, 0xb3, 0x78, 0xd1, 0xf3, 0xad, 0x78, 0x5c, 0x4c, 0xd0, 0x03,
+        0x47, 0xf1, 0x5c, 0x5b, 0x2d, 0x5a, 0x81, 0x31, 0x00, 0xf7, 0x18, 0x37, 0xd0, 0x78, 0xee,
+        0xda, 0xe9, 0x6f, 0xb2, 0x9e, 0x2c, 0xa2, 0x7a, 0xdc, 0xbc, 0x1d, 0xe9, 0x86, 0x6f, 0x0f,
+        0x6a, 0x93, 0x89, 0xd9, 0x29, 0x59, 0xc1, 0xb3, 0x8d, 0x29, 0x31, 0xaa, 0xe7, 0xd3, 0xd9,
+        0xf9, 0x37, 0x86, 0xcc, 0x47, 0xfd, 0x11, 0x1a, 0xef, 0x8d, 0x2e, 0x86, 0x22, 0x82, 0xf1,
+        0x95, 0xd9, 0xca, 0xee, 0x02, 0x5c, 0x19, 0xac, 0xca, 0xf9, 0xd0, 0xda, 0x4f, 0xf2, 0x40,
+        0x04,
+    ];
+    const SIG_WITHOUT_NULL: &[u8] = &[
+        0x8e, 0xdf, 0x1d, 0x68, 0x7a, 0x83, 0x05, 0x61, 0xab, 0xdb, 0xca, 0x25, 0x8c, 0x86, 0x71,
+        0x57, 0xcf, 0x55, 0x15, 0xad, 0xe1, 0x38, 0xec, 0x22, 0xa9, 0x47, 0xad, 0x2c, 0x98, 0x5e,
+        0x50, 0x5e, 0xb0, 0xbb, 0x48, 0x2e, 0x15, 0x53, 0x5e, 0xb9, 0xe8, 0xa3, 0x32, 0x3c, 0x90,
+        0x50, 0x8b, 0x4a, 0x73, 0xe8, 0x7e, 0x7d, 0x5f, 0x95, 0x63, 0x76, 0x92, 0xec, 0x9e, 0xa4,
+        0xd8, 0x0d, 0xb3, 0xdc, 0xf1, 0x5c, 0x87, 0x0b, 0xfa, 0x0b, 0xd3, 0x7e, 0xd7, 0xa9, 0x72,
+        0xae, 0x1c, 0xdb, 0x95, 0x77, 0xaa, 0xd4, 0x08, 0x48, 0x4e, 0x35, 0x17, 0x76, 0xf1, 0x5c,
+        0xf4, 0x85, 0x4f, 0x98, 0x16, 0x06, 0x49, 0xeb, 0x34, 0x8e, 0x0a, 0x07, 0x94, 0x30, 0x69,
+        0x3a, 0xf0, 0x59, 0x45, 0x34, 0x93, 0x20, 0x08, 0x7c, 0xe7, 0x3b, 0xce, 0xac, 0x8d, 0xd7,
+        0x54, 0x97, 0x2d, 0x10, 0x78, 0xef, 0x7e, 0xb7, 0x99, 0x24, 0x8b, 0x6b, 0xa9, 0x76, 0x6d,
+        0xbd, 0x01, 0x6c, 0xf1, 0xc8, 0x59, 0xfa, 0x5e, 0xf4, 0x2f, 0xb5, 0xe2, 0xc1, 0x30, 0x67,
+        0x6a, 0x69, 0xf2, 0xd8, 0x72, 0x47, 0xdc, 0xf9, 0xe8, 0xd4, 0x03, 0x53, 0x22, 0x71, 0xf4,
+        0x7c, 0x93, 0x1f, 0x2d, 0xb0, 0x84, 0x80, 0x52, 0x49, 0xb5, 0xa3, 0xf0, 0x01, 0xd6, 0xd8,
+        0x66, 0xbe, 0x98, 0x9e, 0x64, 0xd7, 0xec, 0x17, 0xf5, 0xdc, 0x9e, 0x3a, 0x33, 0x3b, 0xe8,
+        0xad, 0x34, 0x89, 0x60, 0x4b, 0x16, 0xbd, 0x37, 0xdc, 0xdc, 0x56, 0x29, 0x19, 0x0b, 0xbf,
+        0xbc, 0x07, 0xad, 0xee, 0x50, 0xc0, 0x15, 0xf1, 0x51, 0x8b, 0x07, 0x29, 0x0c, 0x6d, 0x57,
+        0x9e, 0x5d, 0xe7, 0x59, 0xaa, 0xe1, 0x73, 0xe6, 0x86, 0xff, 0xa3, 0x6b, 0x54, 0xa8, 0xff,
+        0xce, 0x9b, 0xb3, 0x1f, 0xff, 0x9d, 0x0e, 0x00, 0x14, 0x6b, 0x26, 0x7d, 0xe4, 0x00, 0x9d,
+        0x4a,
+    ];
+
+    let params = &signature::RSA_PKCS1_2048_8192_SHA256;
+
+    params
+        .verify_lenient_pkcs1(PUBLIC_KEY, MESSAGE, SIG_WITH_NULL)
+        .expect("NULL parameters");
+    params
+        .verify_lenient_pkcs1(PUBLIC_KEY, MESSAGE, SIG_WITHOUT_NULL)
+        .expect("absent parameters");
+
+    // The strict path only accepts the standard encoding.
+    let public_key = signature::UnparsedPublicKey::new(params, PUBLIC_KEY);
+    assert!(public_key.verify(MESSAGE, SIG_WITH_NULL).is_ok());
+    assert!(public_key.verify(MESSAGE, SIG_WITHOUT_NULL).is_err());
+
+    assert!(params
+        .verify_lenient_pkcs1(PUBLIC_KEY, b"wrong message", SIG_WITHOUT_NULL)
+        .is_err());
+    assert!(signature::RSA_PKCS1_2048_8192_SHA384
+        .verify_lenient_pkcs1(PUBLIC_KEY, MESSAGE, SIG_WITH_NULL)
+        .is_err());
+    assert!(signature::RSA_PSS_2048_8192_SHA256
+        .verify_lenient_pkcs1(PUBLIC_KEY, MESSAGE, SIG_WITH_NULL)
+        .is_err());
+}
+
+#[test]
+fn rsa_oaep_label_mismatch() {
+    const MESSAGE: &[u8] = b"Hello World!";
+
+    let private_key = PrivateDecryptingKey::generate(KeySize::Rsa2048).unwrap();
+    let public_key = OaepPublicEncryptingKey::new(private_key.public_key()).unwrap();
+    let private_key = OaepPrivateDecryptingKey::new(private_key).unwrap();
+
+    for alg in [
+        &OAEP_SHA1_MGF1SHA1,
+        &OAEP_SHA256_MGF1SHA256,
+        &OAEP_SHA384_MGF1SHA384,
+        &OAEP_SHA512_MGF1SHA512,
+    ] {
+        let mut ciphertext = vec![0u8; public_key.ciphertext_size()];
+        let ciphertext = public_key
+            .encrypt(alg, MESSAGE, &mut ciphertext, Some(b"A"))
+            .unwrap();
+
+        let mut plaintext = vec![0u8; private_key.min_output_size()];
+        assert!(private_key
+            .decrypt(alg, ciphertext, &mut plaintext, Some(b"B"))
+            .is_err());
+        assert!(private_key
+            .decrypt(alg, ciphertext, &mut plaintext, None)
+            .is_err());
+
+        let plaintext = private_key
+            .decrypt(alg, ciphertext, &mut plaintext, Some(b"A"))
+            .unwrap();
+        assert_eq!(MESSAGE, plaintext);
+    }
+}
+
+#[cfg(feature = "ring-io")]
+#[test]
+fn rsa_public_key_eq() {
+    const PRIVATE_KEY: &[u8] = include_bytes!("data/rsa_test_private_key_2048.p8");
+    let key_pair = RsaKeyPair::from_pkcs8(PRIVATE_KEY).unwrap();
+    let public_key = key_pair.public_key();
+    let components = RsaPublicKeyComponents::<Vec<u8>>::from(public_key);
+
+    let from_components =
+        RsaSubjectPublicKey::from_modulus_and_exponent(&components.n, &components.e).unwrap();
+    let from_components_again =
+        RsaSubjectPublicKey::from_modulus_and_exponent(&components.n, &components.e).unwrap();
+    assert_eq!(from_components, from_components_again);
+    assert_eq!(public_key, &from_components);
+
+    let spki: PublicKeyX509Der = public_key.as_der().unwrap();
+    assert_eq!(
+        public_key,
+        &RsaSubjectPublicKey::from_der(spki.as_ref()).unwrap()
+    );
+
+    let other_key_pair = RsaKeyPair::generate(KeySize::Rsa2048).unwrap();
+    assert_ne!(public_key, other_key_pair.public_key());
+
+    // Same modulus, different exponent.
+    let other_exponent =
+        RsaSubjectPublicKey::from_modulus_and_exponent(&components.n, &[0x01, 0x00, 0x03]).unwrap();
+    assert_ne!(from_components, other_exponent);
+}
+
+#[test]
+fn rsa_pkcs1_decrypt_invalid_padding() {
+    let private_key = PrivateDecryptingKey::generate(KeySize::Rsa2048).unwrap();
+    let private_key = Pkcs1PrivateDecryptingKey::new(private_key).unwrap();
+
+    // The ciphertext 1 decrypts to the integer 1, which does not have PKCS#1 v1.5 encryption
+    // padding. Padding failures are rejected explicitly with an error.
+    let mut ciphertext = vec![0u8; private_key.key_size_bytes()];
+    *ciphertext.last_mut().unwrap() = 1;
+    let mut plaintext = vec![0u8; private_key.min_output_size()];
+    assert!(private_key.decrypt(&ciphertext, &mut plaintext).is_err());
+}
+
+#[test]
+fn rsa_public_key_from_der_spki() {
+    const SPKI: &[u8] = include_bytes!("data/rsa_test_public_key_2048.x509");
+    const RSA_PUBLIC_KEY: &[u8] = include_bytes!("data/rsa_test_public_key_2048.der");
+
+    let public_key = RsaSubjectPublicKey::from_der_spki(SPKI).unwrap();
+    #[cfg(feature = "ring-io")]
+    assert_eq!(256, public_key.modulus_len());
+    assert_eq!(RSA_PUBLIC_KEY, public_key.as_ref());
+    assert_eq!(public_key, RsaSubjectPublicKey::from_der(SPKI).unwrap());
+    let spki: PublicKeyX509Der = public_key.as_der().unwrap();
+    assert_eq!(SPKI, spki.as_ref());
+
+    // A PKCS#1 RSAPublicKey is not a SubjectPublicKeyInfo.
+    assert!(RsaSubjectPublicKey::from_der_spki(RSA_PUBLIC_KEY).is_err());
+
+    // A SubjectPublicKeyInfo for another key type.
+    let ec_key_pair =
+        signature::EcdsaKeyPair::generate(&signature::ECDSA_P256_SHA256_ASN1_SIGNING).unwrap();
+    let ec_spki: PublicKeyX509Der = ec_key_pair.public_key().as_der().unwrap();
+    assert!(RsaSubjectPublicKey::from_der_spki(ec_spki.as_ref()).is_err());
+}
+
+#[test]
+fn test_rsa_public_key_matches() {
+    let key_pair =
+        RsaKeyPair::from_pkcs8(include_bytes!("data/rsa_test_private_key_2048.p8")).unwrap();
+    let public_key = key_pair.public_key();
+    let x509: PublicKeyX509Der = public_key.as_der().unwrap();
+
+    assert!(key_pair.public_key_matches(public_key.as_ref()));
+    assert!(key_pair.public_key_matches(x509.as_ref()));
+
+    let other = RsaKeyPair::generate(KeySize::Rsa2048).unwrap();
+    let other_x509: PublicKeyX509Der = other.public_key().as_der().unwrap();
+    assert!(!key_pair.public_key_matches(other.public_key().as_ref()));
+    assert!(!key_pair.public_key_matches(other_x509.as_ref()));
+
+    assert!(!key_pair.public_key_matches(&[]));
+    assert!(!key_pair.public_key_matches(&public_key.as_ref()[1..]));
+}